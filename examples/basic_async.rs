@@ -23,7 +23,8 @@ mod async_example {
             .add_input("examples/data/config.json")
             .add_output("-") // stdout
             .with_mode(ErrorPolicy::FastFail)
-            .build()?;
+            .build()
+            .await?;
 
         // Read all inputs asynchronously
         let configs: Vec<Config> = engine.read_all().await?;
@@ -10,9 +10,14 @@ use crate::engine::IoEngine;
 use crate::error::{AggregateError, ErrorPolicy, SingleIoError, Stage};
 #[cfg(feature = "custom")]
 use crate::format::CustomFormat;
-use crate::format::{DEFAULT_FORMAT_ORDER, FormatKind, FormatRegistry};
+use crate::format::{
+    FormatKind, FormatRegistry, KeyOrder, OutputOptions, OutputStyle, DEFAULT_FORMAT_ORDER,
+};
 use crate::io::{FileInput, FileOutput, InputProvider, OutputTarget, StdinInput, StdoutOutput};
-use crate::io::{InMemorySource, StderrOutput};
+use crate::io::{
+    InMemorySource, ProcessInput, ProcessOutput, SchemeRegistry, SocketInput, SocketOutput,
+    StderrOutput,
+};
 
 pub struct MultiioBuilder {
     input_args: Vec<String>,
@@ -20,10 +25,15 @@ pub struct MultiioBuilder {
     input_specs: Vec<InputSpec>,
     output_specs: Vec<OutputSpec>,
     registry: FormatRegistry,
+    scheme_registry: SchemeRegistry,
     error_policy: ErrorPolicy,
     default_input_formats: Vec<FormatKind>,
     default_output_formats: Vec<FormatKind>,
     file_exists_policy: FileExistsPolicy,
+    output_options: Option<OutputOptions>,
+    file_mode: Option<u32>,
+    #[cfg(feature = "encryption")]
+    encryption_key: Option<crate::io::SecretKey>,
 }
 
 impl MultiioBuilder {
@@ -34,10 +44,15 @@ impl MultiioBuilder {
             input_specs: Vec::new(),
             output_specs: Vec::new(),
             registry,
+            scheme_registry: SchemeRegistry::new(),
             error_policy: ErrorPolicy::Accumulate,
             default_input_formats: DEFAULT_FORMAT_ORDER.to_vec(),
             default_output_formats: DEFAULT_FORMAT_ORDER.to_vec(),
             file_exists_policy: FileExistsPolicy::Overwrite,
+            output_options: None,
+            file_mode: None,
+            #[cfg(feature = "encryption")]
+            encryption_key: None,
         }
     }
 
@@ -47,6 +62,28 @@ impl MultiioBuilder {
         self
     }
 
+    /// Register a factory for `scheme://rest` input tokens (e.g. `s3://`,
+    /// `http://`, `db://`). Every matching token resolved by
+    /// `with_input_args`/`add_input` is built via `factory(rest)` instead of
+    /// falling through to file-path resolution.
+    pub fn register_input_scheme<F>(mut self, scheme: impl Into<String>, factory: F) -> Self
+    where
+        F: Fn(&str) -> std::io::Result<Arc<dyn InputProvider>> + Send + Sync + 'static,
+    {
+        self.scheme_registry.register_input_scheme(scheme, factory);
+        self
+    }
+
+    /// Register a factory for `scheme://rest` output tokens. See
+    /// `register_input_scheme`.
+    pub fn register_output_scheme<F>(mut self, scheme: impl Into<String>, factory: F) -> Self
+    where
+        F: Fn(&str) -> std::io::Result<Arc<dyn OutputTarget>> + Send + Sync + 'static,
+    {
+        self.scheme_registry.register_output_scheme(scheme, factory);
+        self
+    }
+
     pub fn inputs_from_args(mut self, args: &[String]) -> Self {
         self.input_args = args.to_vec();
         self
@@ -111,6 +148,34 @@ impl MultiioBuilder {
         self
     }
 
+    /// Set the default output formatting options (pretty/compact, indent, key
+    /// order) applied to every output that doesn't override them per-spec.
+    pub fn with_output_options(mut self, options: OutputOptions) -> Self {
+        self.output_options = Some(options);
+        self
+    }
+
+    /// Set the Unix permission bits (e.g. `0o600`) applied to every output's
+    /// backing file once a write completes, for outputs that don't override
+    /// it per-spec. No-op on non-Unix platforms and for targets with no
+    /// backing file path.
+    pub fn with_file_mode(mut self, mode: u32) -> Self {
+        self.file_mode = Some(mode);
+        self
+    }
+
+    /// Transparently encrypt/decrypt every file-backed input and output
+    /// resolved by this builder with `key` (see
+    /// [`crate::io::EncryptedInput`]/[`crate::io::EncryptedOutput`]). The
+    /// encryption layer wraps the compression layer: on write, plaintext is
+    /// encrypted and the ciphertext then compressed; on read, bytes are
+    /// decompressed first and then decrypted.
+    #[cfg(feature = "encryption")]
+    pub fn with_encryption(mut self, key: crate::io::SecretKey) -> Self {
+        self.encryption_key = Some(key);
+        self
+    }
+
     pub fn build(self) -> Result<IoEngine, AggregateError> {
         let mut inputs = self.resolve_inputs()?;
         let mut outputs = self.resolve_outputs()?;
@@ -156,6 +221,7 @@ impl MultiioBuilder {
         if let Some(path) = raw.strip_prefix('@') {
             if path.is_empty() {
                 return Err(SingleIoError {
+                    attempts: 1,
                     stage: Stage::ResolveInput,
                     target: raw.to_string(),
                     error: Box::new(std::io::Error::new(
@@ -165,12 +231,10 @@ impl MultiioBuilder {
                 });
             }
 
-            let path = PathBuf::from(path);
-            let provider: Arc<dyn InputProvider> = Arc::new(FileInput::new(path.clone()));
-            let explicit = self.infer_format_from_path(&path);
+            let (provider, explicit) = self.file_input_provider(path);
 
             return Ok(InputSpec {
-                raw: path.to_string_lossy().into_owned(),
+                raw: path.to_string(),
                 provider,
                 explicit_format: explicit,
                 format_candidates: self.default_input_formats.clone(),
@@ -186,6 +250,94 @@ impl MultiioBuilder {
             });
         }
 
+        if let Some(command_line) = raw.strip_prefix('!') {
+            let (program, args) =
+                parse_command_line(command_line).ok_or_else(|| SingleIoError {
+                    attempts: 1,
+                    stage: Stage::ResolveInput,
+                    target: raw.to_string(),
+                    error: Box::new(std::io::Error::new(
+                        std::io::ErrorKind::InvalidInput,
+                        "expected a command after '!'",
+                    )),
+                })?;
+
+            let provider: Arc<dyn InputProvider> =
+                Arc::new(ProcessInput::new(program).with_args(args));
+
+            return Ok(InputSpec {
+                raw: command_line.to_string(),
+                provider,
+                explicit_format: None,
+                format_candidates: self.default_input_formats.clone(),
+            });
+        }
+
+        if let Some(addr) = raw.strip_prefix("tcp://") {
+            let provider: Arc<dyn InputProvider> = Arc::new(SocketInput::tcp(addr));
+            return Ok(InputSpec {
+                raw: raw.to_string(),
+                provider,
+                explicit_format: None,
+                format_candidates: self.default_input_formats.clone(),
+            });
+        }
+
+        #[cfg(unix)]
+        if let Some(path) = raw.strip_prefix("unix:") {
+            let provider: Arc<dyn InputProvider> = Arc::new(SocketInput::unix(path));
+            return Ok(InputSpec {
+                raw: raw.to_string(),
+                provider,
+                explicit_format: None,
+                format_candidates: self.default_input_formats.clone(),
+            });
+        }
+
+        #[cfg(feature = "http")]
+        if raw.starts_with("http://") || raw.starts_with("https://") {
+            let provider: Arc<dyn InputProvider> = Arc::new(crate::io::HttpInput::new(raw));
+            return Ok(InputSpec {
+                raw: raw.to_string(),
+                provider,
+                explicit_format: self.infer_format_from_url(raw),
+                format_candidates: self.default_input_formats.clone(),
+            });
+        }
+
+        #[cfg(feature = "ssh")]
+        if raw.starts_with("ssh://") {
+            let explicit = self.infer_format_from_path(Path::new(url_path(raw)));
+            let provider: Arc<dyn InputProvider> =
+                Arc::new(crate::io::SftpInput::new(raw).map_err(|e| SingleIoError {
+                    attempts: 1,
+                    stage: Stage::ResolveInput,
+                    target: raw.to_string(),
+                    error: Box::new(e),
+                })?);
+            return Ok(InputSpec {
+                raw: raw.to_string(),
+                provider,
+                explicit_format: explicit,
+                format_candidates: self.default_input_formats.clone(),
+            });
+        }
+
+        if let Some(result) = self.scheme_registry.resolve_input(raw) {
+            let provider = result.map_err(|e| SingleIoError {
+                attempts: 1,
+                stage: Stage::ResolveInput,
+                target: raw.to_string(),
+                error: Box::new(e),
+            })?;
+            return Ok(InputSpec {
+                raw: raw.to_string(),
+                provider,
+                explicit_format: None,
+                format_candidates: self.default_input_formats.clone(),
+            });
+        }
+
         if let Some(content) = raw.strip_prefix('=') {
             use std::hash::{Hash, Hasher};
 
@@ -204,9 +356,7 @@ impl MultiioBuilder {
             });
         }
 
-        let path = PathBuf::from(raw);
-        let provider: Arc<dyn InputProvider> = Arc::new(FileInput::new(path.clone()));
-        let explicit = self.infer_format_from_path(&path);
+        let (provider, explicit) = self.file_input_provider(raw);
 
         Ok(InputSpec {
             raw: raw.to_string(),
@@ -245,6 +395,7 @@ impl MultiioBuilder {
         if let Some(path) = raw.strip_prefix('@') {
             if path.is_empty() {
                 return Err(SingleIoError {
+                    attempts: 1,
                     stage: Stage::ResolveOutput,
                     target: raw.to_string(),
                     error: Box::new(std::io::Error::new(
@@ -254,16 +405,16 @@ impl MultiioBuilder {
                 });
             }
 
-            let path = PathBuf::from(path);
-            let target: Arc<dyn OutputTarget> = Arc::new(FileOutput::new(path.clone()));
-            let explicit = self.infer_format_from_path(&path);
+            let (target, explicit) = self.file_output_target(path);
 
             return Ok(OutputSpec {
-                raw: path.to_string_lossy().into_owned(),
+                raw: path.to_string(),
                 target,
                 explicit_format: explicit,
                 format_candidates: self.default_output_formats.clone(),
                 file_exists_policy: self.file_exists_policy,
+                output_options: self.output_options.clone(),
+                file_mode: self.file_mode,
             });
         }
 
@@ -274,6 +425,8 @@ impl MultiioBuilder {
                 explicit_format: None,
                 format_candidates: self.default_output_formats.clone(),
                 file_exists_policy: self.file_exists_policy,
+                output_options: self.output_options.clone(),
+                file_mode: None,
             });
         }
 
@@ -284,13 +437,119 @@ impl MultiioBuilder {
                 explicit_format: None,
                 format_candidates: self.default_output_formats.clone(),
                 file_exists_policy: self.file_exists_policy,
+                output_options: self.output_options.clone(),
+                file_mode: None,
             });
         }
 
-        let path = PathBuf::from(raw);
-        let target: Arc<dyn OutputTarget> = Arc::new(FileOutput::new(path.clone()));
+        if let Some(command_line) = raw.strip_prefix('!') {
+            let (program, args) =
+                parse_command_line(command_line).ok_or_else(|| SingleIoError {
+                    attempts: 1,
+                    stage: Stage::ResolveOutput,
+                    target: raw.to_string(),
+                    error: Box::new(std::io::Error::new(
+                        std::io::ErrorKind::InvalidInput,
+                        "expected a command after '!'",
+                    )),
+                })?;
 
-        let explicit = self.infer_format_from_path(&path);
+            let target: Arc<dyn OutputTarget> =
+                Arc::new(ProcessOutput::new(program).with_args(args));
+
+            return Ok(OutputSpec {
+                raw: command_line.to_string(),
+                target,
+                explicit_format: None,
+                format_candidates: self.default_output_formats.clone(),
+                file_exists_policy: self.file_exists_policy,
+                output_options: self.output_options.clone(),
+                file_mode: None,
+            });
+        }
+
+        if let Some(addr) = raw.strip_prefix("tcp://") {
+            let target: Arc<dyn OutputTarget> = Arc::new(SocketOutput::tcp(addr));
+            return Ok(OutputSpec {
+                raw: raw.to_string(),
+                target,
+                explicit_format: None,
+                format_candidates: self.default_output_formats.clone(),
+                file_exists_policy: self.file_exists_policy,
+                output_options: self.output_options.clone(),
+                file_mode: None,
+            });
+        }
+
+        #[cfg(unix)]
+        if let Some(path) = raw.strip_prefix("unix:") {
+            let target: Arc<dyn OutputTarget> = Arc::new(SocketOutput::unix(path));
+            return Ok(OutputSpec {
+                raw: raw.to_string(),
+                target,
+                explicit_format: None,
+                format_candidates: self.default_output_formats.clone(),
+                file_exists_policy: self.file_exists_policy,
+                output_options: self.output_options.clone(),
+                file_mode: None,
+            });
+        }
+
+        #[cfg(feature = "http")]
+        if raw.starts_with("http://") || raw.starts_with("https://") {
+            let target: Arc<dyn OutputTarget> = Arc::new(crate::io::HttpOutput::new(raw));
+            let explicit = self.infer_format_from_path(Path::new(url_path(raw)));
+            return Ok(OutputSpec {
+                raw: raw.to_string(),
+                target,
+                explicit_format: explicit,
+                format_candidates: self.default_output_formats.clone(),
+                file_exists_policy: self.file_exists_policy,
+                output_options: self.output_options.clone(),
+                file_mode: None,
+            });
+        }
+
+        #[cfg(feature = "ssh")]
+        if raw.starts_with("ssh://") {
+            let explicit = self.infer_format_from_path(Path::new(url_path(raw)));
+            let target: Arc<dyn OutputTarget> =
+                Arc::new(crate::io::SftpOutput::new(raw).map_err(|e| SingleIoError {
+                    attempts: 1,
+                    stage: Stage::ResolveOutput,
+                    target: raw.to_string(),
+                    error: Box::new(e),
+                })?);
+            return Ok(OutputSpec {
+                raw: raw.to_string(),
+                target,
+                explicit_format: explicit,
+                format_candidates: self.default_output_formats.clone(),
+                file_exists_policy: self.file_exists_policy,
+                output_options: self.output_options.clone(),
+                file_mode: None,
+            });
+        }
+
+        if let Some(result) = self.scheme_registry.resolve_output(raw) {
+            let target = result.map_err(|e| SingleIoError {
+                attempts: 1,
+                stage: Stage::ResolveOutput,
+                target: raw.to_string(),
+                error: Box::new(e),
+            })?;
+            return Ok(OutputSpec {
+                raw: raw.to_string(),
+                target,
+                explicit_format: None,
+                format_candidates: self.default_output_formats.clone(),
+                file_exists_policy: self.file_exists_policy,
+                output_options: self.output_options.clone(),
+                file_mode: None,
+            });
+        }
+
+        let (target, explicit) = self.file_output_target(raw);
 
         Ok(OutputSpec {
             raw: raw.to_string(),
@@ -298,6 +557,8 @@ impl MultiioBuilder {
             explicit_format: explicit,
             format_candidates: self.default_output_formats.clone(),
             file_exists_policy: self.file_exists_policy,
+            output_options: self.output_options.clone(),
+            file_mode: self.file_mode,
         })
     }
 
@@ -309,6 +570,136 @@ impl MultiioBuilder {
             .and_then(|ext| self.registry.kind_for_extension(ext))
     }
 
+    /// Infers a format for a `http(s)://` input URL, first from its path's
+    /// extension (e.g. `.../data.json`), falling back to a `HEAD` request's
+    /// `Content-Type` header when the path has none or it isn't recognized.
+    /// The `Content-Type` probe is best-effort: a network failure just leaves
+    /// the format unresolved, for `format_candidates` to sniff at read time.
+    #[cfg(feature = "http")]
+    fn infer_format_from_url(&self, url: &str) -> Option<FormatKind> {
+        if let Some(format) = self.infer_format_from_path(Path::new(url_path(url))) {
+            return Some(format);
+        }
+        let content_type = crate::io::probe_content_type(url, None)?;
+        mime_type_extension(&content_type).and_then(|ext| self.registry.kind_for_extension(ext))
+    }
+
+    /// Builds a file-backed input provider for `path_str`, transparently
+    /// wrapping it in decompression when the path carries a recognized
+    /// compression extension (`.gz`/`.zst`/`.zip`/`.bz2`, see
+    /// `Compression::detect`). Format inference runs against the
+    /// *decompressed* name (the zip entry, or the path with its compression
+    /// suffix stripped) so e.g. `config.json.gz` still resolves to JSON.
+    #[cfg(feature = "compression")]
+    fn file_input_provider(&self, path_str: &str) -> (Arc<dyn InputProvider>, Option<FormatKind>) {
+        match crate::io::Compression::detect(path_str) {
+            Some((compression, format_hint)) => {
+                let fs_path = path_str.split_once('#').map(|(p, _)| p).unwrap_or(path_str);
+                let provider: Arc<dyn InputProvider> = Arc::new(crate::io::CompressedInput::new(
+                    Arc::new(FileInput::new(PathBuf::from(fs_path))),
+                    compression,
+                ));
+                let provider = self.wrap_input_encryption(provider);
+                let explicit = self.infer_format_from_path(Path::new(&format_hint));
+                (provider, explicit)
+            }
+            None => {
+                let path = PathBuf::from(path_str);
+                let provider: Arc<dyn InputProvider> = Arc::new(FileInput::new(path.clone()));
+                let provider = self.wrap_input_encryption(provider);
+                let explicit = self.infer_format_from_path(&path);
+                (provider, explicit)
+            }
+        }
+    }
+
+    #[cfg(not(feature = "compression"))]
+    fn file_input_provider(&self, path_str: &str) -> (Arc<dyn InputProvider>, Option<FormatKind>) {
+        let path = PathBuf::from(path_str);
+        let provider: Arc<dyn InputProvider> = Arc::new(FileInput::new(path.clone()));
+        let provider = self.wrap_input_encryption(provider);
+        let explicit = self.infer_format_from_path(&path);
+        (provider, explicit)
+    }
+
+    /// Wraps `provider` in [`crate::io::EncryptedInput`] when this builder has
+    /// an encryption key configured; a no-op otherwise (and when the
+    /// `encryption` feature is disabled).
+    #[cfg(feature = "encryption")]
+    fn wrap_input_encryption(&self, provider: Arc<dyn InputProvider>) -> Arc<dyn InputProvider> {
+        match &self.encryption_key {
+            Some(key) => Arc::new(crate::io::EncryptedInput::new(provider, key.clone())),
+            None => provider,
+        }
+    }
+
+    #[cfg(not(feature = "encryption"))]
+    fn wrap_input_encryption(&self, provider: Arc<dyn InputProvider>) -> Arc<dyn InputProvider> {
+        provider
+    }
+
+    /// Wraps `target` in [`crate::io::EncryptedOutput`] when this builder has
+    /// an encryption key configured; a no-op otherwise (and when the
+    /// `encryption` feature is disabled).
+    #[cfg(feature = "encryption")]
+    fn wrap_output_encryption(&self, target: Arc<dyn OutputTarget>) -> Arc<dyn OutputTarget> {
+        match &self.encryption_key {
+            Some(key) => Arc::new(crate::io::EncryptedOutput::new(target, key.clone())),
+            None => target,
+        }
+    }
+
+    #[cfg(not(feature = "encryption"))]
+    fn wrap_output_encryption(&self, target: Arc<dyn OutputTarget>) -> Arc<dyn OutputTarget> {
+        target
+    }
+
+    /// Builds a file-backed output target for `path_str`, transparently
+    /// wrapping it in compression when the path carries a recognized
+    /// compression extension. See `file_input_provider` for how format
+    /// inference accounts for the compression suffix / zip entry.
+    #[cfg(feature = "compression")]
+    fn file_output_target(&self, path_str: &str) -> (Arc<dyn OutputTarget>, Option<FormatKind>) {
+        match crate::io::Compression::detect(path_str) {
+            Some((compression, format_hint)) => {
+                let fs_path = path_str.split_once('#').map(|(p, _)| p).unwrap_or(path_str);
+                let mut file = FileOutput::new(PathBuf::from(fs_path));
+                if let Some(mode) = self.file_mode {
+                    file = file.with_mode(mode);
+                }
+                let target: Arc<dyn OutputTarget> =
+                    Arc::new(crate::io::CompressedOutput::new(Arc::new(file), compression));
+                let target = self.wrap_output_encryption(target);
+                let explicit = self.infer_format_from_path(Path::new(&format_hint));
+                (target, explicit)
+            }
+            None => {
+                let path = PathBuf::from(path_str);
+                let mut file = FileOutput::new(path.clone());
+                if let Some(mode) = self.file_mode {
+                    file = file.with_mode(mode);
+                }
+                let target: Arc<dyn OutputTarget> = Arc::new(file);
+                let target = self.wrap_output_encryption(target);
+                let explicit = self.infer_format_from_path(&path);
+                (target, explicit)
+            }
+        }
+    }
+
+    #[cfg(not(feature = "compression"))]
+    fn file_output_target(&self, path_str: &str) -> (Arc<dyn OutputTarget>, Option<FormatKind>) {
+        let path = PathBuf::from(path_str);
+        let mut file = FileOutput::new(path.clone());
+        if let Some(mode) = self.file_mode {
+            file = file.with_mode(mode);
+        }
+        let target: Arc<dyn OutputTarget> = Arc::new(file);
+        let target = self.wrap_output_encryption(target);
+        let explicit = self.infer_format_from_path(&path);
+        (target, explicit)
+    }
+
     pub fn from_pipeline_config(
         config: PipelineConfig,
         registry: FormatRegistry,
@@ -368,6 +759,7 @@ impl MultiioBuilder {
             "stdin" | "-" => Arc::new(StdinInput::new()),
             "file" => {
                 let path = cfg.path.as_ref().ok_or_else(|| SingleIoError {
+                    attempts: 1,
                     stage: Stage::ResolveInput,
                     target: cfg.id.clone(),
                     error: Box::new(std::io::Error::new(
@@ -377,8 +769,72 @@ impl MultiioBuilder {
                 })?;
                 Arc::new(FileInput::new(PathBuf::from(path)))
             }
+            "command" => {
+                let command = cfg.path.as_ref().ok_or_else(|| SingleIoError {
+                    attempts: 1,
+                    stage: Stage::ResolveInput,
+                    target: cfg.id.clone(),
+                    error: Box::new(std::io::Error::new(
+                        std::io::ErrorKind::InvalidInput,
+                        "command input requires 'path' field holding the command line",
+                    )),
+                })?;
+                let (program, args) = parse_command_line(command).ok_or_else(|| SingleIoError {
+                    attempts: 1,
+                    stage: Stage::ResolveInput,
+                    target: cfg.id.clone(),
+                    error: Box::new(std::io::Error::new(
+                        std::io::ErrorKind::InvalidInput,
+                        "command input's 'path' field must not be empty",
+                    )),
+                })?;
+                Arc::new(ProcessInput::new(program).with_args(args))
+            }
+            "tcp" => {
+                let addr = cfg.path.as_ref().ok_or_else(|| SingleIoError {
+                    attempts: 1,
+                    stage: Stage::ResolveInput,
+                    target: cfg.id.clone(),
+                    error: Box::new(std::io::Error::new(
+                        std::io::ErrorKind::InvalidInput,
+                        "tcp input requires 'path' field holding the host:port",
+                    )),
+                })?;
+                Arc::new(SocketInput::tcp(addr))
+            }
+            #[cfg(unix)]
+            "unix" => {
+                let path = cfg.path.as_ref().ok_or_else(|| SingleIoError {
+                    attempts: 1,
+                    stage: Stage::ResolveInput,
+                    target: cfg.id.clone(),
+                    error: Box::new(std::io::Error::new(
+                        std::io::ErrorKind::InvalidInput,
+                        "unix input requires 'path' field holding the socket path",
+                    )),
+                })?;
+                Arc::new(SocketInput::unix(path))
+            }
+            #[cfg(feature = "http")]
+            "http" => {
+                let url = cfg.url.as_ref().ok_or_else(|| SingleIoError {
+                    attempts: 1,
+                    stage: Stage::ResolveInput,
+                    target: cfg.id.clone(),
+                    error: Box::new(std::io::Error::new(
+                        std::io::ErrorKind::InvalidInput,
+                        "http input requires 'url' field",
+                    )),
+                })?;
+                let mut input = crate::io::HttpInput::new(url.clone());
+                for (name, value) in &cfg.headers {
+                    input = input.with_header(name.clone(), value.clone());
+                }
+                Arc::new(input)
+            }
             other => {
                 return Err(SingleIoError {
+                    attempts: 1,
                     stage: Stage::ResolveInput,
                     target: cfg.id.clone(),
                     error: Box::new(std::io::Error::new(
@@ -389,6 +845,16 @@ impl MultiioBuilder {
             }
         };
 
+        #[cfg(feature = "encryption")]
+        let provider = match cfg
+            .encryption_key
+            .as_deref()
+            .and_then(crate::io::SecretKey::from_hex)
+        {
+            Some(key) => Arc::new(crate::io::EncryptedInput::new(provider, key)) as Arc<dyn InputProvider>,
+            None => provider,
+        };
+
         let explicit_format = cfg
             .format
             .as_ref()
@@ -403,11 +869,18 @@ impl MultiioBuilder {
     }
 
     fn output_from_config(&self, cfg: &OutputConfig) -> Result<OutputSpec, SingleIoError> {
+        let file_mode = cfg
+            .file_mode
+            .as_deref()
+            .and_then(parse_file_mode)
+            .or(self.file_mode);
+
         let target: Arc<dyn OutputTarget> = match cfg.kind.as_str() {
             "stdout" | "-" => Arc::new(StdoutOutput::new()),
             "stderr" => Arc::new(crate::io::StderrOutput::new()),
             "file" => {
                 let path = cfg.path.as_ref().ok_or_else(|| SingleIoError {
+                    attempts: 1,
                     stage: Stage::ResolveOutput,
                     target: cfg.id.clone(),
                     error: Box::new(std::io::Error::new(
@@ -415,10 +888,78 @@ impl MultiioBuilder {
                         "file output requires 'path' field",
                     )),
                 })?;
-                Arc::new(FileOutput::new(PathBuf::from(path)))
+                let mut file = FileOutput::new(PathBuf::from(path));
+                if let Some(mode) = file_mode {
+                    file = file.with_mode(mode);
+                }
+                Arc::new(file)
+            }
+            "command" => {
+                let command = cfg.path.as_ref().ok_or_else(|| SingleIoError {
+                    attempts: 1,
+                    stage: Stage::ResolveOutput,
+                    target: cfg.id.clone(),
+                    error: Box::new(std::io::Error::new(
+                        std::io::ErrorKind::InvalidInput,
+                        "command output requires 'path' field holding the command line",
+                    )),
+                })?;
+                let (program, args) = parse_command_line(command).ok_or_else(|| SingleIoError {
+                    attempts: 1,
+                    stage: Stage::ResolveOutput,
+                    target: cfg.id.clone(),
+                    error: Box::new(std::io::Error::new(
+                        std::io::ErrorKind::InvalidInput,
+                        "command output's 'path' field must not be empty",
+                    )),
+                })?;
+                Arc::new(ProcessOutput::new(program).with_args(args))
+            }
+            "tcp" => {
+                let addr = cfg.path.as_ref().ok_or_else(|| SingleIoError {
+                    attempts: 1,
+                    stage: Stage::ResolveOutput,
+                    target: cfg.id.clone(),
+                    error: Box::new(std::io::Error::new(
+                        std::io::ErrorKind::InvalidInput,
+                        "tcp output requires 'path' field holding the host:port",
+                    )),
+                })?;
+                Arc::new(SocketOutput::tcp(addr))
+            }
+            #[cfg(unix)]
+            "unix" => {
+                let path = cfg.path.as_ref().ok_or_else(|| SingleIoError {
+                    attempts: 1,
+                    stage: Stage::ResolveOutput,
+                    target: cfg.id.clone(),
+                    error: Box::new(std::io::Error::new(
+                        std::io::ErrorKind::InvalidInput,
+                        "unix output requires 'path' field holding the socket path",
+                    )),
+                })?;
+                Arc::new(SocketOutput::unix(path))
+            }
+            #[cfg(feature = "http")]
+            "http" => {
+                let url = cfg.url.as_ref().ok_or_else(|| SingleIoError {
+                    attempts: 1,
+                    stage: Stage::ResolveOutput,
+                    target: cfg.id.clone(),
+                    error: Box::new(std::io::Error::new(
+                        std::io::ErrorKind::InvalidInput,
+                        "http output requires 'url' field",
+                    )),
+                })?;
+                let mut output = crate::io::HttpOutput::new(url.clone());
+                for (name, value) in &cfg.headers {
+                    output = output.with_header(name.clone(), value.clone());
+                }
+                Arc::new(output)
             }
             other => {
                 return Err(SingleIoError {
+                    attempts: 1,
                     stage: Stage::ResolveOutput,
                     target: cfg.id.clone(),
                     error: Box::new(std::io::Error::new(
@@ -429,6 +970,16 @@ impl MultiioBuilder {
             }
         };
 
+        #[cfg(feature = "encryption")]
+        let target = match cfg
+            .encryption_key
+            .as_deref()
+            .and_then(crate::io::SecretKey::from_hex)
+        {
+            Some(key) => Arc::new(crate::io::EncryptedOutput::new(target, key)) as Arc<dyn OutputTarget>,
+            None => target,
+        };
+
         let explicit_format = cfg
             .format
             .as_ref()
@@ -440,12 +991,17 @@ impl MultiioBuilder {
             .and_then(|s| s.parse::<FileExistsPolicy>().ok())
             .unwrap_or(self.file_exists_policy);
 
+        let output_options =
+            output_options_from_config(cfg).or_else(|| self.output_options.clone());
+
         Ok(OutputSpec {
             raw: cfg.id.clone(),
             target,
             explicit_format,
             format_candidates: self.default_output_formats.clone(),
             file_exists_policy,
+            output_options,
+            file_mode,
         })
     }
 }
@@ -456,10 +1012,78 @@ impl Default for MultiioBuilder {
     }
 }
 
+/// Build an `OutputOptions` from an `OutputConfig`'s `style`/`indent`/
+/// `key_order` fields, returning `None` if none of them were set.
+pub(crate) fn output_options_from_config(cfg: &OutputConfig) -> Option<OutputOptions> {
+    if cfg.style.is_none() && cfg.indent.is_none() && cfg.key_order.is_none() {
+        return None;
+    }
+
+    let mut options = OutputOptions::default();
+    if let Some(style) = cfg.style.as_ref().and_then(|s| OutputStyle::from_str(s)) {
+        options.style = style;
+    }
+    if let Some(indent) = cfg.indent.as_ref() {
+        options = options.with_indent(indent.clone());
+    }
+    if let Some(key_order) = cfg.key_order.as_ref().and_then(|s| KeyOrder::from_str(s)) {
+        options = options.with_key_order(key_order);
+    }
+    Some(options)
+}
+
+/// Split a `!cmd arg1 arg2` command line into a program and its arguments on
+/// whitespace. Returns `None` if the line is empty after trimming.
+pub(crate) fn parse_command_line(command_line: &str) -> Option<(String, Vec<String>)> {
+    let mut parts = command_line.split_whitespace();
+    let program = parts.next()?.to_string();
+    let args = parts.map(String::from).collect();
+    Some((program, args))
+}
+
+/// Parses a Unix permission string like `"600"`, `"0600"`, or `"0o600"` as
+/// octal. Returns `None` for anything that doesn't parse, so a malformed
+/// `file_mode` config value is silently ignored rather than rejected, and
+/// the output falls back to the builder-wide `with_file_mode` value, if any.
+fn parse_file_mode(s: &str) -> Option<u32> {
+    let digits = s.strip_prefix("0o").unwrap_or(s);
+    u32::from_str_radix(digits, 8).ok()
+}
+
+/// Extracts the path component of a `scheme://host/path?query#fragment` URL
+/// (no trailing query/fragment), without pulling in a full URL-parsing
+/// dependency for this one use.
+#[cfg(any(feature = "http", feature = "ssh"))]
+fn url_path(url: &str) -> &str {
+    let after_scheme = url.split_once("://").map(|(_, rest)| rest).unwrap_or(url);
+    let path = after_scheme.split_once('/').map_or("", |(_, path)| path);
+    path.split(['?', '#']).next().unwrap_or(path)
+}
+
+/// Maps a `Content-Type` header value (already stripped of `; charset=...`
+/// and lowercased, see `io::probe_content_type`) to the file extension
+/// `FormatRegistry::kind_for_extension` would recognize.
+#[cfg(feature = "http")]
+fn mime_type_extension(content_type: &str) -> Option<&'static str> {
+    match content_type {
+        "application/json" => Some("json"),
+        "application/x-ndjson" | "application/jsonlines" | "application/x-jsonlines" => {
+            Some("ndjson")
+        }
+        "text/csv" => Some("csv"),
+        "application/yaml" | "application/x-yaml" | "text/yaml" | "text/x-yaml" => Some("yaml"),
+        "application/toml" => Some("toml"),
+        "application/xml" | "text/xml" => Some("xml"),
+        "text/markdown" => Some("md"),
+        "text/plain" => Some("txt"),
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::format::{DEFAULT_FORMAT_ORDER, FormatKind, default_registry};
+    use crate::format::{default_registry, FormatKind, DEFAULT_FORMAT_ORDER};
 
     #[test]
     fn builder_defaults_match_default_format_order() {
@@ -515,4 +1139,133 @@ mod tests {
         assert_eq!(forced_path.target.id(), "out.txt");
         assert_eq!(forced_path.explicit_format, Some(FormatKind::Plaintext));
     }
+
+    #[cfg(feature = "compression")]
+    #[test]
+    fn resolve_single_input_infers_format_through_compression_suffix() {
+        let builder = MultiioBuilder::default();
+
+        let gz = builder
+            .resolve_single_input("config.json.gz")
+            .expect("gz spec");
+        assert_eq!(gz.explicit_format, Some(FormatKind::Json));
+
+        let zip_entry = builder
+            .resolve_single_input("archive.zip#data.csv")
+            .expect("zip entry spec");
+        assert_eq!(zip_entry.explicit_format, Some(FormatKind::Csv));
+    }
+
+    #[cfg(feature = "http")]
+    #[test]
+    fn resolve_single_input_and_output_recognize_http_schemes() {
+        let builder = MultiioBuilder::default();
+
+        let input = builder
+            .resolve_single_input("https://api.example.com/data.json")
+            .expect("http input spec");
+        assert_eq!(input.raw, "https://api.example.com/data.json");
+        assert_eq!(input.explicit_format, Some(FormatKind::Json));
+
+        let output = builder
+            .resolve_single_output("http://example.com/out.csv")
+            .expect("http output spec");
+        assert_eq!(output.raw, "http://example.com/out.csv");
+        assert_eq!(output.explicit_format, Some(FormatKind::Csv));
+    }
+
+    #[cfg(feature = "http")]
+    #[test]
+    fn url_path_strips_scheme_host_query_and_fragment() {
+        assert_eq!(
+            url_path("https://api.example.com/v1/data.json?since=2024#top"),
+            "v1/data.json"
+        );
+        assert_eq!(url_path("https://api.example.com"), "");
+    }
+
+    #[test]
+    fn registered_input_scheme_resolves_before_file_fallback() {
+        use crate::io::InMemorySource;
+
+        let builder = MultiioBuilder::default().register_input_scheme("mem", |rest| {
+            Ok(Arc::new(InMemorySource::from_string(
+                rest.to_string(),
+                format!("contents of {rest}"),
+            )))
+        });
+
+        let spec = builder
+            .resolve_single_input("mem://widgets")
+            .expect("scheme spec");
+        assert_eq!(spec.raw, "mem://widgets");
+        assert_eq!(spec.provider.id(), "widgets");
+    }
+
+    #[test]
+    fn registered_output_scheme_resolves_before_file_fallback() {
+        use crate::io::InMemorySink;
+
+        let builder = MultiioBuilder::default().register_output_scheme("mem", |rest| {
+            Ok(Arc::new(InMemorySink::new(rest.to_string())))
+        });
+
+        let spec = builder
+            .resolve_single_output("mem://widgets")
+            .expect("scheme spec");
+        assert_eq!(spec.raw, "mem://widgets");
+        assert_eq!(spec.target.id(), "widgets");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn with_file_mode_threads_into_resolved_file_output_spec() {
+        let builder = MultiioBuilder::default().with_file_mode(0o600);
+
+        let spec = builder
+            .resolve_single_output("out.txt")
+            .expect("file output spec");
+        assert_eq!(spec.file_mode, Some(0o600));
+
+        let stdout = builder
+            .resolve_single_output("stdout")
+            .expect("stdout spec");
+        assert_eq!(stdout.file_mode, None);
+    }
+
+    #[test]
+    fn parse_file_mode_accepts_bare_and_prefixed_octal() {
+        assert_eq!(parse_file_mode("600"), Some(0o600));
+        assert_eq!(parse_file_mode("0600"), Some(0o600));
+        assert_eq!(parse_file_mode("0o600"), Some(0o600));
+        assert_eq!(parse_file_mode("not-a-mode"), None);
+    }
+
+    #[cfg(feature = "encryption")]
+    #[test]
+    fn with_encryption_wraps_resolved_file_targets() {
+        let key = crate::io::SecretKey::generate();
+        let builder = MultiioBuilder::default().with_encryption(key);
+
+        let input = builder
+            .resolve_single_input("in.txt")
+            .expect("file input spec");
+        assert_eq!(input.provider.id(), "in.txt");
+
+        let output = builder
+            .resolve_single_output("out.txt")
+            .expect("file output spec");
+        assert_eq!(output.target.id(), "out.txt");
+    }
+
+    #[test]
+    fn unregistered_scheme_falls_back_to_file_resolution() {
+        let builder = MultiioBuilder::default();
+
+        let spec = builder
+            .resolve_single_input("s3://bucket/key")
+            .expect("falls back to file provider");
+        assert_eq!(spec.raw, "s3://bucket/key");
+        assert_eq!(spec.provider.id(), "s3://bucket/key");
+    }
 }
@@ -10,15 +10,126 @@ use crate::engine_async::AsyncIoEngine;
 use crate::error::{AggregateError, ErrorPolicy, SingleIoError, Stage};
 #[cfg(feature = "custom")]
 use crate::format::CustomFormat;
+#[cfg(feature = "custom")]
+use crate::format::AsyncCustomFormat;
 use crate::format::{
-    AsyncFormatRegistry, DEFAULT_FORMAT_ORDER, FormatKind, FormatRegistry, default_async_registry,
-    default_registry,
+    default_async_registry, default_registry, AsyncFormatRegistry, FormatKind, FormatRegistry,
+    OutputOptions, DEFAULT_FORMAT_ORDER,
 };
 use crate::io::{
     AsyncFileInput, AsyncFileOutput, AsyncInMemorySource, AsyncInputProvider, AsyncOutputTarget,
-    AsyncStderrOutput, AsyncStdinInput, AsyncStdoutOutput,
+    AsyncProcessInput, AsyncProcessOutput, AsyncSchemeRegistry, AsyncSocketInput, AsyncSocketOutput,
+    AsyncStderrOutput, AsyncStdinInput, AsyncStdoutOutput, AsyncTransformInput, AsyncTransformOutput,
 };
 
+/// Does `s` contain a glob metacharacter (`*`, `?`, `[`)?
+fn is_glob_pattern(s: &str) -> bool {
+    s.contains(['*', '?', '['])
+}
+
+/// Splits `raw` on a `" | "`-delimited pipe stage, used by
+/// `resolve_single_input`/`resolve_single_output` to route a source/target
+/// through an external command via `AsyncTransformInput`/`AsyncTransformOutput`.
+///
+/// Requires surrounding spaces (rather than a bare `|`) so a path or inline
+/// `=content` that happens to contain a literal `|` isn't misread as a pipe
+/// stage.
+fn split_pipe_stage(raw: &str) -> Option<(&str, &str)> {
+    raw.split_once(" | ")
+        .map(|(left, right)| (left.trim(), right.trim()))
+        .filter(|(left, right)| !left.is_empty() && !right.is_empty())
+}
+
+/// Match a single `/`-free path segment against a glob pattern segment.
+/// Supports `*` (any run of characters), `?` (any single character), and
+/// `[...]` character classes (with `!`/`^` negation and `a-z` ranges) —
+/// POSIX `fnmatch` style. Crossing `/` is handled one level up, by `**` in
+/// [`glob_path_match`].
+fn glob_segment_match(pattern: &str, text: &str) -> bool {
+    fn match_here(p: &[char], t: &[char]) -> bool {
+        match p.first() {
+            None => t.is_empty(),
+            Some('*') => match_here(&p[1..], t) || (!t.is_empty() && match_here(p, &t[1..])),
+            Some('?') => !t.is_empty() && match_here(&p[1..], &t[1..]),
+            Some('[') => {
+                let Some(close) = p.iter().skip(1).position(|&c| c == ']').map(|i| i + 1) else {
+                    // No closing bracket: treat '[' as a literal character.
+                    return !t.is_empty() && t[0] == '[' && match_here(&p[1..], &t[1..]);
+                };
+                if t.is_empty() {
+                    return false;
+                }
+                let class = &p[1..close];
+                let (negate, class) = match class.first() {
+                    Some('!') | Some('^') => (true, &class[1..]),
+                    _ => (false, class),
+                };
+                let mut matched = false;
+                let mut i = 0;
+                while i < class.len() {
+                    if i + 2 < class.len() && class[i + 1] == '-' {
+                        if class[i] <= t[0] && t[0] <= class[i + 2] {
+                            matched = true;
+                        }
+                        i += 3;
+                    } else {
+                        if class[i] == t[0] {
+                            matched = true;
+                        }
+                        i += 1;
+                    }
+                }
+                matched != negate && match_here(&p[close + 1..], &t[1..])
+            }
+            Some(&c) => !t.is_empty() && t[0] == c && match_here(&p[1..], &t[1..]),
+        }
+    }
+
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    match_here(&pattern, &text)
+}
+
+/// Match a `/`-separated glob pattern (whose segments may be `**`, matching
+/// zero or more whole path components) against a `/`-separated relative
+/// path, the way shells expand e.g. `data/**/*.yaml`.
+fn glob_path_match(pattern: &[&str], path: &[&str]) -> bool {
+    match pattern.split_first() {
+        None => path.is_empty(),
+        Some((&"**", rest)) => {
+            glob_path_match(rest, path)
+                || matches!(path.split_first(), Some((_, tail)) if glob_path_match(pattern, tail))
+        }
+        Some((seg, rest)) => matches!(
+            path.split_first(),
+            Some((p, tail)) if glob_segment_match(seg, p) && glob_path_match(rest, tail)
+        ),
+    }
+}
+
+/// Recursively collect every regular file under the directory `root`, sorted
+/// for deterministic ordering since directory iteration order isn't
+/// guaranteed. A `root` that isn't a directory returns `read_dir`'s error.
+async fn walk_files_recursive(root: &Path) -> std::io::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    let mut dirs = vec![root.to_path_buf()];
+
+    while let Some(dir) = dirs.pop() {
+        let mut entries = tokio::fs::read_dir(&dir).await?;
+        while let Some(entry) = entries.next_entry().await? {
+            let file_type = entry.file_type().await?;
+            if file_type.is_dir() {
+                dirs.push(entry.path());
+            } else if file_type.is_file() {
+                files.push(entry.path());
+            }
+        }
+    }
+
+    files.sort();
+    Ok(files)
+}
+
 pub struct MultiioAsyncBuilder {
     input_args: Vec<String>,
     output_args: Vec<String>,
@@ -26,10 +137,14 @@ pub struct MultiioAsyncBuilder {
     output_specs: Vec<AsyncOutputSpec>,
     registry: AsyncFormatRegistry,
     sync_registry: FormatRegistry,
+    scheme_registry: AsyncSchemeRegistry,
     error_policy: ErrorPolicy,
     default_input_formats: Vec<FormatKind>,
     default_output_formats: Vec<FormatKind>,
     file_exists_policy: FileExistsPolicy,
+    output_options: Option<OutputOptions>,
+    watch: bool,
+    watch_debounce: std::time::Duration,
 }
 
 impl Default for MultiioAsyncBuilder {
@@ -47,13 +162,39 @@ impl MultiioAsyncBuilder {
             output_specs: Vec::new(),
             registry,
             sync_registry: default_registry(),
+            scheme_registry: AsyncSchemeRegistry::new(),
             error_policy: ErrorPolicy::Accumulate,
             default_input_formats: DEFAULT_FORMAT_ORDER.to_vec(),
             default_output_formats: DEFAULT_FORMAT_ORDER.to_vec(),
             file_exists_policy: FileExistsPolicy::Overwrite,
+            output_options: None,
+            watch: false,
+            watch_debounce: std::time::Duration::from_millis(200),
         }
     }
 
+    /// Register a factory for `scheme://rest` input tokens (e.g. `s3://`,
+    /// `http://`, `db://`). Every matching token resolved by
+    /// `with_input_args`/`add_input` is built via `factory(rest)` instead of
+    /// falling through to file-path resolution.
+    pub fn register_input_scheme<F>(mut self, scheme: impl Into<String>, factory: F) -> Self
+    where
+        F: Fn(&str) -> std::io::Result<Arc<dyn AsyncInputProvider>> + Send + Sync + 'static,
+    {
+        self.scheme_registry.register_input_scheme(scheme, factory);
+        self
+    }
+
+    /// Register a factory for `scheme://rest` output tokens. See
+    /// `register_input_scheme`.
+    pub fn register_output_scheme<F>(mut self, scheme: impl Into<String>, factory: F) -> Self
+    where
+        F: Fn(&str) -> std::io::Result<Arc<dyn AsyncOutputTarget>> + Send + Sync + 'static,
+    {
+        self.scheme_registry.register_output_scheme(scheme, factory);
+        self
+    }
+
     /// Replace the underlying sync `FormatRegistry` used for decoding,
     /// encoding, and streaming.
     pub fn with_sync_registry(mut self, registry: FormatRegistry) -> Self {
@@ -81,6 +222,18 @@ impl MultiioAsyncBuilder {
         self
     }
 
+    /// Register a custom format whose codec is itself async (e.g. backed by
+    /// compression or a network-assisted transform) on the async registry.
+    ///
+    /// Unlike `with_custom_format`, this does not touch the sync registry, so
+    /// it never blocks the async runtime: decoding and encoding run entirely
+    /// through the registered futures.
+    #[cfg(feature = "custom")]
+    pub fn with_async_custom_format(mut self, format: AsyncCustomFormat) -> Self {
+        self.registry.register_custom(format);
+        self
+    }
+
     pub fn inputs_from_args(mut self, args: &[String]) -> Self {
         self.input_args = args.to_vec();
         self
@@ -145,8 +298,30 @@ impl MultiioAsyncBuilder {
         self
     }
 
-    pub fn build(self) -> Result<AsyncIoEngine, AggregateError> {
-        let mut inputs = self.resolve_inputs()?;
+    /// Set the default output formatting options (pretty/compact, indent, key
+    /// order) applied to every output that doesn't override them per-spec.
+    pub fn with_output_options(mut self, options: OutputOptions) -> Self {
+        self.output_options = Some(options);
+        self
+    }
+
+    /// Mark this engine as watch-capable, i.e. intended to be driven via
+    /// `AsyncIoEngine::run_watched` rather than a one-shot `read_all`/
+    /// `write_all`. See `AsyncIoEngine::watch_enabled`.
+    pub fn with_watch(mut self, enabled: bool) -> Self {
+        self.watch = enabled;
+        self
+    }
+
+    /// Set the debounce window `run_watched` uses to coalesce a burst of
+    /// rapid file changes into a single rerun. Defaults to 200ms.
+    pub fn with_watch_debounce(mut self, debounce: std::time::Duration) -> Self {
+        self.watch_debounce = debounce;
+        self
+    }
+
+    pub async fn build(self) -> Result<AsyncIoEngine, AggregateError> {
+        let mut inputs = self.resolve_inputs().await?;
         let mut outputs = self.resolve_outputs()?;
 
         // Add pre-built specs
@@ -159,22 +334,47 @@ impl MultiioAsyncBuilder {
             self.error_policy,
             inputs,
             outputs,
-        ))
+        )
+        .with_watch(self.watch)
+        .with_watch_debounce(self.watch_debounce))
     }
 
-    fn resolve_inputs(&self) -> Result<Vec<AsyncInputSpec>, AggregateError> {
+    /// Resolve every input arg, expanding glob patterns (e.g. `logs/*.json`,
+    /// `data/**/*.yaml`) and bare existing directories (e.g. `data/`) into
+    /// one [`AsyncInputSpec`] per matched, registry-resolvable file, the way
+    /// Deno's `collect_specifiers` walks a root and filters by supported
+    /// extension. Args that are neither fall through to
+    /// [`Self::resolve_single_input`] unchanged. Errors on individual args
+    /// (including individual glob/directory expansions) respect
+    /// `error_policy` exactly like the non-expanding path below.
+    async fn resolve_inputs(&self) -> Result<Vec<AsyncInputSpec>, AggregateError> {
         let mut specs = Vec::with_capacity(self.input_args.len());
         let mut errors = Vec::new();
 
         for raw in &self.input_args {
-            match self.resolve_single_input(raw) {
-                Ok(spec) => specs.push(spec),
-                Err(e) => {
+            match self.expand_input_path(raw).await {
+                Some(Ok(paths)) => {
+                    // No registry-resolvable matches isn't an error: it's the
+                    // same "nothing here" outcome as an empty directory.
+                    for path in paths {
+                        specs.push(self.input_spec_from_path(&path.to_string_lossy()));
+                    }
+                }
+                Some(Err(e)) => {
                     errors.push(e);
                     if matches!(self.error_policy, ErrorPolicy::FastFail) {
                         return Err(AggregateError { errors });
                     }
                 }
+                None => match self.resolve_single_input(raw) {
+                    Ok(spec) => specs.push(spec),
+                    Err(e) => {
+                        errors.push(e);
+                        if matches!(self.error_policy, ErrorPolicy::FastFail) {
+                            return Err(AggregateError { errors });
+                        }
+                    }
+                },
             }
         }
 
@@ -185,12 +385,138 @@ impl MultiioAsyncBuilder {
         }
     }
 
+    /// If `raw` is a glob pattern or names an existing directory, walk it
+    /// asynchronously and return the matched files (filtered to extensions
+    /// `self.registry.kind_for_extension` can resolve), sorted for
+    /// deterministic ordering. Returns `None` for anything else (scheme
+    /// tokens, `@`/`!`/`=`-prefixed args, plain single file paths, ...) so
+    /// the caller falls back to its normal single-spec resolution.
+    async fn expand_input_path(&self, raw: &str) -> Option<Result<Vec<PathBuf>, SingleIoError>> {
+        let glob = is_glob_pattern(raw);
+        let path = Path::new(raw);
+
+        let is_dir = if glob {
+            false
+        } else {
+            tokio::fs::metadata(path)
+                .await
+                .map(|meta| meta.is_dir())
+                .unwrap_or(false)
+        };
+
+        if !glob && !is_dir {
+            return None;
+        }
+
+        let walk_result = if glob {
+            let segments: Vec<&str> = raw.split('/').collect();
+            let (root, literal_len) = Self::glob_literal_root(&segments);
+            let pattern_tail = &segments[literal_len..];
+            walk_files_recursive(&root).await.map(|files| {
+                files
+                    .into_iter()
+                    .filter(|file| {
+                        let relative = file.strip_prefix(&root).unwrap_or(file);
+                        let rel_segments: Vec<&str> =
+                            relative.iter().filter_map(|c| c.to_str()).collect();
+                        glob_path_match(pattern_tail, &rel_segments)
+                    })
+                    .collect()
+            })
+        } else {
+            walk_files_recursive(path).await
+        };
+
+        Some(
+            walk_result
+                .map(|files| self.filter_resolvable_extensions(files))
+                .map_err(|e| SingleIoError {
+                    attempts: 1,
+                    stage: Stage::ResolveInput,
+                    target: raw.to_string(),
+                    error: Box::new(e),
+                }),
+        )
+    }
+
+    /// Keep only paths whose extension resolves to a registered `FormatKind`,
+    /// so an expansion silently skips files the registry has no handler for
+    /// (a stray `.bak` or `.lock` file sitting next to the configs it does
+    /// understand) rather than surfacing them as errors.
+    fn filter_resolvable_extensions(&self, files: Vec<PathBuf>) -> Vec<PathBuf> {
+        files
+            .into_iter()
+            .filter(|path| self.infer_format_from_path(path).is_some())
+            .collect()
+    }
+
+    /// Find the longest leading run of `/`-separated segments in a glob
+    /// pattern that contains no metacharacters or `**`, and return it as a
+    /// filesystem path to walk (falling back to `.` if the pattern starts
+    /// with a metacharacter) along with how many segments it consumed.
+    fn glob_literal_root(pattern_segments: &[&str]) -> (PathBuf, usize) {
+        let mut root = PathBuf::new();
+        let mut consumed = 0;
+        for seg in pattern_segments {
+            if *seg == "**" || is_glob_pattern(seg) {
+                break;
+            }
+            root.push(seg);
+            consumed += 1;
+        }
+        let root = if root.as_os_str().is_empty() {
+            PathBuf::from(".")
+        } else {
+            root
+        };
+        (root, consumed)
+    }
+
+    /// Build an `AsyncInputSpec` for a concrete file path the same way the
+    /// plain-path fallback in `resolve_single_input` does.
+    fn input_spec_from_path(&self, path_str: &str) -> AsyncInputSpec {
+        let (provider, explicit) = self.file_input_provider(path_str);
+        AsyncInputSpec {
+            raw: path_str.to_string(),
+            provider,
+            explicit_format: explicit,
+            format_candidates: self.default_input_formats.clone(),
+        }
+    }
+
     fn resolve_single_input(&self, raw: &str) -> Result<AsyncInputSpec, SingleIoError> {
         let raw = raw.trim();
 
+        // `source | command args` pipes `source`'s resolved input through an
+        // external command before the format layer ever sees it.
+        if let Some((source, command_line)) = split_pipe_stage(raw) {
+            let upstream = self.resolve_single_input(source)?;
+            let (program, args) =
+                crate::builder::parse_command_line(command_line).ok_or_else(|| SingleIoError {
+                    attempts: 1,
+                    stage: Stage::ResolveInput,
+                    target: raw.to_string(),
+                    error: Box::new(std::io::Error::new(
+                        std::io::ErrorKind::InvalidInput,
+                        "expected a command after '|'",
+                    )),
+                })?;
+
+            let provider: Arc<dyn AsyncInputProvider> =
+                Arc::new(AsyncTransformInput::new(upstream.provider, program, args));
+
+            return Ok(AsyncInputSpec {
+                raw: raw.to_string(),
+                provider,
+                explicit_format: upstream.explicit_format,
+                format_candidates: self.default_input_formats.clone(),
+            });
+        }
+
         if let Some(path) = raw.strip_prefix('@') {
             if path.is_empty() {
                 return Err(SingleIoError {
+                    attempts: 1,
                     stage: Stage::ResolveInput,
                     target: raw.to_string(),
                     error: Box::new(std::io::Error::new(
@@ -200,12 +526,10 @@ impl MultiioAsyncBuilder {
                 });
             }
 
-            let path = PathBuf::from(path);
-            let provider: Arc<dyn AsyncInputProvider> = Arc::new(AsyncFileInput::new(path.clone()));
-            let explicit = self.infer_format_from_path(&path);
+            let (provider, explicit) = self.file_input_provider(path);
 
             return Ok(AsyncInputSpec {
-                raw: path.to_string_lossy().into_owned(),
+                raw: path.to_string(),
                 provider,
                 explicit_format: explicit,
                 format_candidates: self.default_input_formats.clone(),
@@ -221,6 +545,67 @@ impl MultiioAsyncBuilder {
             });
         }
 
+        // `cmd:` is a more self-documenting spelling of the same `!`
+        // subprocess-input prefix; both resolve to `AsyncProcessInput`.
+        if let Some(command_line) = raw.strip_prefix('!').or_else(|| raw.strip_prefix("cmd:")) {
+            let (program, args) =
+                crate::builder::parse_command_line(command_line).ok_or_else(|| SingleIoError {
+                    attempts: 1,
+                    stage: Stage::ResolveInput,
+                    target: raw.to_string(),
+                    error: Box::new(std::io::Error::new(
+                        std::io::ErrorKind::InvalidInput,
+                        "expected a command after '!' or 'cmd:'",
+                    )),
+                })?;
+
+            let provider: Arc<dyn AsyncInputProvider> =
+                Arc::new(AsyncProcessInput::new(program).with_args(args));
+
+            return Ok(AsyncInputSpec {
+                raw: command_line.to_string(),
+                provider,
+                explicit_format: None,
+                format_candidates: self.default_input_formats.clone(),
+            });
+        }
+
+        if let Some(addr) = raw.strip_prefix("tcp://") {
+            let provider: Arc<dyn AsyncInputProvider> = Arc::new(AsyncSocketInput::tcp(addr));
+            return Ok(AsyncInputSpec {
+                raw: raw.to_string(),
+                provider,
+                explicit_format: None,
+                format_candidates: self.default_input_formats.clone(),
+            });
+        }
+
+        #[cfg(unix)]
+        if let Some(path) = raw.strip_prefix("unix:") {
+            let provider: Arc<dyn AsyncInputProvider> = Arc::new(AsyncSocketInput::unix(path));
+            return Ok(AsyncInputSpec {
+                raw: raw.to_string(),
+                provider,
+                explicit_format: None,
+                format_candidates: self.default_input_formats.clone(),
+            });
+        }
+
+        if let Some(result) = self.scheme_registry.resolve_input(raw) {
+            let provider = result.map_err(|e| SingleIoError {
+                attempts: 1,
+                stage: Stage::ResolveInput,
+                target: raw.to_string(),
+                error: Box::new(e),
+            })?;
+            return Ok(AsyncInputSpec {
+                raw: raw.to_string(),
+                provider,
+                explicit_format: None,
+                format_candidates: self.default_input_formats.clone(),
+            });
+        }
+
         if let Some(content) = raw.strip_prefix('=') {
             use std::hash::{Hash, Hasher};
 
@@ -241,16 +626,7 @@ impl MultiioAsyncBuilder {
             });
         }
 
-        let path = PathBuf::from(raw);
-        let provider: Arc<dyn AsyncInputProvider> = Arc::new(AsyncFileInput::new(path.clone()));
-        let explicit = self.infer_format_from_path(&path);
-
-        Ok(AsyncInputSpec {
-            raw: raw.to_string(),
-            provider,
-            explicit_format: explicit,
-            format_candidates: self.default_input_formats.clone(),
-        })
+        Ok(self.input_spec_from_path(raw))
     }
 
     fn resolve_outputs(&self) -> Result<Vec<AsyncOutputSpec>, AggregateError> {
@@ -279,9 +655,38 @@ impl MultiioAsyncBuilder {
     fn resolve_single_output(&self, raw: &str) -> Result<AsyncOutputSpec, SingleIoError> {
         let raw = raw.trim();
 
+        // `command args | destination` pipes serialized bytes through an
+        // external command before they reach `destination`.
+        if let Some((command_line, destination)) = split_pipe_stage(raw) {
+            let (program, args) =
+                crate::builder::parse_command_line(command_line).ok_or_else(|| SingleIoError {
+                    attempts: 1,
+                    stage: Stage::ResolveOutput,
+                    target: raw.to_string(),
+                    error: Box::new(std::io::Error::new(
+                        std::io::ErrorKind::InvalidInput,
+                        "expected a command before '|'",
+                    )),
+                })?;
+            let downstream = self.resolve_single_output(destination)?;
+
+            let target: Arc<dyn AsyncOutputTarget> =
+                Arc::new(AsyncTransformOutput::new(downstream.target, program, args));
+
+            return Ok(AsyncOutputSpec {
+                raw: raw.to_string(),
+                target,
+                explicit_format: downstream.explicit_format,
+                format_candidates: self.default_output_formats.clone(),
+                file_exists_policy: self.file_exists_policy,
+                output_options: self.output_options.clone(),
+            });
+        }
+
         if let Some(path) = raw.strip_prefix('@') {
             if path.is_empty() {
                 return Err(SingleIoError {
+                    attempts: 1,
                     stage: Stage::ResolveOutput,
                     target: raw.to_string(),
                     error: Box::new(std::io::Error::new(
@@ -291,16 +696,15 @@ impl MultiioAsyncBuilder {
                 });
             }
 
-            let path = PathBuf::from(path);
-            let target: Arc<dyn AsyncOutputTarget> = Arc::new(AsyncFileOutput::new(path.clone()));
-            let explicit = self.infer_format_from_path(&path);
+            let (target, explicit) = self.file_output_target(path);
 
             return Ok(AsyncOutputSpec {
-                raw: path.to_string_lossy().into_owned(),
+                raw: path.to_string(),
                 target,
                 explicit_format: explicit,
                 format_candidates: self.default_output_formats.clone(),
                 file_exists_policy: self.file_exists_policy,
+                output_options: self.output_options.clone(),
             });
         }
 
@@ -311,6 +715,7 @@ impl MultiioAsyncBuilder {
                 explicit_format: None,
                 format_candidates: self.default_output_formats.clone(),
                 file_exists_policy: self.file_exists_policy,
+                output_options: self.output_options.clone(),
             });
         }
 
@@ -321,13 +726,80 @@ impl MultiioAsyncBuilder {
                 explicit_format: None,
                 format_candidates: self.default_output_formats.clone(),
                 file_exists_policy: self.file_exists_policy,
+                output_options: self.output_options.clone(),
             });
         }
 
-        let path = PathBuf::from(raw);
-        let target: Arc<dyn AsyncOutputTarget> = Arc::new(AsyncFileOutput::new(path.clone()));
+        // `cmd:` is a more self-documenting spelling of the same `!`
+        // subprocess-output prefix; both resolve to `AsyncProcessOutput`.
+        if let Some(command_line) = raw.strip_prefix('!').or_else(|| raw.strip_prefix("cmd:")) {
+            let (program, args) =
+                crate::builder::parse_command_line(command_line).ok_or_else(|| SingleIoError {
+                    attempts: 1,
+                    stage: Stage::ResolveOutput,
+                    target: raw.to_string(),
+                    error: Box::new(std::io::Error::new(
+                        std::io::ErrorKind::InvalidInput,
+                        "expected a command after '!' or 'cmd:'",
+                    )),
+                })?;
 
-        let explicit = self.infer_format_from_path(&path);
+            let target: Arc<dyn AsyncOutputTarget> =
+                Arc::new(AsyncProcessOutput::new(program).with_args(args));
+
+            return Ok(AsyncOutputSpec {
+                raw: command_line.to_string(),
+                target,
+                explicit_format: None,
+                format_candidates: self.default_output_formats.clone(),
+                file_exists_policy: self.file_exists_policy,
+                output_options: self.output_options.clone(),
+            });
+        }
+
+        if let Some(addr) = raw.strip_prefix("tcp://") {
+            let target: Arc<dyn AsyncOutputTarget> = Arc::new(AsyncSocketOutput::tcp(addr));
+            return Ok(AsyncOutputSpec {
+                raw: raw.to_string(),
+                target,
+                explicit_format: None,
+                format_candidates: self.default_output_formats.clone(),
+                file_exists_policy: self.file_exists_policy,
+                output_options: self.output_options.clone(),
+            });
+        }
+
+        #[cfg(unix)]
+        if let Some(path) = raw.strip_prefix("unix:") {
+            let target: Arc<dyn AsyncOutputTarget> = Arc::new(AsyncSocketOutput::unix(path));
+            return Ok(AsyncOutputSpec {
+                raw: raw.to_string(),
+                target,
+                explicit_format: None,
+                format_candidates: self.default_output_formats.clone(),
+                file_exists_policy: self.file_exists_policy,
+                output_options: self.output_options.clone(),
+            });
+        }
+
+        if let Some(result) = self.scheme_registry.resolve_output(raw) {
+            let target = result.map_err(|e| SingleIoError {
+                attempts: 1,
+                stage: Stage::ResolveOutput,
+                target: raw.to_string(),
+                error: Box::new(e),
+            })?;
+            return Ok(AsyncOutputSpec {
+                raw: raw.to_string(),
+                target,
+                explicit_format: None,
+                format_candidates: self.default_output_formats.clone(),
+                file_exists_policy: self.file_exists_policy,
+                output_options: self.output_options.clone(),
+            });
+        }
+
+        let (target, explicit) = self.file_output_target(raw);
 
         Ok(AsyncOutputSpec {
             raw: raw.to_string(),
@@ -335,6 +807,7 @@ impl MultiioAsyncBuilder {
             explicit_format: explicit,
             format_candidates: self.default_output_formats.clone(),
             file_exists_policy: self.file_exists_policy,
+            output_options: self.output_options.clone(),
         })
     }
 
@@ -346,6 +819,88 @@ impl MultiioAsyncBuilder {
             .and_then(|ext| self.registry.kind_for_extension(ext))
     }
 
+    /// Builds a file-backed async input provider for `path_str`, transparently
+    /// wrapping it in decompression when the path carries a recognized
+    /// compression extension (`.gz`/`.zst`/`.zip`/`.bz2`, see
+    /// `Compression::detect`). Format inference runs against the
+    /// *decompressed* name (the zip entry, or the path with its compression
+    /// suffix stripped) so e.g. `config.json.gz` still resolves to JSON.
+    #[cfg(feature = "compression")]
+    fn file_input_provider(
+        &self,
+        path_str: &str,
+    ) -> (Arc<dyn AsyncInputProvider>, Option<FormatKind>) {
+        match crate::io::Compression::detect(path_str) {
+            Some((compression, format_hint)) => {
+                let fs_path = path_str.split_once('#').map(|(p, _)| p).unwrap_or(path_str);
+                let provider: Arc<dyn AsyncInputProvider> =
+                    Arc::new(crate::io::AsyncCompressedInput::new(
+                        Arc::new(AsyncFileInput::new(PathBuf::from(fs_path))),
+                        compression,
+                    ));
+                let explicit = self.infer_format_from_path(Path::new(&format_hint));
+                (provider, explicit)
+            }
+            None => {
+                let path = PathBuf::from(path_str);
+                let provider: Arc<dyn AsyncInputProvider> = Arc::new(AsyncFileInput::new(path.clone()));
+                let explicit = self.infer_format_from_path(&path);
+                (provider, explicit)
+            }
+        }
+    }
+
+    #[cfg(not(feature = "compression"))]
+    fn file_input_provider(
+        &self,
+        path_str: &str,
+    ) -> (Arc<dyn AsyncInputProvider>, Option<FormatKind>) {
+        let path = PathBuf::from(path_str);
+        let provider: Arc<dyn AsyncInputProvider> = Arc::new(AsyncFileInput::new(path.clone()));
+        let explicit = self.infer_format_from_path(&path);
+        (provider, explicit)
+    }
+
+    /// Builds a file-backed async output target for `path_str`, transparently
+    /// wrapping it in compression when the path carries a recognized
+    /// compression extension. See `file_input_provider` for how format
+    /// inference accounts for the compression suffix / zip entry.
+    #[cfg(feature = "compression")]
+    fn file_output_target(
+        &self,
+        path_str: &str,
+    ) -> (Arc<dyn AsyncOutputTarget>, Option<FormatKind>) {
+        match crate::io::Compression::detect(path_str) {
+            Some((compression, format_hint)) => {
+                let fs_path = path_str.split_once('#').map(|(p, _)| p).unwrap_or(path_str);
+                let target: Arc<dyn AsyncOutputTarget> =
+                    Arc::new(crate::io::AsyncCompressedOutput::new(
+                        Arc::new(AsyncFileOutput::new(PathBuf::from(fs_path))),
+                        compression,
+                    ));
+                let explicit = self.infer_format_from_path(Path::new(&format_hint));
+                (target, explicit)
+            }
+            None => {
+                let path = PathBuf::from(path_str);
+                let target: Arc<dyn AsyncOutputTarget> = Arc::new(AsyncFileOutput::new(path.clone()));
+                let explicit = self.infer_format_from_path(&path);
+                (target, explicit)
+            }
+        }
+    }
+
+    #[cfg(not(feature = "compression"))]
+    fn file_output_target(
+        &self,
+        path_str: &str,
+    ) -> (Arc<dyn AsyncOutputTarget>, Option<FormatKind>) {
+        let path = PathBuf::from(path_str);
+        let target: Arc<dyn AsyncOutputTarget> = Arc::new(AsyncFileOutput::new(path.clone()));
+        let explicit = self.infer_format_from_path(&path);
+        (target, explicit)
+    }
+
     pub fn from_pipeline_config(
         config: PipelineConfig,
         registry: AsyncFormatRegistry,
@@ -406,6 +961,7 @@ impl MultiioAsyncBuilder {
             "stdin" | "-" => Arc::new(AsyncStdinInput::new()),
             "file" => {
                 let path = cfg.path.as_ref().ok_or_else(|| SingleIoError {
+                    attempts: 1,
                     stage: Stage::ResolveInput,
                     target: cfg.id.clone(),
                     error: Box::new(std::io::Error::new(
@@ -415,8 +971,56 @@ impl MultiioAsyncBuilder {
                 })?;
                 Arc::new(AsyncFileInput::new(PathBuf::from(path)))
             }
+            "command" => {
+                let command = cfg.path.as_ref().ok_or_else(|| SingleIoError {
+                    attempts: 1,
+                    stage: Stage::ResolveInput,
+                    target: cfg.id.clone(),
+                    error: Box::new(std::io::Error::new(
+                        std::io::ErrorKind::InvalidInput,
+                        "command input requires 'path' field holding the command line",
+                    )),
+                })?;
+                let (program, args) =
+                    crate::builder::parse_command_line(command).ok_or_else(|| SingleIoError {
+                        attempts: 1,
+                        stage: Stage::ResolveInput,
+                        target: cfg.id.clone(),
+                        error: Box::new(std::io::Error::new(
+                            std::io::ErrorKind::InvalidInput,
+                            "command input's 'path' field must not be empty",
+                        )),
+                    })?;
+                Arc::new(AsyncProcessInput::new(program).with_args(args))
+            }
+            "tcp" => {
+                let addr = cfg.path.as_ref().ok_or_else(|| SingleIoError {
+                    attempts: 1,
+                    stage: Stage::ResolveInput,
+                    target: cfg.id.clone(),
+                    error: Box::new(std::io::Error::new(
+                        std::io::ErrorKind::InvalidInput,
+                        "tcp input requires 'path' field holding the host:port",
+                    )),
+                })?;
+                Arc::new(AsyncSocketInput::tcp(addr))
+            }
+            #[cfg(unix)]
+            "unix" => {
+                let path = cfg.path.as_ref().ok_or_else(|| SingleIoError {
+                    attempts: 1,
+                    stage: Stage::ResolveInput,
+                    target: cfg.id.clone(),
+                    error: Box::new(std::io::Error::new(
+                        std::io::ErrorKind::InvalidInput,
+                        "unix input requires 'path' field holding the socket path",
+                    )),
+                })?;
+                Arc::new(AsyncSocketInput::unix(path))
+            }
             other => {
                 return Err(SingleIoError {
+                    attempts: 1,
                     stage: Stage::ResolveInput,
                     target: cfg.id.clone(),
                     error: Box::new(std::io::Error::new(
@@ -446,6 +1050,7 @@ impl MultiioAsyncBuilder {
             "stderr" => Arc::new(AsyncStderrOutput::new()),
             "file" => {
                 let path = cfg.path.as_ref().ok_or_else(|| SingleIoError {
+                    attempts: 1,
                     stage: Stage::ResolveOutput,
                     target: cfg.id.clone(),
                     error: Box::new(std::io::Error::new(
@@ -455,8 +1060,56 @@ impl MultiioAsyncBuilder {
                 })?;
                 Arc::new(AsyncFileOutput::new(PathBuf::from(path)))
             }
+            "command" => {
+                let command = cfg.path.as_ref().ok_or_else(|| SingleIoError {
+                    attempts: 1,
+                    stage: Stage::ResolveOutput,
+                    target: cfg.id.clone(),
+                    error: Box::new(std::io::Error::new(
+                        std::io::ErrorKind::InvalidInput,
+                        "command output requires 'path' field holding the command line",
+                    )),
+                })?;
+                let (program, args) =
+                    crate::builder::parse_command_line(command).ok_or_else(|| SingleIoError {
+                        attempts: 1,
+                        stage: Stage::ResolveOutput,
+                        target: cfg.id.clone(),
+                        error: Box::new(std::io::Error::new(
+                            std::io::ErrorKind::InvalidInput,
+                            "command output's 'path' field must not be empty",
+                        )),
+                    })?;
+                Arc::new(AsyncProcessOutput::new(program).with_args(args))
+            }
+            "tcp" => {
+                let addr = cfg.path.as_ref().ok_or_else(|| SingleIoError {
+                    attempts: 1,
+                    stage: Stage::ResolveOutput,
+                    target: cfg.id.clone(),
+                    error: Box::new(std::io::Error::new(
+                        std::io::ErrorKind::InvalidInput,
+                        "tcp output requires 'path' field holding the host:port",
+                    )),
+                })?;
+                Arc::new(AsyncSocketOutput::tcp(addr))
+            }
+            #[cfg(unix)]
+            "unix" => {
+                let path = cfg.path.as_ref().ok_or_else(|| SingleIoError {
+                    attempts: 1,
+                    stage: Stage::ResolveOutput,
+                    target: cfg.id.clone(),
+                    error: Box::new(std::io::Error::new(
+                        std::io::ErrorKind::InvalidInput,
+                        "unix output requires 'path' field holding the socket path",
+                    )),
+                })?;
+                Arc::new(AsyncSocketOutput::unix(path))
+            }
             other => {
                 return Err(SingleIoError {
+                    attempts: 1,
                     stage: Stage::ResolveOutput,
                     target: cfg.id.clone(),
                     error: Box::new(std::io::Error::new(
@@ -478,12 +1131,16 @@ impl MultiioAsyncBuilder {
             .and_then(|s| s.parse::<FileExistsPolicy>().ok())
             .unwrap_or(self.file_exists_policy);
 
+        let output_options =
+            crate::builder::output_options_from_config(cfg).or_else(|| self.output_options.clone());
+
         Ok(AsyncOutputSpec {
             raw: cfg.id.clone(),
             target,
             explicit_format,
             format_candidates: self.default_output_formats.clone(),
             file_exists_policy,
+            output_options,
         })
     }
 }
@@ -491,7 +1148,7 @@ impl MultiioAsyncBuilder {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::format::{DEFAULT_FORMAT_ORDER, FormatKind, default_async_registry};
+    use crate::format::{default_async_registry, FormatKind, DEFAULT_FORMAT_ORDER};
 
     #[test]
     fn async_builder_defaults_match_default_format_order() {
@@ -522,6 +1179,64 @@ mod tests {
         assert_eq!(forced_path.explicit_format, Some(FormatKind::Plaintext));
     }
 
+    #[test]
+    fn resolve_single_input_accepts_cmd_prefix_as_alias_for_bang() {
+        let builder = MultiioAsyncBuilder::default();
+
+        let bang = builder
+            .resolve_single_input("!echo hi")
+            .expect("bang command spec");
+        let cmd = builder
+            .resolve_single_input("cmd:echo hi")
+            .expect("cmd: command spec");
+
+        assert_eq!(bang.raw, "echo hi");
+        assert_eq!(cmd.raw, "echo hi");
+        assert_eq!(bang.provider.id(), cmd.provider.id());
+    }
+
+    #[test]
+    fn resolve_single_output_accepts_cmd_prefix_as_alias_for_bang() {
+        let builder = MultiioAsyncBuilder::default();
+
+        let bang = builder
+            .resolve_single_output("!sort")
+            .expect("bang command spec");
+        let cmd = builder
+            .resolve_single_output("cmd:sort")
+            .expect("cmd: command spec");
+
+        assert_eq!(bang.raw, "sort");
+        assert_eq!(cmd.raw, "sort");
+        assert_eq!(bang.target.id(), cmd.target.id());
+    }
+
+    #[test]
+    fn resolve_single_input_pipes_through_transform_command() {
+        let builder = MultiioAsyncBuilder::default();
+
+        let spec = builder
+            .resolve_single_input("data.json | gzip -d")
+            .expect("piped input spec");
+
+        assert_eq!(spec.raw, "data.json | gzip -d");
+        assert_eq!(spec.provider.id(), "data.json");
+        assert_eq!(spec.explicit_format, Some(FormatKind::Json));
+    }
+
+    #[test]
+    fn resolve_single_output_pipes_through_transform_command() {
+        let builder = MultiioAsyncBuilder::default();
+
+        let spec = builder
+            .resolve_single_output("gzip | out.json")
+            .expect("piped output spec");
+
+        assert_eq!(spec.raw, "gzip | out.json");
+        assert_eq!(spec.target.id(), "out.json");
+        assert_eq!(spec.explicit_format, Some(FormatKind::Json));
+    }
+
     #[test]
     fn resolve_single_output_supports_stdout_alias_stderr_and_forced_path() {
         let builder = MultiioAsyncBuilder::default();
@@ -547,4 +1262,62 @@ mod tests {
         assert_eq!(forced_path.target.id(), "out.txt");
         assert_eq!(forced_path.explicit_format, Some(FormatKind::Plaintext));
     }
+
+    #[cfg(feature = "compression")]
+    #[test]
+    fn resolve_single_input_infers_format_through_compression_suffix() {
+        let builder = MultiioAsyncBuilder::default();
+
+        let gz = builder
+            .resolve_single_input("config.json.gz")
+            .expect("gz spec");
+        assert_eq!(gz.explicit_format, Some(FormatKind::Json));
+
+        let zip_entry = builder
+            .resolve_single_input("archive.zip#data.csv")
+            .expect("zip entry spec");
+        assert_eq!(zip_entry.explicit_format, Some(FormatKind::Csv));
+    }
+
+    #[test]
+    fn registered_input_scheme_resolves_before_file_fallback() {
+        let builder = MultiioAsyncBuilder::default().register_input_scheme("mem", |rest| {
+            Ok(Arc::new(AsyncInMemorySource::from_string(
+                rest.to_string(),
+                format!("contents of {rest}"),
+            )))
+        });
+
+        let spec = builder
+            .resolve_single_input("mem://widgets")
+            .expect("scheme spec");
+        assert_eq!(spec.raw, "mem://widgets");
+        assert_eq!(spec.provider.id(), "widgets");
+    }
+
+    #[test]
+    fn registered_output_scheme_resolves_before_file_fallback() {
+        let builder = MultiioAsyncBuilder::default().register_output_scheme("mem", |rest| {
+            Ok(Arc::new(AsyncFileOutput::new(std::path::PathBuf::from(
+                rest,
+            ))))
+        });
+
+        let spec = builder
+            .resolve_single_output("mem://widgets")
+            .expect("scheme spec");
+        assert_eq!(spec.raw, "mem://widgets");
+        assert_eq!(spec.target.id(), "widgets");
+    }
+
+    #[test]
+    fn unregistered_scheme_falls_back_to_file_resolution() {
+        let builder = MultiioAsyncBuilder::default();
+
+        let spec = builder
+            .resolve_single_input("s3://bucket/key")
+            .expect("falls back to file provider");
+        assert_eq!(spec.raw, "s3://bucket/key");
+        assert_eq!(spec.provider.id(), "s3://bucket/key");
+    }
 }
@@ -13,10 +13,22 @@
 //!   - `=<content>` => inline content (in-memory input)
 //!   - `@<path>` => force treating the value as a file path (useful for
 //!     disambiguating reserved tokens)
+//!   - `!<cmd> [args...]` => run an external command and read its stdout
+//!   - `tcp://host:port` => connect over TCP and read an NDJSON record stream
+//!   - `unix:/path` => connect to a Unix domain socket and read an NDJSON
+//!     record stream
+//!   - `<scheme>://<rest>` => any other scheme resolves through a factory
+//!     registered via `MultiioBuilder::register_input_scheme`
 //! - Outputs:
 //!   - `-` or `stdout` => stdout
 //!   - `stderr` => stderr
 //!   - `@<path>` => force treating the value as a file path (e.g. `@stderr`)
+//!   - `!<cmd> [args...]` => run an external command and write to its stdin
+//!   - `tcp://host:port` => connect over TCP and write an NDJSON record stream
+//!   - `unix:/path` => connect to a Unix domain socket and write an NDJSON
+//!     record stream
+//!   - `<scheme>://<rest>` => any other scheme resolves through a factory
+//!     registered via `MultiioBuilder::register_output_scheme`
 //!
 //! # Example
 //!
@@ -97,7 +97,12 @@ impl ArgumentType for InputArgs {
     fn from_value(val: Option<&str>) -> sarge::ArgResult<Self> {
         fn normalize(token: &str) -> String {
             // Preserve explicit prefixes so callers can disambiguate.
-            if token.starts_with('@') || token.starts_with('=') {
+            if token.starts_with('@')
+                || token.starts_with('=')
+                || token.starts_with('!')
+                || token.contains("://")
+                || token.starts_with("unix:")
+            {
                 return token.to_string();
             }
 
@@ -139,7 +144,11 @@ impl ArgumentType for OutputArgs {
     fn from_value(val: Option<&str>) -> sarge::ArgResult<Self> {
         fn normalize(token: &str) -> String {
             // Preserve explicit prefixes so callers can disambiguate.
-            if token.starts_with('@') {
+            if token.starts_with('@')
+                || token.starts_with('!')
+                || token.contains("://")
+                || token.starts_with("unix:")
+            {
                 return token.to_string();
             }
 
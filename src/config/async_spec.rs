@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use crate::format::FormatKind;
+use crate::format::{FormatKind, OutputOptions};
 use crate::io::{AsyncInputProvider, AsyncOutputTarget};
 
 use super::FileExistsPolicy;
@@ -38,6 +38,19 @@ impl AsyncInputSpec {
         self.format_candidates = candidates;
         self
     }
+
+    /// Wrap this input's provider so it's transparently decompressed before
+    /// format deserialization sees it. See
+    /// [`crate::io::Compression::detect`] for auto-detecting a codec (and
+    /// the format candidate it implies) from `raw`'s extension.
+    #[cfg(feature = "compression")]
+    pub fn with_compression(mut self, compression: crate::io::Compression) -> Self {
+        self.provider = std::sync::Arc::new(crate::io::AsyncCompressedInput::new(
+            self.provider,
+            compression,
+        ));
+        self
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -52,6 +65,9 @@ pub struct AsyncOutputSpec {
     pub format_candidates: Vec<FormatKind>,
     /// Policy for handling existing files
     pub file_exists_policy: FileExistsPolicy,
+    /// Formatting hints (pretty/compact, indent, key order) for the serializer.
+    /// `None` means the format's own default shape.
+    pub output_options: Option<OutputOptions>,
 }
 
 impl AsyncOutputSpec {
@@ -62,6 +78,7 @@ impl AsyncOutputSpec {
             explicit_format: None,
             format_candidates: Vec::new(),
             file_exists_policy: FileExistsPolicy::default(),
+            output_options: None,
         }
     }
 
@@ -82,4 +99,23 @@ impl AsyncOutputSpec {
         self.file_exists_policy = policy;
         self
     }
+
+    /// Set the output formatting options.
+    pub fn with_output_options(mut self, options: OutputOptions) -> Self {
+        self.output_options = Some(options);
+        self
+    }
+
+    /// Wrap this output's target so everything serialization writes through
+    /// it is transparently compressed. See
+    /// [`crate::io::Compression::detect`] for auto-detecting a codec (and
+    /// the format candidate it implies) from `raw`'s extension.
+    #[cfg(feature = "compression")]
+    pub fn with_compression(mut self, compression: crate::io::Compression) -> Self {
+        self.target = std::sync::Arc::new(crate::io::AsyncCompressedOutput::new(
+            self.target,
+            compression,
+        ));
+        self
+    }
 }
@@ -29,9 +29,16 @@ pub struct InputConfig {
     /// URL (for HTTP/network inputs)
     #[serde(default)]
     pub url: Option<String>,
+    /// Request headers (for HTTP inputs)
+    #[serde(default)]
+    pub headers: std::collections::HashMap<String, String>,
     /// Explicit format: "json", "yaml", etc.
     #[serde(default)]
     pub format: Option<String>,
+    /// 64-character hex-encoded AES-256 key (see
+    /// `crate::io::SecretKey::from_hex`) to transparently decrypt this input.
+    #[serde(default)]
+    pub encryption_key: Option<String>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -43,12 +50,37 @@ pub struct OutputConfig {
     /// File path (for file outputs)
     #[serde(default)]
     pub path: Option<String>,
+    /// URL (for HTTP/network outputs)
+    #[serde(default)]
+    pub url: Option<String>,
+    /// Request headers (for HTTP outputs)
+    #[serde(default)]
+    pub headers: std::collections::HashMap<String, String>,
     /// Explicit format: "json", "yaml", etc.
     #[serde(default)]
     pub format: Option<String>,
     /// File exists policy: "overwrite", "append", "error"
     #[serde(default)]
     pub file_exists_policy: Option<String>,
+    /// Output style: "compact" or "pretty". Combined with `indent` and
+    /// `key_order` into an `OutputOptions` hint for the serializer.
+    #[serde(default)]
+    pub style: Option<String>,
+    /// Indent unit used when `style` is "pretty" (defaults to two spaces).
+    #[serde(default)]
+    pub indent: Option<String>,
+    /// Key order: "insertion" or "sorted".
+    #[serde(default)]
+    pub key_order: Option<String>,
+    /// Unix permission bits applied to the output's backing file, as an
+    /// octal string (e.g. `"0600"`, `"600"`, or `"0o600"`). Ignored on
+    /// non-Unix platforms and for outputs with no backing file.
+    #[serde(default)]
+    pub file_mode: Option<String>,
+    /// 64-character hex-encoded AES-256 key (see
+    /// `crate::io::SecretKey::from_hex`) to transparently encrypt this output.
+    #[serde(default)]
+    pub encryption_key: Option<String>,
 }
 
 impl PipelineConfig {
@@ -79,4 +111,31 @@ impl PipelineConfig {
         self.format_order = Some(order);
         self
     }
+
+    /// Parse a pipeline config from JSONC: JSON with `//`/`/* */` comments
+    /// and trailing commas, for hand-edited config files. Strips comments and
+    /// trailing commas via `format::strip_jsonc_comments` before delegating
+    /// to `serde_json`.
+    #[cfg(feature = "json")]
+    pub fn from_jsonc_str(input: &str) -> Result<Self, crate::format::FormatError> {
+        crate::format::deserialize_jsonc(input.as_bytes())
+    }
+
+    /// Build a runnable [`crate::engine::IoEngine`] directly from this
+    /// config, so the config module is a real entry point instead of an
+    /// inert data structure.
+    ///
+    /// Resolves each input/output's `kind` string into its provider/target
+    /// (`"stdin"` -> `StdinInput`, `"file"` -> `FileInput` using `path`,
+    /// `"http"` -> `HttpInput`/`HttpOutput` using `url`, ...), and parses
+    /// `format`, `file_exists_policy`, and `error_policy` the same way
+    /// [`crate::MultiioBuilder::from_pipeline_config`] does. Failures are
+    /// reported as `SingleIoError`s keyed by the offending input/output's
+    /// `id`, collected into an [`crate::error::AggregateError`].
+    pub fn into_engine(
+        self,
+        registry: crate::format::FormatRegistry,
+    ) -> Result<crate::engine::IoEngine, crate::error::AggregateError> {
+        crate::builder::MultiioBuilder::from_pipeline_config(self, registry)?.build()
+    }
 }
@@ -2,7 +2,7 @@
 
 use std::sync::Arc;
 
-use crate::format::FormatKind;
+use crate::format::{FormatKind, OutputOptions};
 use crate::io::{InputProvider, OutputTarget};
 
 /// Policy for handling existing output files.
@@ -10,11 +10,23 @@ use crate::io::{InputProvider, OutputTarget};
 pub enum FileExistsPolicy {
     /// Overwrite existing files
     Overwrite,
-    /// Append to existing files
+    /// Append to existing files.
+    ///
+    /// Under `ErrorPolicy::Retry`, a transient failure partway through the
+    /// write is retried by reopening the target and rewriting the whole
+    /// buffer from scratch - safe only because the engine refuses to retry
+    /// once any bytes have actually landed on disk, which would otherwise
+    /// duplicate that prefix in the output.
     Append,
     #[default]
     /// Return an error if file exists
     Error,
+    /// Overwrite existing files transactionally: for file-backed targets, the
+    /// new content is written to a temporary file in the same directory and
+    /// atomically renamed over the destination once fully flushed, so a
+    /// mid-write crash never leaves a truncated file behind. Targets with no
+    /// backing file path fall back to a plain overwrite.
+    AtomicOverwrite,
 }
 
 impl FileExistsPolicy {
@@ -24,6 +36,7 @@ impl FileExistsPolicy {
             "overwrite" => Some(FileExistsPolicy::Overwrite),
             "append" => Some(FileExistsPolicy::Append),
             "error" => Some(FileExistsPolicy::Error),
+            "atomic_overwrite" | "atomic" => Some(FileExistsPolicy::AtomicOverwrite),
             _ => None,
         }
     }
@@ -64,6 +77,29 @@ impl InputSpec {
         self.format_candidates = candidates;
         self
     }
+
+    /// Wrap this input's provider so it's transparently decompressed before
+    /// format deserialization sees it. See
+    /// [`crate::io::Compression::detect`] for auto-detecting a codec (and
+    /// the format candidate it implies) from `raw`'s extension.
+    #[cfg(feature = "compression")]
+    pub fn with_compression(mut self, compression: crate::io::Compression) -> Self {
+        self.provider = std::sync::Arc::new(crate::io::CompressedInput::new(
+            self.provider,
+            compression,
+        ));
+        self
+    }
+
+    /// Wrap this input's provider so it's transparently decrypted before
+    /// format deserialization sees it. Chained after
+    /// [`Self::with_compression`], the encryption layer is outermost: bytes
+    /// are decompressed first and then decrypted.
+    #[cfg(feature = "encryption")]
+    pub fn with_encryption(mut self, key: crate::io::SecretKey) -> Self {
+        self.provider = std::sync::Arc::new(crate::io::EncryptedInput::new(self.provider, key));
+        self
+    }
 }
 
 /// Specification for a single output target.
@@ -79,6 +115,16 @@ pub struct OutputSpec {
     pub format_candidates: Vec<FormatKind>,
     /// Policy for handling existing files
     pub file_exists_policy: FileExistsPolicy,
+    /// Formatting hints (pretty/compact, indent, key order) for the serializer.
+    /// `None` means the format's own default shape.
+    pub output_options: Option<OutputOptions>,
+    /// Unix permission bits (e.g. `0o600`) applied to the target's backing
+    /// file, if any, once a write completes. Covers `FileOutput::with_mode`'s
+    /// gap for files that already existed (the kernel ignores `open`'s mode
+    /// argument in that case), by having `IoEngine` call `set_permissions`
+    /// on `target.file_path()` after every write. Ignored on non-Unix
+    /// platforms and for targets with no backing file.
+    pub file_mode: Option<u32>,
 }
 
 impl OutputSpec {
@@ -90,6 +136,8 @@ impl OutputSpec {
             explicit_format: None,
             format_candidates: Vec::new(),
             file_exists_policy: FileExistsPolicy::default(),
+            output_options: None,
+            file_mode: None,
         }
     }
 
@@ -110,4 +158,42 @@ impl OutputSpec {
         self.file_exists_policy = policy;
         self
     }
+
+    /// Set the Unix permission bits applied to the target's backing file
+    /// after every write (see the `file_mode` field doc for why this is
+    /// separate from `FileOutput::with_mode`).
+    pub fn with_file_mode(mut self, mode: u32) -> Self {
+        self.file_mode = Some(mode);
+        self
+    }
+
+    /// Set the output formatting options.
+    pub fn with_output_options(mut self, options: OutputOptions) -> Self {
+        self.output_options = Some(options);
+        self
+    }
+
+    /// Wrap this output's target so everything serialization writes through
+    /// it is transparently compressed. See
+    /// [`crate::io::Compression::detect`] for auto-detecting a codec (and
+    /// the format candidate it implies) from `raw`'s extension.
+    #[cfg(feature = "compression")]
+    pub fn with_compression(mut self, compression: crate::io::Compression) -> Self {
+        self.target = std::sync::Arc::new(crate::io::CompressedOutput::new(
+            self.target,
+            compression,
+        ));
+        self
+    }
+
+    /// Wrap this output's target so everything written through it is
+    /// transparently encrypted. Chained after [`Self::with_compression`] (e.g.
+    /// `.with_compression(...).with_encryption(...)`), plaintext is encrypted
+    /// and the ciphertext then compressed; a reader undoes this with
+    /// `InputSpec::with_compression(...).with_encryption(...)`.
+    #[cfg(feature = "encryption")]
+    pub fn with_encryption(mut self, key: crate::io::SecretKey) -> Self {
+        self.target = std::sync::Arc::new(crate::io::EncryptedOutput::new(self.target, key));
+        self
+    }
 }
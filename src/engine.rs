@@ -1,19 +1,79 @@
 //! Synchronous I/O engine for orchestrating read and write operations.
 
-use std::io::Read;
+use std::io::{Read, Write};
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::sync::Mutex;
 
-use serde::{Serialize, de::DeserializeOwned};
+use serde::{de::DeserializeOwned, Serialize};
 
 use crate::config::{FileExistsPolicy, InputSpec, OutputSpec};
-use crate::error::{AggregateError, ErrorPolicy, SingleIoError, Stage};
+use crate::error::{backoff_delay, AggregateError, ErrorPolicy, SingleIoError, Stage};
 use crate::format::FormatRegistry;
 
+/// Monotonic counter mixed into temporary file names so that concurrent
+/// atomic writes within the same process never collide.
+static ATOMIC_WRITE_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// An output opened for incremental, item-at-a-time writing: either a plain
+/// writer, or (under `FileExistsPolicy::AtomicOverwrite`) a temp file that
+/// still needs the fsync-then-rename dance `write_atomic` gives a single
+/// buffered write. Shared by every streaming writer (`write_ndjson_records`,
+/// `write_stream`) so each only has to decide *what* to write per item, not
+/// *how* to open/finalize an output.
+enum WriteSink {
+    Direct(Box<dyn std::io::Write + Send>),
+    Atomic {
+        writer: Box<dyn std::io::Write + Send>,
+        tmp_path: std::path::PathBuf,
+        dest_path: std::path::PathBuf,
+    },
+}
+
+impl WriteSink {
+    fn writer(&mut self) -> &mut dyn std::io::Write {
+        match self {
+            WriteSink::Direct(w) => w,
+            WriteSink::Atomic { writer, .. } => writer,
+        }
+    }
+}
+
+/// Wraps a `Write` and counts the bytes actually accepted by the underlying
+/// `write` calls, so a caller can tell whether a failure happened before
+/// anything landed or partway through. Used by `write_output_bytes_inner`'s
+/// `Append` arm to detect a partial write before deciding whether the
+/// resulting error is safe to retry.
+struct CountingWriter<'a> {
+    inner: &'a mut dyn Write,
+    written: usize,
+}
+
+impl<'a> CountingWriter<'a> {
+    fn new(inner: &'a mut dyn Write) -> Self {
+        Self { inner, written: 0 }
+    }
+}
+
+impl Write for CountingWriter<'_> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let n = self.inner.write(buf)?;
+        self.written += n;
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
+    }
+}
+
 /// Synchronous I/O engine for orchestrating multi-input/multi-output operations.
 pub struct IoEngine {
     registry: FormatRegistry,
     error_policy: ErrorPolicy,
     inputs: Vec<InputSpec>,
     outputs: Vec<OutputSpec>,
+    concurrency: Option<usize>,
 }
 
 impl IoEngine {
@@ -29,9 +89,33 @@ impl IoEngine {
             error_policy,
             inputs,
             outputs,
+            concurrency: None,
         }
     }
 
+    /// Mutable access to the configured outputs, for crate-internal callers
+    /// that need to rewrite a just-built engine's targets (e.g.
+    /// `crate::testing::PipelineTest`, which redirects `stdout`/`file`
+    /// outputs to an `InMemorySink` after the normal config resolution has
+    /// already worked out each one's format and `FileExistsPolicy`).
+    pub(crate) fn outputs_mut(&mut self) -> &mut [OutputSpec] {
+        &mut self.outputs
+    }
+
+    /// Opt into a bounded thread pool for `read_all_parallel`/`write_all_parallel`:
+    /// at most `concurrency` specs are processed at once (clamped to at least 1).
+    /// Without this, the parallel variants fall back to their sequential
+    /// counterparts.
+    pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = Some(concurrency.max(1));
+        self
+    }
+
+    /// Get the configured parallel concurrency, if any.
+    pub fn concurrency(&self) -> Option<usize> {
+        self.concurrency
+    }
+
     /// Get the format registry.
     pub fn registry(&self) -> &FormatRegistry {
         &self.registry
@@ -52,6 +136,50 @@ impl IoEngine {
         &self.outputs
     }
 
+    /// Run a single-attempt I/O operation, retrying it under
+    /// `ErrorPolicy::Retry` as long as the error is transient
+    /// (`SingleIoError::is_transient`) and the attempt budget isn't
+    /// exhausted. Each retry calls `op` again from scratch, so it reopens
+    /// the underlying provider/target rather than resuming a failed stream.
+    /// The returned error's `attempts` field reflects how many attempts were
+    /// actually made. With any other error policy, `op` runs exactly once.
+    ///
+    /// For `FileExistsPolicy::Append` outputs specifically, "from scratch"
+    /// means a fresh `open_append()` followed by rewriting the *entire*
+    /// buffer - if a prior attempt's `write_all` already landed some bytes
+    /// on disk before hitting a transient error, retrying would duplicate
+    /// that prefix in the output. `write_output_bytes_inner`'s `Append` arm
+    /// guards against this itself by refusing to mark an error transient
+    /// once any bytes have actually been written.
+    fn with_retry<T>(
+        &self,
+        mut op: impl FnMut() -> Result<T, SingleIoError>,
+    ) -> Result<T, SingleIoError> {
+        let ErrorPolicy::Retry {
+            max_attempts,
+            base_delay,
+            max_delay,
+        } = self.error_policy
+        else {
+            return op();
+        };
+
+        let mut attempt = 1;
+        loop {
+            match op() {
+                Ok(value) => return Ok(value),
+                Err(mut e) => {
+                    e.attempts = attempt;
+                    if attempt >= max_attempts.max(1) || !e.is_transient() {
+                        return Err(e);
+                    }
+                    std::thread::sleep(backoff_delay(base_delay, max_delay, attempt));
+                    attempt += 1;
+                }
+            }
+        }
+    }
+
     /// Read all inputs and deserialize each into type T.
     ///
     /// Returns a vector of deserialized values, one for each input.
@@ -66,7 +194,7 @@ impl IoEngine {
         let mut buffer = Vec::new();
 
         for spec in &self.inputs {
-            match self.read_one_with_buffer::<T>(spec, &mut buffer) {
+            match self.with_retry(|| self.read_one_with_buffer::<T>(spec, &mut buffer)) {
                 Ok(value) => results.push(value),
                 Err(e) => {
                     errors.push(e);
@@ -84,13 +212,96 @@ impl IoEngine {
         }
     }
 
+    /// Like `read_all`, but fans the per-input work out across a bounded
+    /// thread pool when `with_concurrency` has been set; otherwise falls back
+    /// to `read_all`.
+    ///
+    /// Results are returned in the original input order regardless of which
+    /// order workers finish in. Under `ErrorPolicy::FastFail`, the first
+    /// error flips a cancellation flag so no further inputs are started;
+    /// inputs already in flight at that point still run to completion, and
+    /// only the first error observed is returned. Under
+    /// `ErrorPolicy::Accumulate`, every input is read and all errors are
+    /// gathered into one `AggregateError`.
+    pub fn read_all_parallel<T>(&self) -> Result<Vec<T>, AggregateError>
+    where
+        T: DeserializeOwned + Send,
+    {
+        let Some(concurrency) = self.concurrency else {
+            return self.read_all::<T>();
+        };
+
+        let len = self.inputs.len();
+        if len == 0 {
+            return Ok(Vec::new());
+        }
+        let concurrency = concurrency.min(len);
+
+        let next_index = AtomicUsize::new(0);
+        let cancelled = AtomicBool::new(false);
+        let results: Mutex<Vec<Option<T>>> = Mutex::new((0..len).map(|_| None).collect());
+        let first_error: Mutex<Option<SingleIoError>> = Mutex::new(None);
+        let errors: Mutex<Vec<SingleIoError>> = Mutex::new(Vec::new());
+
+        std::thread::scope(|scope| {
+            for _ in 0..concurrency {
+                scope.spawn(|| {
+                    let mut buffer = Vec::new();
+                    loop {
+                        if matches!(self.error_policy, ErrorPolicy::FastFail)
+                            && cancelled.load(Ordering::SeqCst)
+                        {
+                            break;
+                        }
+                        let idx = next_index.fetch_add(1, Ordering::SeqCst);
+                        if idx >= len {
+                            break;
+                        }
+                        let spec = &self.inputs[idx];
+                        match self.with_retry(|| self.read_one_with_buffer::<T>(spec, &mut buffer))
+                        {
+                            Ok(value) => results.lock().unwrap()[idx] = Some(value),
+                            Err(e) => {
+                                if matches!(self.error_policy, ErrorPolicy::FastFail) {
+                                    if !cancelled.swap(true, Ordering::SeqCst) {
+                                        *first_error.lock().unwrap() = Some(e);
+                                    }
+                                } else {
+                                    errors.lock().unwrap().push(e);
+                                }
+                            }
+                        }
+                    }
+                });
+            }
+        });
+
+        if matches!(self.error_policy, ErrorPolicy::FastFail) {
+            if let Some(e) = first_error.into_inner().unwrap() {
+                return Err(AggregateError { errors: vec![e] });
+            }
+        } else {
+            let errors = errors.into_inner().unwrap();
+            if !errors.is_empty() {
+                return Err(AggregateError { errors });
+            }
+        }
+
+        Ok(results
+            .into_inner()
+            .unwrap()
+            .into_iter()
+            .map(|v| v.expect("every index is filled when read_all_parallel reports no errors"))
+            .collect())
+    }
+
     /// Read a single input and deserialize into type T.
     fn read_one<T>(&self, spec: &InputSpec) -> Result<T, SingleIoError>
     where
         T: DeserializeOwned,
     {
         let mut buffer = Vec::new();
-        self.read_one_with_buffer::<T>(spec, &mut buffer)
+        self.with_retry(|| self.read_one_with_buffer::<T>(spec, &mut buffer))
     }
 
     fn read_one_with_buffer<T>(
@@ -98,11 +309,40 @@ impl IoEngine {
         spec: &InputSpec,
         buffer: &mut Vec<u8>,
     ) -> Result<T, SingleIoError>
+    where
+        T: DeserializeOwned,
+    {
+        #[cfg(feature = "tracing")]
+        let _span = crate::trace::operation_span("read", &spec.raw).entered();
+        #[cfg(feature = "tracing")]
+        let start = std::time::Instant::now();
+
+        let result = self.read_one_with_buffer_impl::<T>(spec, buffer);
+
+        #[cfg(feature = "tracing")]
+        crate::trace::record_outcome(
+            &result,
+            Stage::Parse,
+            &spec.raw,
+            spec.explicit_format.as_ref(),
+            buffer.len(),
+            start,
+        );
+
+        result
+    }
+
+    fn read_one_with_buffer_impl<T>(
+        &self,
+        spec: &InputSpec,
+        buffer: &mut Vec<u8>,
+    ) -> Result<T, SingleIoError>
     where
         T: DeserializeOwned,
     {
         // Open the input stream
         let mut reader = spec.provider.open().map_err(|e| SingleIoError {
+            attempts: 1,
             stage: Stage::Open,
             target: spec.raw.clone(),
             error: Box::new(e),
@@ -111,6 +351,7 @@ impl IoEngine {
         // Read all bytes into the reusable buffer
         buffer.clear();
         reader.read_to_end(buffer).map_err(|e| SingleIoError {
+            attempts: 1,
             stage: Stage::Open,
             target: spec.raw.clone(),
             error: Box::new(e),
@@ -124,6 +365,7 @@ impl IoEngine {
                 buffer,
             )
             .map_err(|e| SingleIoError {
+                attempts: 1,
                 stage: Stage::Parse,
                 target: spec.raw.clone(),
                 error: Box::new(e),
@@ -140,7 +382,7 @@ impl IoEngine {
         let mut errors = Vec::new();
 
         for spec in &self.outputs {
-            if let Err(e) = self.write_one(spec, values) {
+            if let Err(e) = self.with_retry(|| self.write_one(spec, values)) {
                 errors.push(e);
                 if matches!(self.error_policy, ErrorPolicy::FastFail) {
                     return Err(AggregateError { errors });
@@ -155,6 +397,73 @@ impl IoEngine {
         }
     }
 
+    /// Like `write_all`, but fans the per-output work out across a bounded
+    /// thread pool when `with_concurrency` has been set; otherwise falls back
+    /// to `write_all`.
+    ///
+    /// Error-policy semantics mirror `read_all_parallel`: under
+    /// `ErrorPolicy::FastFail`, the first error flips a cancellation flag so
+    /// no further outputs are started, and only that first error is
+    /// returned. Under `ErrorPolicy::Accumulate`, every output is written and
+    /// all errors are gathered into one `AggregateError`.
+    pub fn write_all_parallel<T>(&self, values: &[T]) -> Result<(), AggregateError>
+    where
+        T: Serialize + Sync,
+    {
+        let Some(concurrency) = self.concurrency else {
+            return self.write_all(values);
+        };
+
+        let len = self.outputs.len();
+        if len == 0 {
+            return Ok(());
+        }
+        let concurrency = concurrency.min(len);
+
+        let next_index = AtomicUsize::new(0);
+        let cancelled = AtomicBool::new(false);
+        let first_error: Mutex<Option<SingleIoError>> = Mutex::new(None);
+        let errors: Mutex<Vec<SingleIoError>> = Mutex::new(Vec::new());
+
+        std::thread::scope(|scope| {
+            for _ in 0..concurrency {
+                scope.spawn(|| loop {
+                    if matches!(self.error_policy, ErrorPolicy::FastFail)
+                        && cancelled.load(Ordering::SeqCst)
+                    {
+                        break;
+                    }
+                    let idx = next_index.fetch_add(1, Ordering::SeqCst);
+                    if idx >= len {
+                        break;
+                    }
+                    if let Err(e) = self.with_retry(|| self.write_one(&self.outputs[idx], values)) {
+                        if matches!(self.error_policy, ErrorPolicy::FastFail) {
+                            if !cancelled.swap(true, Ordering::SeqCst) {
+                                *first_error.lock().unwrap() = Some(e);
+                            }
+                        } else {
+                            errors.lock().unwrap().push(e);
+                        }
+                    }
+                });
+            }
+        });
+
+        if matches!(self.error_policy, ErrorPolicy::FastFail) {
+            if let Some(e) = first_error.into_inner().unwrap() {
+                return Err(AggregateError { errors: vec![e] });
+            }
+        } else {
+            let errors = errors.into_inner().unwrap();
+            if !errors.is_empty() {
+                return Err(AggregateError { errors });
+            }
+        }
+
+        Ok(())
+    }
+
     /// Write a single value to all outputs.
     pub fn write_one_value<T>(&self, value: &T) -> Result<(), AggregateError>
     where
@@ -163,7 +472,7 @@ impl IoEngine {
         let mut errors = Vec::new();
 
         for spec in &self.outputs {
-            if let Err(e) = self.write_single(spec, value) {
+            if let Err(e) = self.with_retry(|| self.write_single(spec, value)) {
                 errors.push(e);
                 if matches!(self.error_policy, ErrorPolicy::FastFail) {
                     return Err(AggregateError { errors });
@@ -180,89 +489,365 @@ impl IoEngine {
 
     /// Write values to a single output.
     fn write_one<T>(&self, spec: &OutputSpec, values: &[T]) -> Result<(), SingleIoError>
+    where
+        T: Serialize,
+    {
+        #[cfg(feature = "tracing")]
+        let _span = crate::trace::operation_span("write", &spec.raw).entered();
+        #[cfg(feature = "tracing")]
+        let start = std::time::Instant::now();
+
+        let result = self.write_one_impl(spec, values);
+
+        #[cfg(feature = "tracing")]
+        {
+            let bytes = result.as_ref().ok().copied().unwrap_or(0);
+            crate::trace::record_outcome(
+                &result,
+                Stage::Serialize,
+                &spec.raw,
+                spec.explicit_format.as_ref(),
+                bytes,
+                start,
+            );
+        }
+
+        result.map(|_| ())
+    }
+
+    fn write_one_impl<T>(&self, spec: &OutputSpec, values: &[T]) -> Result<usize, SingleIoError>
     where
         T: Serialize,
     {
         // Serialize to bytes (handles both built-in and custom formats)
-        let bytes = self
-            .registry
-            .serialize_value::<&[T]>(
+        let bytes = match &spec.output_options {
+            Some(options) => self.registry.serialize_value_with_options::<&[T]>(
                 spec.explicit_format.as_ref(),
                 &spec.format_candidates,
                 &values,
-            )
-            .map_err(|e| SingleIoError {
-                stage: Stage::Serialize,
-                target: spec.raw.clone(),
-                error: Box::new(e),
-            })?;
-
-        // Open the output stream based on policy
-        let mut writer = self.open_output(spec)?;
-
-        // Write bytes
-        std::io::Write::write_all(&mut *writer, &bytes).map_err(|e| SingleIoError {
+                options,
+            ),
+            None => self.registry.serialize_value::<&[T]>(
+                spec.explicit_format.as_ref(),
+                &spec.format_candidates,
+                &values,
+            ),
+        }
+        .map_err(|e| SingleIoError {
+            attempts: 1,
             stage: Stage::Serialize,
             target: spec.raw.clone(),
             error: Box::new(e),
-        })
+        })?;
+
+        self.write_output_bytes(spec, &bytes)?;
+        Ok(bytes.len())
+    }
+
+    /// Route `records` across outputs using `router` instead of broadcasting
+    /// the same values to every output, then write each output's assigned
+    /// batch once through the normal `write_one` path.
+    ///
+    /// `router` is consulted once per record with the current output count;
+    /// an output that receives no records is left untouched (not written at
+    /// all). `ErrorPolicy` governs per-output failures exactly as in
+    /// `write_all`: under `FastFail` the first error stops further output
+    /// writes, under `Accumulate` every targeted output is attempted and all
+    /// errors are gathered.
+    pub fn write_records_routed<T>(
+        &self,
+        records: &[T],
+        router: &dyn crate::router::Router<T>,
+    ) -> Result<(), AggregateError>
+    where
+        T: Serialize,
+    {
+        let output_count = self.outputs.len();
+        let mut buckets: Vec<Vec<&T>> = (0..output_count).map(|_| Vec::new()).collect();
+
+        for record in records {
+            for idx in router.route(record, output_count) {
+                if let Some(bucket) = buckets.get_mut(idx) {
+                    bucket.push(record);
+                }
+            }
+        }
+
+        let mut errors = Vec::new();
+        for (idx, bucket) in buckets.into_iter().enumerate() {
+            if bucket.is_empty() {
+                continue;
+            }
+            if let Err(e) = self.with_retry(|| self.write_one(&self.outputs[idx], &bucket)) {
+                errors.push(e);
+                if matches!(self.error_policy, ErrorPolicy::FastFail) {
+                    return Err(AggregateError { errors });
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(AggregateError { errors })
+        }
     }
 
     /// Write a single value to a specific output.
     fn write_single<T>(&self, spec: &OutputSpec, value: &T) -> Result<(), SingleIoError>
+    where
+        T: Serialize,
+    {
+        #[cfg(feature = "tracing")]
+        let _span = crate::trace::operation_span("write", &spec.raw).entered();
+        #[cfg(feature = "tracing")]
+        let start = std::time::Instant::now();
+
+        let result = self.write_single_impl(spec, value);
+
+        #[cfg(feature = "tracing")]
+        {
+            let bytes = result.as_ref().ok().copied().unwrap_or(0);
+            crate::trace::record_outcome(
+                &result,
+                Stage::Serialize,
+                &spec.raw,
+                spec.explicit_format.as_ref(),
+                bytes,
+                start,
+            );
+        }
+
+        result.map(|_| ())
+    }
+
+    fn write_single_impl<T>(&self, spec: &OutputSpec, value: &T) -> Result<usize, SingleIoError>
     where
         T: Serialize,
     {
         // Serialize to bytes (handles both built-in and custom formats)
-        let bytes = self
-            .registry
-            .serialize_value(
+        let bytes = match &spec.output_options {
+            Some(options) => self.registry.serialize_value_with_options(
                 spec.explicit_format.as_ref(),
                 &spec.format_candidates,
                 value,
-            )
-            .map_err(|e| SingleIoError {
-                stage: Stage::Serialize,
-                target: spec.raw.clone(),
-                error: Box::new(e),
-            })?;
-
-        // Open the output stream based on policy
-        let mut writer = self.open_output(spec)?;
-
-        // Write bytes
-        std::io::Write::write_all(&mut *writer, &bytes).map_err(|e| SingleIoError {
+                options,
+            ),
+            None => self.registry.serialize_value(
+                spec.explicit_format.as_ref(),
+                &spec.format_candidates,
+                value,
+            ),
+        }
+        .map_err(|e| SingleIoError {
+            attempts: 1,
             stage: Stage::Serialize,
             target: spec.raw.clone(),
             error: Box::new(e),
+        })?;
+
+        self.write_output_bytes(spec, &bytes)?;
+        Ok(bytes.len())
+    }
+
+    /// Write already-serialized bytes to an output, honoring its file-exists policy.
+    fn write_output_bytes(&self, spec: &OutputSpec, bytes: &[u8]) -> Result<(), SingleIoError> {
+        self.write_output_bytes_inner(spec, bytes)?;
+        self.apply_file_mode(spec)
+    }
+
+    /// Writes `bytes` to `writer` and flushes it, mapping either failure to a
+    /// `Stage::Write` error against `target` (the bytes are already
+    /// serialized by this point, so a failure here is the I/O write call
+    /// itself, not a value-to-bytes conversion - see `Stage::Write`). The
+    /// explicit flush (rather than just letting `writer` drop) is what lets a
+    /// target like `ProcessOutput` observe the command's exit status: its
+    /// `Write::flush` closes stdin and waits for the child, surfacing a
+    /// nonzero exit as an error, which a bare `Drop` has no way to report.
+    fn write_all_then_flush(
+        writer: &mut dyn Write,
+        bytes: &[u8],
+        target: &str,
+    ) -> Result<(), SingleIoError> {
+        writer.write_all(bytes).map_err(|e| SingleIoError {
+            attempts: 1,
+            stage: Stage::Write,
+            target: target.to_string(),
+            error: Box::new(e),
+        })?;
+        writer.flush().map_err(|e| SingleIoError {
+            attempts: 1,
+            stage: Stage::Write,
+            target: target.to_string(),
+            error: Box::new(e),
         })
     }
 
-    /// Open an output based on the file exists policy.
-    fn open_output(
-        &self,
-        spec: &OutputSpec,
-    ) -> Result<Box<dyn std::io::Write + Send>, SingleIoError> {
-        let result = match spec.file_exists_policy {
-            FileExistsPolicy::Overwrite => spec.target.open_overwrite(),
-            FileExistsPolicy::Append => spec.target.open_append(),
+    fn write_output_bytes_inner(&self, spec: &OutputSpec, bytes: &[u8]) -> Result<(), SingleIoError> {
+        match spec.file_exists_policy {
+            FileExistsPolicy::Append => {
+                let mut writer = spec.target.open_append().map_err(|e| SingleIoError {
+                    attempts: 1,
+                    stage: Stage::Open,
+                    target: spec.raw.clone(),
+                    error: Box::new(e),
+                })?;
+                let mut counting = CountingWriter::new(&mut *writer);
+                Self::write_all_then_flush(&mut counting, bytes, &spec.raw).map_err(|mut e| {
+                    // A prefix of `bytes` already landed on disk before this
+                    // failure. `with_retry` retries an `Append` write by
+                    // reopening the target and rewriting the whole buffer
+                    // from scratch, which would duplicate that prefix - so
+                    // suppress retryability for this one error rather than
+                    // let `is_transient` say yes. Only do this when the
+                    // error actually was transient: an already-permanent
+                    // error (e.g. `PermissionDenied`) should keep its real
+                    // `ErrorKind` so `class()`/diagnostics still see it, not
+                    // get masked as `ErrorKind::Other`.
+                    if counting.written > 0 && e.is_transient() {
+                        e.error = Box::new(std::io::Error::new(std::io::ErrorKind::Other, e.error));
+                    }
+                    e
+                })
+            }
             FileExistsPolicy::Error => {
-                // For file outputs, check if file exists
-                // For now, just use overwrite (can be enhanced)
-                spec.target.open_overwrite()
+                if let Some(path) = spec.target.file_path() {
+                    if path.exists() {
+                        return Err(SingleIoError {
+                            attempts: 1,
+                            stage: Stage::Open,
+                            target: spec.raw.clone(),
+                            error: Box::new(std::io::Error::new(
+                                std::io::ErrorKind::AlreadyExists,
+                                format!("output file already exists: {}", path.display()),
+                            )),
+                        });
+                    }
+                }
+                let mut writer = spec.target.open_overwrite().map_err(|e| SingleIoError {
+                    attempts: 1,
+                    stage: Stage::Open,
+                    target: spec.raw.clone(),
+                    error: Box::new(e),
+                })?;
+                Self::write_all_then_flush(&mut *writer, bytes, &spec.raw)
+            }
+            FileExistsPolicy::Overwrite => {
+                let mut writer = spec.target.open_overwrite().map_err(|e| SingleIoError {
+                    attempts: 1,
+                    stage: Stage::Open,
+                    target: spec.raw.clone(),
+                    error: Box::new(e),
+                })?;
+                Self::write_all_then_flush(&mut *writer, bytes, &spec.raw)
             }
+            FileExistsPolicy::AtomicOverwrite => match spec.target.file_path() {
+                Some(path) => self.write_atomic(spec, path, bytes),
+                None => {
+                    // No real file behind this target (stdout, sockets, in-memory
+                    // buffers, ...), so there's nothing to rename over; fall back
+                    // to a plain overwrite.
+                    let mut writer = spec.target.open_overwrite().map_err(|e| SingleIoError {
+                        attempts: 1,
+                        stage: Stage::Open,
+                        target: spec.raw.clone(),
+                        error: Box::new(e),
+                    })?;
+                    Self::write_all_then_flush(&mut *writer, bytes, &spec.raw)
+                }
+            },
+        }
+    }
+
+    /// Write `bytes` to `path` transactionally: write to a temp file in the
+    /// same directory, flush and fsync it, then rename it over `path`. The
+    /// rename is atomic on the same filesystem, so a crash mid-write leaves
+    /// either the old file or the new one, never a truncated one. The temp
+    /// file is removed if any step fails.
+    fn write_atomic(
+        &self,
+        spec: &OutputSpec,
+        path: &Path,
+        bytes: &[u8],
+    ) -> Result<(), SingleIoError> {
+        let dir = path.parent().filter(|p| !p.as_os_str().is_empty());
+        let file_name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_else(|| "output".to_string());
+        let unique = ATOMIC_WRITE_COUNTER.fetch_add(1, Ordering::Relaxed);
+        let tmp_name = format!(".{file_name}.tmp-{}-{unique}", std::process::id());
+        let tmp_path = match dir {
+            Some(dir) => dir.join(tmp_name),
+            None => std::path::PathBuf::from(tmp_name),
         };
 
-        result.map_err(|e| SingleIoError {
-            stage: Stage::Open,
-            target: spec.raw.clone(),
-            error: Box::new(e),
+        let result = (|| -> std::io::Result<()> {
+            let mut writer = spec.target.open_overwrite_at(&tmp_path)?;
+            std::io::Write::write_all(&mut *writer, bytes)?;
+            // Drop before fsync: wrapping targets (compression, encryption)
+            // only flush their trailing bytes (footer, final auth tag, ...)
+            // on drop, since `Write::flush` alone can't finish a codec that
+            // still has buffered partial output.
+            drop(writer);
+            std::fs::File::open(&tmp_path)?.sync_all()?;
+            std::fs::rename(&tmp_path, path)?;
+            Ok(())
+        })();
+
+        result.map_err(|e| {
+            let _ = std::fs::remove_file(&tmp_path);
+            SingleIoError {
+                attempts: 1,
+                stage: Stage::Write,
+                target: spec.raw.clone(),
+                error: Box::new(e),
+            }
         })
     }
 
-    /// Create an iterator that reads each input lazily.
+    /// Applies `spec.file_mode` to the target's backing file after a
+    /// successful write, covering the case `FileOutput::with_mode` can't: a
+    /// file that already existed before this write (the kernel ignores
+    /// `open`'s mode argument then). A no-op when `file_mode` is unset, on
+    /// non-Unix platforms, or for targets with no backing file path.
+    #[cfg(unix)]
+    fn apply_file_mode(&self, spec: &OutputSpec) -> Result<(), SingleIoError> {
+        use std::os::unix::fs::PermissionsExt;
+
+        let Some(mode) = spec.file_mode else {
+            return Ok(());
+        };
+        let Some(path) = spec.target.file_path() else {
+            return Ok(());
+        };
+        std::fs::set_permissions(path, std::fs::Permissions::from_mode(mode)).map_err(|e| {
+            SingleIoError {
+                attempts: 1,
+                stage: Stage::Write,
+                target: spec.raw.clone(),
+                error: Box::new(e),
+            }
+        })
+    }
+
+    #[cfg(not(unix))]
+    fn apply_file_mode(&self, _spec: &OutputSpec) -> Result<(), SingleIoError> {
+        Ok(())
+    }
+
+    /// Create an iterator that reads each input lazily, yielding one item per
+    /// *input* (the whole document decoded as a single `T`).
     ///
-    /// This allows processing inputs one at a time without loading all into memory.
+    /// This allows processing inputs one at a time without loading all into
+    /// memory. For formats with a record-oriented representation (NDJSON,
+    /// CSV, YAML documents, ...), use [`Self::read_records`] instead to get
+    /// one item per *record*: for NDJSON specifically, each line is decoded
+    /// independently, so a malformed line surfaces a `Stage::Parse` error
+    /// naming its 1-based line number without stopping the iterator from
+    /// yielding subsequent lines - a caller using `ErrorPolicy::Accumulate`
+    /// can keep pulling and collect every error, while a `FastFail` caller
+    /// can stop at the first one.
     pub fn read_stream<T>(&self) -> impl Iterator<Item = Result<T, SingleIoError>> + '_
     where
         T: DeserializeOwned,
@@ -285,6 +870,20 @@ impl IoEngine {
             .flat_map(move |spec| self.records_stream_for_spec::<T>(spec))
     }
 
+    /// Stream records from all inputs as schema-less `serde_json::Value`s.
+    ///
+    /// This is `read_records::<serde_json::Value>` under another name: it
+    /// shares the exact per-format streaming iterators (NDJSON, CSV rows
+    /// keyed by the header row, YAML documents, the custom streaming bridge)
+    /// and the same `Stage::Parse`/`SingleIoError` per-record error
+    /// reporting, but lets callers inspect heterogeneous rows, filter by
+    /// field, or reshape before committing to a concrete type.
+    pub fn read_records_dynamic(
+        &self,
+    ) -> impl Iterator<Item = Result<serde_json::Value, SingleIoError>> + '_ {
+        self.read_records::<serde_json::Value>()
+    }
+
     /// Stream JSON records from all inputs whose resolved format is JSON.
     ///
     /// Each top-level JSON value is deserialized into `T`. Errors are reported per-record
@@ -329,6 +928,7 @@ impl IoEngine {
             Ok(k) => k,
             Err(e) => {
                 let err = SingleIoError {
+                    attempts: 1,
                     stage: Stage::ResolveInput,
                     target: spec.raw.clone(),
                     error: Box::new(e),
@@ -339,6 +939,7 @@ impl IoEngine {
 
         if kind != crate::format::FormatKind::Csv {
             let err = SingleIoError {
+                attempts: 1,
                 stage: Stage::ResolveInput,
                 target: spec.raw.clone(),
                 error: Box::new(crate::format::FormatError::UnknownFormat(kind)),
@@ -351,6 +952,7 @@ impl IoEngine {
             Ok(r) => r,
             Err(e) => {
                 let err = SingleIoError {
+                    attempts: 1,
                     stage: Stage::Open,
                     target: spec.raw.clone(),
                     error: Box::new(e),
@@ -362,6 +964,7 @@ impl IoEngine {
         let target = spec.raw.clone();
         let iter = crate::format::deserialize_csv_stream::<T, _>(reader).map(move |res| {
             res.map_err(|e| SingleIoError {
+                attempts: 1,
                 stage: Stage::Parse,
                 target: target.clone(),
                 error: Box::new(e),
@@ -371,6 +974,415 @@ impl IoEngine {
         Box::new(iter)
     }
 
+    /// Stream NDJSON records from all inputs whose resolved format is NDJSON.
+    ///
+    /// Each line is deserialized into `T` independently, so a malformed line
+    /// surfaces its own `SingleIoError` (the line number is folded into the
+    /// underlying `FormatError`'s message) without stopping later lines from
+    /// being yielded; a caller using `ErrorPolicy::Accumulate` can keep
+    /// pulling and collect every error, while a `FastFail` caller can stop at
+    /// the first one.
+    #[cfg(feature = "ndjson")]
+    pub fn read_ndjson_records<T>(&self) -> impl Iterator<Item = Result<T, SingleIoError>> + '_
+    where
+        T: DeserializeOwned + 'static,
+    {
+        self.inputs
+            .iter()
+            .flat_map(move |spec| self.ndjson_stream_for_spec::<T>(spec))
+    }
+
+    #[cfg(feature = "ndjson")]
+    fn ndjson_stream_for_spec<T>(
+        &self,
+        spec: &InputSpec,
+    ) -> Box<dyn Iterator<Item = Result<T, SingleIoError>> + '_>
+    where
+        T: DeserializeOwned + 'static,
+    {
+        // Resolve format first
+        let kind = match self
+            .registry
+            .resolve(spec.explicit_format.as_ref(), &spec.format_candidates)
+        {
+            Ok(k) => k,
+            Err(e) => {
+                let err = SingleIoError {
+                    attempts: 1,
+                    stage: Stage::ResolveInput,
+                    target: spec.raw.clone(),
+                    error: Box::new(e),
+                };
+                return Box::new(std::iter::once(Err(err)));
+            }
+        };
+
+        if kind != crate::format::FormatKind::Ndjson {
+            let err = SingleIoError {
+                attempts: 1,
+                stage: Stage::ResolveInput,
+                target: spec.raw.clone(),
+                error: Box::new(crate::format::FormatError::UnknownFormat(kind)),
+            };
+            return Box::new(std::iter::once(Err(err)));
+        }
+
+        // Open the input
+        let reader = match spec.provider.open() {
+            Ok(r) => r,
+            Err(e) => {
+                let err = SingleIoError {
+                    attempts: 1,
+                    stage: Stage::Open,
+                    target: spec.raw.clone(),
+                    error: Box::new(e),
+                };
+                return Box::new(std::iter::once(Err(err)));
+            }
+        };
+
+        let target = spec.raw.clone();
+        let iter = crate::format::deserialize_ndjson_stream::<T, _>(reader).map(move |res| {
+            res.map_err(|e| SingleIoError {
+                attempts: 1,
+                stage: Stage::Parse,
+                target: target.clone(),
+                error: Box::new(e),
+            })
+        });
+
+        Box::new(iter)
+    }
+
+    /// Write items from `iter` to the configured output as they're produced,
+    /// rather than collecting into a `Vec` first, using whatever incremental
+    /// encoder its resolved format provides (see
+    /// `FormatRegistry::stream_serialize_from`: NDJSON, CSV, and plaintext
+    /// have one; a format without one falls back to collecting every item
+    /// and making a single write, same as that method's own default).
+    ///
+    /// Only meaningful with exactly one configured output: broadcasting a
+    /// single pass over `iter` to several independently-formatted outputs
+    /// needs the whole sequence in memory anyway (to serialize it once per
+    /// output), so with zero or multiple outputs this just collects `iter`
+    /// into a `Vec` and defers to `write_all`. For the all-too-common
+    /// single-output pipeline (read NDJSON, transform, write NDJSON) this is
+    /// `write_ndjson_records`'s format-agnostic sibling.
+    pub fn write_stream<T>(&self, iter: impl Iterator<Item = T>) -> Result<(), AggregateError>
+    where
+        T: Serialize,
+    {
+        let [spec] = self.outputs.as_slice() else {
+            let values: Vec<T> = iter.collect();
+            return self.write_all(&values);
+        };
+
+        self.write_stream_one(spec, iter)
+            .map_err(AggregateError::single)
+    }
+
+    fn write_stream_one<T>(
+        &self,
+        spec: &OutputSpec,
+        iter: impl Iterator<Item = T>,
+    ) -> Result<(), SingleIoError>
+    where
+        T: Serialize,
+    {
+        let kind = self
+            .registry
+            .resolve(spec.explicit_format.as_ref(), &spec.format_candidates)
+            .map_err(|e| SingleIoError {
+                attempts: 1,
+                stage: Stage::ResolveOutput,
+                target: spec.raw.clone(),
+                error: Box::new(e),
+            })?;
+
+        let mut sink = self.open_write_sink(spec)?;
+
+        let values = iter.map(Ok::<T, crate::format::FormatError>);
+        if let Err(e) = self
+            .registry
+            .stream_serialize_from(Some(&kind), &[], sink.writer(), values)
+        {
+            // The streaming encoder interleaves serializing and writing, so
+            // a failure can be either; `FormatError::Io` is how the `?`
+            // inside `Format::stream_serialize` surfaces a write failure,
+            // distinct from a genuine encoding error.
+            let stage = match &e {
+                crate::format::FormatError::Io(_) => Stage::Write,
+                _ => Stage::Serialize,
+            };
+            // `sink` is abandoned rather than finalized: an `Atomic` sink's
+            // temp file holds a truncated/partial write at this point, and
+            // finalizing it anyway would leak it on disk (or worse, risk
+            // renaming it over the destination - see `write_atomic`'s own
+            // "never leave a truncated file" guarantee).
+            Self::abandon_write_sink(sink);
+            return Err(SingleIoError {
+                attempts: 1,
+                stage,
+                target: spec.raw.clone(),
+                error: Box::new(e),
+            });
+        }
+
+        self.finalize_write_sink(spec, sink)
+    }
+
+    /// Stream-write NDJSON records to all outputs whose resolved format is
+    /// NDJSON, serializing and writing each record as it's pulled off `iter`
+    /// rather than collecting into a `Vec` first, so a multi-gigabyte
+    /// transform never needs the whole sequence in memory at once.
+    ///
+    /// Mirrors `write_all`'s "broadcast to every output" semantics in a
+    /// single pass: each record is serialized once and written to every
+    /// still-live output before the next record is pulled. A
+    /// `FileExistsPolicy::AtomicOverwrite` output still gets the
+    /// temp-file-then-rename treatment `write_all` gives it (see
+    /// `write_atomic`), just with records streamed straight into the temp
+    /// file instead of being assembled in memory first. A write failure
+    /// drops that output from the remaining iteration (matching how
+    /// `write_all` stops writing to an output it failed to open), while
+    /// other live outputs keep receiving records.
+    #[cfg(feature = "ndjson")]
+    pub fn write_ndjson_records<T>(
+        &self,
+        iter: impl Iterator<Item = T>,
+    ) -> Result<(), AggregateError>
+    where
+        T: Serialize,
+    {
+        let mut errors = Vec::new();
+        let mut sinks: Vec<Option<WriteSink>> = Vec::with_capacity(self.outputs.len());
+
+        for spec in &self.outputs {
+            match self.open_ndjson_sink(spec) {
+                Ok(sink) => sinks.push(Some(sink)),
+                Err(e) => {
+                    sinks.push(None);
+                    errors.push(e);
+                    if matches!(self.error_policy, ErrorPolicy::FastFail) {
+                        return Err(AggregateError { errors });
+                    }
+                }
+            }
+        }
+
+        for value in iter {
+            let mut line = Vec::new();
+            if let Err(e) = serde_json::to_writer(&mut line, &value) {
+                // Not attributable to one particular output, so report it
+                // against every output still live and stop writing to all
+                // of them: a record that can't be serialized at all can't
+                // be partially written to some outputs and not others.
+                for (spec, sink) in self.outputs.iter().zip(sinks.iter_mut()) {
+                    if let Some(abandoned) = sink.take() {
+                        Self::abandon_write_sink(abandoned);
+                        errors.push(SingleIoError {
+                            attempts: 1,
+                            stage: Stage::Serialize,
+                            target: spec.raw.clone(),
+                            error: Box::new(std::io::Error::other(e.to_string())),
+                        });
+                    }
+                }
+                if matches!(self.error_policy, ErrorPolicy::FastFail) {
+                    return Err(AggregateError { errors });
+                }
+                continue;
+            }
+            line.push(b'\n');
+
+            let mut fast_fail_error = None;
+            for (spec, sink) in self.outputs.iter().zip(sinks.iter_mut()) {
+                let Some(writer) = sink.as_mut().map(WriteSink::writer) else {
+                    continue;
+                };
+                if let Err(e) = writer.write_all(&line) {
+                    if let Some(abandoned) = sink.take() {
+                        Self::abandon_write_sink(abandoned);
+                    }
+                    let single_err = SingleIoError {
+                        attempts: 1,
+                        stage: Stage::Write,
+                        target: spec.raw.clone(),
+                        error: Box::new(e),
+                    };
+                    if matches!(self.error_policy, ErrorPolicy::FastFail) {
+                        fast_fail_error = Some(single_err);
+                        break;
+                    }
+                    errors.push(single_err);
+                }
+            }
+            if let Some(e) = fast_fail_error {
+                errors.push(e);
+                // Outputs not yet visited this round (or visited earlier and
+                // still open) would otherwise leak an `Atomic` sink's temp
+                // file when `sinks` is dropped without ever being finalized.
+                for remaining in sinks.into_iter().flatten() {
+                    Self::abandon_write_sink(remaining);
+                }
+                return Err(AggregateError { errors });
+            }
+        }
+
+        for (spec, sink) in self.outputs.iter().zip(sinks) {
+            let Some(sink) = sink else { continue };
+            if let Err(e) = self.finalize_write_sink(spec, sink) {
+                errors.push(e);
+                if matches!(self.error_policy, ErrorPolicy::FastFail) {
+                    return Err(AggregateError { errors });
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(AggregateError { errors })
+        }
+    }
+
+    /// Open `spec` for incremental writing, honoring `spec.file_exists_policy`
+    /// the same way `write_output_bytes_inner` does for a single buffered
+    /// write. Doesn't check the resolved format; callers that require a
+    /// specific one (e.g. `open_ndjson_sink`) check it themselves first.
+    fn open_write_sink(&self, spec: &OutputSpec) -> Result<WriteSink, SingleIoError> {
+        let open_err = |e: std::io::Error| SingleIoError {
+            attempts: 1,
+            stage: Stage::Open,
+            target: spec.raw.clone(),
+            error: Box::new(e),
+        };
+
+        match spec.file_exists_policy {
+            FileExistsPolicy::Append => {
+                Ok(WriteSink::Direct(spec.target.open_append().map_err(open_err)?))
+            }
+            FileExistsPolicy::Error => {
+                if let Some(path) = spec.target.file_path() {
+                    if path.exists() {
+                        return Err(open_err(std::io::Error::new(
+                            std::io::ErrorKind::AlreadyExists,
+                            format!("output file already exists: {}", path.display()),
+                        )));
+                    }
+                }
+                Ok(WriteSink::Direct(spec.target.open_overwrite().map_err(open_err)?))
+            }
+            FileExistsPolicy::Overwrite => {
+                Ok(WriteSink::Direct(spec.target.open_overwrite().map_err(open_err)?))
+            }
+            FileExistsPolicy::AtomicOverwrite => match spec.target.file_path() {
+                Some(path) => {
+                    let dir = path.parent().filter(|p| !p.as_os_str().is_empty());
+                    let file_name = path
+                        .file_name()
+                        .map(|n| n.to_string_lossy().into_owned())
+                        .unwrap_or_else(|| "output".to_string());
+                    let unique = ATOMIC_WRITE_COUNTER.fetch_add(1, Ordering::Relaxed);
+                    let tmp_name = format!(".{file_name}.tmp-{}-{unique}", std::process::id());
+                    let tmp_path = match dir {
+                        Some(dir) => dir.join(tmp_name),
+                        None => std::path::PathBuf::from(tmp_name),
+                    };
+                    let writer = spec.target.open_overwrite_at(&tmp_path).map_err(open_err)?;
+                    Ok(WriteSink::Atomic {
+                        writer,
+                        tmp_path,
+                        dest_path: path.to_path_buf(),
+                    })
+                }
+                None => Ok(WriteSink::Direct(
+                    spec.target.open_overwrite().map_err(open_err)?,
+                )),
+            },
+        }
+    }
+
+    /// Drop `sink` without finalizing it. A `Direct` sink's target already
+    /// holds whatever bytes were written to it and there's nothing further
+    /// to clean up, but an `Atomic` sink's temp file would otherwise be
+    /// leaked on disk forever, so it's removed here instead. Used whenever a
+    /// sink is abandoned rather than completed via `finalize_write_sink` - a
+    /// failed write, or a still-open sink when `ErrorPolicy::FastFail` aborts
+    /// the rest of the run.
+    fn abandon_write_sink(sink: WriteSink) {
+        if let WriteSink::Atomic { writer, tmp_path, .. } = sink {
+            drop(writer);
+            let _ = std::fs::remove_file(&tmp_path);
+        }
+    }
+
+    /// Finish a sink opened by `open_write_sink`: a plain writer just needs
+    /// the file mode applied (mirroring `write_output_bytes`'s post-write
+    /// step); an atomic sink still needs its fsync-then-rename dance, same as
+    /// `write_atomic` does for a single buffered write.
+    fn finalize_write_sink(
+        &self,
+        spec: &OutputSpec,
+        sink: WriteSink,
+    ) -> Result<(), SingleIoError> {
+        match sink {
+            WriteSink::Direct(_) => self.apply_file_mode(spec),
+            WriteSink::Atomic {
+                writer,
+                tmp_path,
+                dest_path,
+            } => {
+                let result = (|| -> std::io::Result<()> {
+                    // Drop before fsync: wrapping targets (compression,
+                    // encryption) only flush their trailing bytes on drop
+                    // (see `write_atomic`'s identical ordering).
+                    drop(writer);
+                    std::fs::File::open(&tmp_path)?.sync_all()?;
+                    std::fs::rename(&tmp_path, &dest_path)?;
+                    Ok(())
+                })();
+
+                result.map_err(|e| {
+                    let _ = std::fs::remove_file(&tmp_path);
+                    SingleIoError {
+                        attempts: 1,
+                        stage: Stage::Write,
+                        target: spec.raw.clone(),
+                        error: Box::new(e),
+                    }
+                })?;
+
+                self.apply_file_mode(spec)
+            }
+        }
+    }
+
+    /// Resolve `spec`'s format (must be NDJSON) and open a writer for it.
+    #[cfg(feature = "ndjson")]
+    fn open_ndjson_sink(&self, spec: &OutputSpec) -> Result<WriteSink, SingleIoError> {
+        let kind = self
+            .registry
+            .resolve(spec.explicit_format.as_ref(), &spec.format_candidates)
+            .map_err(|e| SingleIoError {
+                attempts: 1,
+                stage: Stage::ResolveOutput,
+                target: spec.raw.clone(),
+                error: Box::new(e),
+            })?;
+
+        if kind != crate::format::FormatKind::Ndjson {
+            return Err(SingleIoError {
+                attempts: 1,
+                stage: Stage::ResolveOutput,
+                target: spec.raw.clone(),
+                error: Box::new(crate::format::FormatError::UnknownFormat(kind)),
+            });
+        }
+
+        self.open_write_sink(spec)
+    }
+
     fn records_stream_for_spec<T>(
         &self,
         spec: &InputSpec,
@@ -383,6 +1395,7 @@ impl IoEngine {
             Ok(r) => r,
             Err(e) => {
                 let err = SingleIoError {
+                    attempts: 1,
                     stage: Stage::Open,
                     target: spec.raw.clone(),
                     error: Box::new(e),
@@ -402,6 +1415,7 @@ impl IoEngine {
             Ok(iter) => iter,
             Err(e) => {
                 let err = SingleIoError {
+                    attempts: 1,
                     stage: Stage::Parse,
                     target: target.clone(),
                     error: Box::new(e),
@@ -412,6 +1426,7 @@ impl IoEngine {
 
         let mapped = iter.map(move |res| {
             res.map_err(|e| SingleIoError {
+                attempts: 1,
                 stage: Stage::Parse,
                 target: target.clone(),
                 error: Box::new(e),
@@ -437,6 +1452,7 @@ impl IoEngine {
             Ok(k) => k,
             Err(e) => {
                 let err = SingleIoError {
+                    attempts: 1,
                     stage: Stage::ResolveInput,
                     target: spec.raw.clone(),
                     error: Box::new(e),
@@ -447,6 +1463,7 @@ impl IoEngine {
 
         if kind != crate::format::FormatKind::Json {
             let err = SingleIoError {
+                attempts: 1,
                 stage: Stage::ResolveInput,
                 target: spec.raw.clone(),
                 error: Box::new(crate::format::FormatError::UnknownFormat(kind)),
@@ -459,6 +1476,7 @@ impl IoEngine {
             Ok(r) => r,
             Err(e) => {
                 let err = SingleIoError {
+                    attempts: 1,
                     stage: Stage::Open,
                     target: spec.raw.clone(),
                     error: Box::new(e),
@@ -470,6 +1488,7 @@ impl IoEngine {
         let target = spec.raw.clone();
         let iter = crate::format::deserialize_json_stream::<T, _>(reader).map(move |res| {
             res.map_err(|e| SingleIoError {
+                attempts: 1,
                 stage: Stage::Parse,
                 target: target.clone(),
                 error: Box::new(e),
@@ -1,12 +1,24 @@
 //! Asynchronous I/O engine for orchestrating async read and write operations.
 
-use futures::stream::{self, BoxStream, StreamExt};
-use serde::{Serialize, de::DeserializeOwned};
-use tokio::io::AsyncReadExt;
+use std::collections::HashMap;
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime};
+
+use futures::stream::{self, BoxStream, Stream, StreamExt};
+use serde::{de::DeserializeOwned, Serialize};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
 
 use crate::config::{AsyncInputSpec, AsyncOutputSpec, FileExistsPolicy};
-use crate::error::{AggregateError, ErrorPolicy, SingleIoError, Stage};
+use crate::error::{backoff_delay, AggregateError, ErrorPolicy, SingleIoError, Stage};
 use crate::format::{self, AsyncFormatRegistry, FormatKind, FormatRegistry};
+use crate::io::AsyncInputProvider;
+
+/// Monotonic counter mixed into temporary file names so that concurrent
+/// atomic writes within the same process never collide.
+static ATOMIC_WRITE_COUNTER: AtomicU64 = AtomicU64::new(0);
 
 /// Asynchronous I/O engine for orchestrating multi-input/multi-output operations.
 pub struct AsyncIoEngine {
@@ -15,6 +27,100 @@ pub struct AsyncIoEngine {
     error_policy: ErrorPolicy,
     inputs: Vec<AsyncInputSpec>,
     outputs: Vec<AsyncOutputSpec>,
+    concurrency: Option<usize>,
+    watch: bool,
+    watch_debounce: Duration,
+}
+
+/// The outcome of one resolve→decode→encode→write pass performed by
+/// `AsyncIoEngine::run_watched`.
+#[derive(Debug, Clone)]
+pub struct RunReport {
+    /// Watched input paths whose modification triggered this run. Empty for
+    /// the initial run, which fires immediately when the stream is first
+    /// polled rather than in response to a file change.
+    pub changed_paths: Vec<PathBuf>,
+    /// Number of records read (and, on success, written) during this run.
+    pub items_read: usize,
+}
+
+/// How often `run_watched` polls watched files for a changed mtime.
+///
+/// There is no filesystem-notification crate in this dependency tree, so
+/// watching is approximated by polling `tokio::fs::metadata` rather than
+/// reacting to true OS-level change events. This keeps latency low enough
+/// for interactive use without depending on anything beyond `tokio::fs`.
+const WATCH_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Tracks the last-seen modification time of every watched path across
+/// polls of `AsyncIoEngine::run_watched`.
+struct WatchState {
+    paths: Vec<PathBuf>,
+    mtimes: HashMap<PathBuf, SystemTime>,
+    first_run: bool,
+}
+
+impl WatchState {
+    fn new(paths: Vec<PathBuf>) -> Self {
+        Self {
+            paths,
+            mtimes: HashMap::new(),
+            first_run: true,
+        }
+    }
+
+    /// Record the current mtime of every watched path without treating any
+    /// of them as "changed" yet. Called once before the first wait so that
+    /// files untouched since startup don't immediately trigger a rerun.
+    async fn establish_baseline(&mut self) {
+        for path in self.paths.clone() {
+            if let Ok(modified) = Self::mtime(&path).await {
+                self.mtimes.insert(path, modified);
+            }
+        }
+    }
+
+    async fn mtime(path: &Path) -> std::io::Result<SystemTime> {
+        tokio::fs::metadata(path).await?.modified()
+    }
+
+    /// Poll until at least one watched path's mtime changes, then keep
+    /// polling through `debounce` to absorb the rest of the same burst
+    /// before returning the full set of paths that changed.
+    async fn await_next_change(&mut self, debounce: Duration) -> Vec<PathBuf> {
+        loop {
+            tokio::time::sleep(WATCH_POLL_INTERVAL).await;
+            let changed = self.poll_changes().await;
+            if changed.is_empty() {
+                continue;
+            }
+
+            tokio::time::sleep(debounce).await;
+            let mut changed = changed;
+            for path in self.poll_changes().await {
+                if !changed.contains(&path) {
+                    changed.push(path);
+                }
+            }
+            return changed;
+        }
+    }
+
+    /// Check every watched path once and report those whose mtime differs
+    /// from what we last recorded, updating the recorded mtime as we go.
+    async fn poll_changes(&mut self) -> Vec<PathBuf> {
+        let mut changed = Vec::new();
+        for path in self.paths.clone() {
+            let Ok(modified) = Self::mtime(&path).await else {
+                continue;
+            };
+            if self.mtimes.get(&path) != Some(&modified) {
+                self.mtimes.insert(path.clone(), modified);
+                changed.push(path);
+            }
+        }
+        changed
+    }
 }
 
 impl AsyncIoEngine {
@@ -31,6 +137,9 @@ impl AsyncIoEngine {
             error_policy,
             inputs,
             outputs,
+            concurrency: None,
+            watch: false,
+            watch_debounce: Duration::from_millis(200),
         }
     }
 
@@ -52,6 +161,9 @@ impl AsyncIoEngine {
             error_policy,
             inputs,
             outputs,
+            concurrency: None,
+            watch: false,
+            watch_debounce: Duration::from_millis(200),
         }
     }
 
@@ -75,6 +187,84 @@ impl AsyncIoEngine {
         &self.outputs
     }
 
+    /// Opt into bounded concurrent fan-out for `read_all_parallel`/
+    /// `write_all_parallel`: at most `concurrency` inputs/outputs are driven
+    /// at once (clamped to at least 1). Without this, the parallel variants
+    /// fall back to their sequential counterparts. Mirrors
+    /// `IoEngine::with_concurrency`, but bounds concurrent async tasks via
+    /// `buffer_unordered` instead of a thread pool.
+    pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = Some(concurrency.max(1));
+        self
+    }
+
+    /// Get the configured parallel concurrency, if any.
+    pub fn concurrency(&self) -> Option<usize> {
+        self.concurrency
+    }
+
+    /// Mark this engine as watch-capable. See `MultiioAsyncBuilder::with_watch`.
+    pub fn with_watch(mut self, enabled: bool) -> Self {
+        self.watch = enabled;
+        self
+    }
+
+    /// Whether this engine was built with `MultiioAsyncBuilder::with_watch(true)`.
+    pub fn watch_enabled(&self) -> bool {
+        self.watch
+    }
+
+    /// Set the debounce window `run_watched` uses to coalesce a burst of
+    /// rapid file changes into a single rerun.
+    pub fn with_watch_debounce(mut self, debounce: Duration) -> Self {
+        self.watch_debounce = debounce;
+        self
+    }
+
+    /// Get the configured watch debounce window.
+    pub fn watch_debounce(&self) -> Duration {
+        self.watch_debounce
+    }
+
+    /// Run a single-attempt async I/O operation, retrying it under
+    /// `ErrorPolicy::Retry` as long as the error is transient
+    /// (`SingleIoError::is_transient`) and the attempt budget isn't
+    /// exhausted. Each retry calls `op` again from scratch, so it reopens the
+    /// underlying provider/target rather than resuming a failed stream. The
+    /// returned error's `attempts` field reflects how many attempts were
+    /// actually made. With any other error policy, `op` runs exactly once.
+    /// Mirrors `IoEngine::with_retry`, but sleeps via `tokio::time::sleep` so
+    /// it never blocks the async runtime.
+    async fn with_retry_async<T, F, Fut>(&self, mut op: F) -> Result<T, SingleIoError>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, SingleIoError>>,
+    {
+        let ErrorPolicy::Retry {
+            max_attempts,
+            base_delay,
+            max_delay,
+        } = self.error_policy
+        else {
+            return op().await;
+        };
+
+        let mut attempt = 1;
+        loop {
+            match op().await {
+                Ok(value) => return Ok(value),
+                Err(mut e) => {
+                    e.attempts = attempt;
+                    if attempt >= max_attempts.max(1) || !e.is_transient() {
+                        return Err(e);
+                    }
+                    tokio::time::sleep(backoff_delay(base_delay, max_delay, attempt)).await;
+                    attempt += 1;
+                }
+            }
+        }
+    }
+
     /// Read all inputs and deserialize each into type T.
     pub async fn read_all<T>(&self) -> Result<Vec<T>, AggregateError>
     where
@@ -85,7 +275,10 @@ impl AsyncIoEngine {
         let mut buffer = Vec::new();
 
         for spec in &self.inputs {
-            match self.read_one_with_buffer::<T>(spec, &mut buffer).await {
+            match self
+                .with_retry_async(|| self.read_one_with_buffer::<T>(spec, &mut buffer))
+                .await
+            {
                 Ok(value) => results.push(value),
                 Err(e) => {
                     errors.push(e);
@@ -103,6 +296,84 @@ impl AsyncIoEngine {
         }
     }
 
+    /// Like `read_all`, but fans the per-input work out with bounded
+    /// concurrency via `futures::stream::buffer_unordered` when
+    /// `with_concurrency` has been set; otherwise falls back to `read_all`.
+    ///
+    /// Results are returned in the original input order regardless of which
+    /// order tasks finish in. Under `ErrorPolicy::FastFail`, the first error
+    /// flips a cancellation flag so that inputs not yet started skip their
+    /// work; inputs already in flight at that point still run to completion,
+    /// and only the first error observed is returned. Under
+    /// `ErrorPolicy::Accumulate`, every input is read and all errors are
+    /// gathered into one `AggregateError`.
+    pub async fn read_all_parallel<T>(&self) -> Result<Vec<T>, AggregateError>
+    where
+        T: DeserializeOwned + Send + 'static,
+    {
+        let Some(concurrency) = self.concurrency else {
+            return self.read_all::<T>().await;
+        };
+
+        let len = self.inputs.len();
+        if len == 0 {
+            return Ok(Vec::new());
+        }
+        let concurrency = concurrency.min(len);
+
+        let cancelled = AtomicBool::new(false);
+        let results: Mutex<Vec<Option<T>>> = Mutex::new((0..len).map(|_| None).collect());
+        let first_error: Mutex<Option<SingleIoError>> = Mutex::new(None);
+        let errors: Mutex<Vec<SingleIoError>> = Mutex::new(Vec::new());
+
+        let tasks = self.inputs.iter().enumerate().map(|(idx, spec)| async move {
+            if matches!(self.error_policy, ErrorPolicy::FastFail)
+                && cancelled.load(Ordering::SeqCst)
+            {
+                return;
+            }
+            let mut buffer = Vec::new();
+            match self
+                .with_retry_async(|| self.read_one_with_buffer::<T>(spec, &mut buffer))
+                .await
+            {
+                Ok(value) => results.lock().unwrap()[idx] = Some(value),
+                Err(e) => {
+                    if matches!(self.error_policy, ErrorPolicy::FastFail) {
+                        if !cancelled.swap(true, Ordering::SeqCst) {
+                            *first_error.lock().unwrap() = Some(e);
+                        }
+                    } else {
+                        errors.lock().unwrap().push(e);
+                    }
+                }
+            }
+        });
+
+        stream::iter(tasks)
+            .buffer_unordered(concurrency)
+            .collect::<Vec<()>>()
+            .await;
+
+        if matches!(self.error_policy, ErrorPolicy::FastFail) {
+            if let Some(e) = first_error.into_inner().unwrap() {
+                return Err(AggregateError { errors: vec![e] });
+            }
+        } else {
+            let errors = errors.into_inner().unwrap();
+            if !errors.is_empty() {
+                return Err(AggregateError { errors });
+            }
+        }
+
+        Ok(results
+            .into_inner()
+            .unwrap()
+            .into_iter()
+            .map(|v| v.expect("every index is filled when read_all_parallel reports no errors"))
+            .collect())
+    }
+
     /// Stream records from all inputs using their resolved formats.
     ///
     /// This mirrors the synchronous `IoEngine::read_records` API but for async
@@ -111,6 +382,10 @@ impl AsyncIoEngine {
     /// available (e.g. JSON NDJSON, CSV rows, YAML multi-doc, plaintext
     /// line-based). For formats without streaming support this falls back to a
     /// single-item deserialization.
+    ///
+    /// `concurrency` is clamped to at least 1, same as `with_concurrency`: a
+    /// literal 0 would otherwise be passed straight to `buffer_unordered`,
+    /// which never polls any of its inner futures and hangs forever.
     pub fn read_records_async<T>(
         &self,
         concurrency: usize,
@@ -124,7 +399,139 @@ impl AsyncIoEngine {
             .map(|spec| self.records_stream_for_spec_async::<T>(spec));
 
         stream::iter(futs)
-            .buffer_unordered(concurrency)
+            .buffer_unordered(concurrency.max(1))
+            .flat_map(|s| s)
+            .boxed()
+    }
+
+    /// Stream records from all inputs using their resolved formats, like
+    /// `read_records_async`, but preserving input order.
+    ///
+    /// `read_records_async` uses `buffer_unordered`, so a fast input's
+    /// records can race ahead of a slower one opened earlier; that's fine
+    /// for independent inputs but corrupts a pipeline that concatenates many
+    /// shards into one deterministic stream. This uses `buffered` instead:
+    /// up to `concurrency` inputs are still opened and read concurrently,
+    /// but their record streams are flushed in the original input order, so
+    /// one slow input delays only its own (and later inputs') records, not
+    /// the overall ordering. `concurrency` is clamped to at least 1, same as
+    /// `read_records_async`.
+    pub fn read_records_async_ordered<T>(
+        &self,
+        concurrency: usize,
+    ) -> BoxStream<'_, Result<T, SingleIoError>>
+    where
+        T: DeserializeOwned + Send + 'static,
+    {
+        let futs = self
+            .inputs
+            .iter()
+            .map(|spec| self.records_stream_for_spec_async::<T>(spec));
+
+        stream::iter(futs)
+            .buffered(concurrency.max(1))
+            .flat_map(|s| s)
+            .boxed()
+    }
+
+    /// Stream records from all inputs as schema-less `serde_json::Value`s.
+    ///
+    /// This is `read_records_async::<serde_json::Value>` under another name:
+    /// it shares the exact per-format streaming iterators (NDJSON, CSV rows
+    /// keyed by the header row, YAML documents, the custom streaming bridge)
+    /// and the same `Stage::Parse`/`SingleIoError` per-record error
+    /// reporting, but lets callers inspect heterogeneous rows, filter by
+    /// field, or reshape before committing to a concrete type.
+    pub fn read_records_dynamic_async(
+        &self,
+        concurrency: usize,
+    ) -> BoxStream<'_, Result<serde_json::Value, SingleIoError>> {
+        self.read_records_async::<serde_json::Value>(concurrency)
+    }
+
+    /// Stream JSON records from inputs whose resolved format is JSON,
+    /// driven by the `AsyncInputProvider` readers rather than blocking the
+    /// runtime inside a sync iterator. Mirrors `IoEngine::read_json_records`.
+    ///
+    /// Inputs that resolve to a format other than JSON yield a single
+    /// `Stage::ResolveInput` error rather than being silently skipped.
+    #[cfg(feature = "json")]
+    pub fn read_json_records_async<T>(
+        &self,
+        concurrency: usize,
+    ) -> BoxStream<'_, Result<T, SingleIoError>>
+    where
+        T: DeserializeOwned + Send + 'static,
+    {
+        self.kind_checked_records_stream_async::<T>(FormatKind::Json, concurrency)
+    }
+
+    /// Stream CSV records from inputs whose resolved format is CSV, driven
+    /// by the `AsyncInputProvider` readers rather than blocking the runtime
+    /// inside a sync iterator. Mirrors `IoEngine::read_csv_records`.
+    ///
+    /// Inputs that resolve to a format other than CSV yield a single
+    /// `Stage::ResolveInput` error rather than being silently skipped.
+    #[cfg(feature = "csv")]
+    pub fn read_csv_records_async<T>(
+        &self,
+        concurrency: usize,
+    ) -> BoxStream<'_, Result<T, SingleIoError>>
+    where
+        T: DeserializeOwned + Send + 'static,
+    {
+        self.kind_checked_records_stream_async::<T>(FormatKind::Csv, concurrency)
+    }
+
+    /// Shared plumbing for `read_json_records_async`/`read_csv_records_async`:
+    /// resolve each input's format, reject (as a `Stage::ResolveInput` error)
+    /// any input that doesn't resolve to `expected_kind`, and otherwise
+    /// stream it through `records_stream_for_spec_async` like
+    /// `read_records_async` does.
+    fn kind_checked_records_stream_async<T>(
+        &self,
+        expected_kind: FormatKind,
+        concurrency: usize,
+    ) -> BoxStream<'_, Result<T, SingleIoError>>
+    where
+        T: DeserializeOwned + Send + 'static,
+    {
+        let futs = self.inputs.iter().map(move |spec| {
+            let spec = spec;
+            async move {
+                let kind = if let Some(sync_registry) = &self.sync_registry {
+                    sync_registry.resolve(spec.explicit_format.as_ref(), &spec.format_candidates)
+                } else {
+                    self.registry
+                        .resolve(spec.explicit_format.as_ref(), &spec.format_candidates)
+                };
+
+                match kind {
+                    Ok(k) if k == expected_kind => self.records_stream_for_spec_async::<T>(spec).await,
+                    Ok(k) => {
+                        let err = SingleIoError {
+                            attempts: 1,
+                            stage: Stage::ResolveInput,
+                            target: spec.raw.clone(),
+                            error: Box::new(format::FormatError::UnknownFormat(k)),
+                        };
+                        stream::iter(std::iter::once(Err(err))).boxed()
+                    }
+                    Err(e) => {
+                        let err = SingleIoError {
+                            attempts: 1,
+                            stage: Stage::ResolveInput,
+                            target: spec.raw.clone(),
+                            error: Box::new(e),
+                        };
+                        stream::iter(std::iter::once(Err(err))).boxed()
+                    }
+                }
+            }
+        });
+
+        stream::iter(futs)
+            .buffer_unordered(concurrency.max(1))
             .flat_map(|s| s)
             .boxed()
     }
@@ -143,11 +550,40 @@ impl AsyncIoEngine {
         spec: &AsyncInputSpec,
         buffer: &mut Vec<u8>,
     ) -> Result<T, SingleIoError>
+    where
+        T: DeserializeOwned + Send + 'static,
+    {
+        #[cfg(feature = "tracing")]
+        let _span = crate::trace::operation_span("read", &spec.raw).entered();
+        #[cfg(feature = "tracing")]
+        let start = std::time::Instant::now();
+
+        let result = self.read_one_with_buffer_impl::<T>(spec, buffer).await;
+
+        #[cfg(feature = "tracing")]
+        crate::trace::record_outcome(
+            &result,
+            Stage::Parse,
+            &spec.raw,
+            spec.explicit_format.as_ref(),
+            buffer.len(),
+            start,
+        );
+
+        result
+    }
+
+    async fn read_one_with_buffer_impl<T>(
+        &self,
+        spec: &AsyncInputSpec,
+        buffer: &mut Vec<u8>,
+    ) -> Result<T, SingleIoError>
     where
         T: DeserializeOwned + Send + 'static,
     {
         // Open the input stream
         let mut reader = spec.provider.open().await.map_err(|e| SingleIoError {
+            attempts: 1,
             stage: Stage::Open,
             target: spec.raw.clone(),
             error: Box::new(e),
@@ -159,6 +595,7 @@ impl AsyncIoEngine {
             .read_to_end(buffer)
             .await
             .map_err(|e| SingleIoError {
+                attempts: 1,
                 stage: Stage::Open,
                 target: spec.raw.clone(),
                 error: Box::new(e),
@@ -183,6 +620,7 @@ impl AsyncIoEngine {
                     };
 
                     Err(SingleIoError {
+                        attempts: 1,
                         stage,
                         target: spec.raw.clone(),
                         error: Box::new(e),
@@ -190,24 +628,30 @@ impl AsyncIoEngine {
                 }
             }
         } else {
-            // Resolve the format using the async registry and fall back to the
-            // existing async-format helpers.
-            let kind = self
-                .registry
-                .resolve(spec.explicit_format.as_ref(), &spec.format_candidates)
-                .map_err(|e| SingleIoError {
-                    stage: Stage::ResolveInput,
-                    target: spec.raw.clone(),
-                    error: Box::new(e),
-                })?;
-
-            // Deserialize
-            format::deserialize_async::<T>(kind, buffer)
+            // Resolve and deserialize using the async registry, which also
+            // dispatches to any registered `AsyncCustomFormat` before falling
+            // back to the built-in async-format helpers.
+            self.registry
+                .deserialize_value_async::<T>(
+                    spec.explicit_format.as_ref(),
+                    &spec.format_candidates,
+                    buffer,
+                )
                 .await
-                .map_err(|e| SingleIoError {
-                    stage: Stage::Parse,
-                    target: spec.raw.clone(),
-                    error: Box::new(e),
+                .map_err(|e| {
+                    let stage = match e {
+                        format::FormatError::UnknownFormat(_)
+                        | format::FormatError::NoFormatMatched
+                        | format::FormatError::NotEnabled(_) => Stage::ResolveInput,
+                        _ => Stage::Parse,
+                    };
+
+                    SingleIoError {
+                        attempts: 1,
+                        stage,
+                        target: spec.raw.clone(),
+                        error: Box::new(e),
+                    }
                 })
         }
     }
@@ -220,7 +664,7 @@ impl AsyncIoEngine {
         let mut errors = Vec::new();
 
         for spec in &self.outputs {
-            if let Err(e) = self.write_one(spec, values).await {
+            if let Err(e) = self.with_retry_async(|| self.write_one(spec, values)).await {
                 errors.push(e);
                 if matches!(self.error_policy, ErrorPolicy::FastFail) {
                     return Err(AggregateError { errors });
@@ -235,6 +679,354 @@ impl AsyncIoEngine {
         }
     }
 
+    /// Write items from `stream` to the configured output as they're
+    /// produced, rather than collecting into a `Vec` first. Mirrors
+    /// `IoEngine::write_stream`, but only has a true bounded-memory path for
+    /// a single output whose resolved format is NDJSON (feature-gated on
+    /// `ndjson`): each item is serialized and appended to the open writer as
+    /// it's pulled off `stream`, honoring `ErrorPolicy` per item the same
+    /// way `write_ndjson_records` does for the sync engine. Any other shape
+    /// -- zero or multiple outputs, a non-NDJSON format, or the `ndjson`
+    /// feature disabled -- drains `stream` into a `Vec` and falls through to
+    /// `write_all`, same as `IoEngine::write_stream`'s own fallback.
+    pub async fn write_stream_records_async<T>(
+        &self,
+        stream: impl Stream<Item = T> + Unpin + Send,
+    ) -> Result<(), AggregateError>
+    where
+        T: Serialize + Sync,
+    {
+        #[cfg(feature = "ndjson")]
+        if let [spec] = self.outputs.as_slice() {
+            let kind = if let Some(sync_registry) = &self.sync_registry {
+                sync_registry.resolve(spec.explicit_format.as_ref(), &spec.format_candidates)
+            } else {
+                self.registry
+                    .resolve(spec.explicit_format.as_ref(), &spec.format_candidates)
+            };
+
+            if matches!(kind, Ok(FormatKind::Ndjson)) {
+                return self.write_ndjson_stream_one(spec, stream).await;
+            }
+        }
+
+        let values: Vec<T> = stream.collect().await;
+        self.write_all(&values).await
+    }
+
+    /// Bounded-memory NDJSON writer backing `write_stream_records_async`:
+    /// opens `spec` once (honoring `file_exists_policy`, including the
+    /// temp-file-then-rename dance `write_atomic` gives a single buffered
+    /// write), then serializes and appends each item from `stream` as it's
+    /// pulled, so a multi-gigabyte source never needs to be held in memory
+    /// all at once.
+    #[cfg(feature = "ndjson")]
+    async fn write_ndjson_stream_one<T>(
+        &self,
+        spec: &AsyncOutputSpec,
+        mut stream: impl Stream<Item = T> + Unpin + Send,
+    ) -> Result<(), AggregateError>
+    where
+        T: Serialize + Sync,
+    {
+        enum NdjsonSinkAsync {
+            Direct(Box<dyn tokio::io::AsyncWrite + Unpin + Send>),
+            Atomic {
+                writer: Box<dyn tokio::io::AsyncWrite + Unpin + Send>,
+                tmp_path: PathBuf,
+                dest_path: PathBuf,
+            },
+        }
+
+        let open_err = |e: std::io::Error| SingleIoError {
+            attempts: 1,
+            stage: Stage::Open,
+            target: spec.raw.clone(),
+            error: Box::new(e),
+        };
+
+        let mut sink = match spec.file_exists_policy {
+            FileExistsPolicy::Append => {
+                match spec.target.open_append().await {
+                    Ok(w) => NdjsonSinkAsync::Direct(w),
+                    Err(e) => return Err(AggregateError::single(open_err(e))),
+                }
+            }
+            FileExistsPolicy::Error => {
+                if let Some(path) = spec.target.file_path() {
+                    if tokio::fs::metadata(path).await.is_ok() {
+                        return Err(AggregateError::single(open_err(std::io::Error::new(
+                            std::io::ErrorKind::AlreadyExists,
+                            format!("output file already exists: {}", path.display()),
+                        ))));
+                    }
+                }
+                match spec.target.open_overwrite().await {
+                    Ok(w) => NdjsonSinkAsync::Direct(w),
+                    Err(e) => return Err(AggregateError::single(open_err(e))),
+                }
+            }
+            FileExistsPolicy::Overwrite => match spec.target.open_overwrite().await {
+                Ok(w) => NdjsonSinkAsync::Direct(w),
+                Err(e) => return Err(AggregateError::single(open_err(e))),
+            },
+            FileExistsPolicy::AtomicOverwrite => match spec.target.file_path() {
+                Some(path) => {
+                    let dir = path.parent().filter(|p| !p.as_os_str().is_empty());
+                    let file_name = path
+                        .file_name()
+                        .map(|n| n.to_string_lossy().into_owned())
+                        .unwrap_or_else(|| "output".to_string());
+                    let unique = ATOMIC_WRITE_COUNTER.fetch_add(1, Ordering::Relaxed);
+                    let tmp_name = format!(".{file_name}.tmp-{}-{unique}", std::process::id());
+                    let tmp_path = match dir {
+                        Some(dir) => dir.join(tmp_name),
+                        None => PathBuf::from(tmp_name),
+                    };
+                    if let Some(dir) = dir {
+                        if let Err(e) = tokio::fs::create_dir_all(dir).await {
+                            return Err(AggregateError::single(open_err(e)));
+                        }
+                    }
+                    match spec.target.open_overwrite_at(&tmp_path).await {
+                        Ok(writer) => NdjsonSinkAsync::Atomic {
+                            writer,
+                            tmp_path,
+                            dest_path: path.to_path_buf(),
+                        },
+                        Err(e) => return Err(AggregateError::single(open_err(e))),
+                    }
+                }
+                None => match spec.target.open_overwrite().await {
+                    Ok(w) => NdjsonSinkAsync::Direct(w),
+                    Err(e) => return Err(AggregateError::single(open_err(e))),
+                },
+            },
+        };
+
+        let mut errors = Vec::new();
+        let mut write_failed = false;
+
+        while let Some(value) = stream.next().await {
+            let bytes_result = if let Some(sync_registry) = &self.sync_registry {
+                sync_registry.serialize_value::<T>(Some(&FormatKind::Ndjson), &[], &value)
+            } else {
+                self.registry
+                    .serialize_value_async::<T>(Some(&FormatKind::Ndjson), &[], &value)
+                    .await
+            };
+
+            let bytes = match bytes_result {
+                Ok(b) => b,
+                Err(e) => {
+                    errors.push(SingleIoError {
+                        attempts: 1,
+                        stage: Stage::Serialize,
+                        target: spec.raw.clone(),
+                        error: Box::new(e),
+                    });
+                    if matches!(self.error_policy, ErrorPolicy::FastFail) {
+                        return Err(AggregateError { errors });
+                    }
+                    continue;
+                }
+            };
+
+            let write_result = match &mut sink {
+                NdjsonSinkAsync::Direct(w) => w.write_all(&bytes).await,
+                NdjsonSinkAsync::Atomic { writer, .. } => writer.write_all(&bytes).await,
+            };
+
+            if let Err(e) = write_result {
+                errors.push(SingleIoError {
+                    attempts: 1,
+                    stage: Stage::Write,
+                    target: spec.raw.clone(),
+                    error: Box::new(e),
+                });
+                // The sink is now in an unknown state; further writes to it
+                // would likely keep failing, so stop pulling more items
+                // rather than looping through the rest of a dead stream.
+                write_failed = true;
+                break;
+            }
+        }
+
+        if let NdjsonSinkAsync::Atomic { writer, tmp_path, dest_path } = sink {
+            if write_failed {
+                // A prior write_all already failed, so the temp file is
+                // truncated/partial. Finalizing anyway (shutdown/fsync can
+                // succeed even though a prior write_all on the same sink
+                // failed) would rename that partial file over `dest_path`,
+                // breaking the "only the old file or the complete new one,
+                // never a truncated one" guarantee `write_atomic` documents.
+                // Drop the writer and remove the temp file instead.
+                drop(writer);
+                let _ = tokio::fs::remove_file(&tmp_path).await;
+            } else {
+                let result: std::io::Result<()> = async {
+                    // Shutdown (not just drop) before fsync: a wrapping target's
+                    // compressing/filtering `AsyncWrite` only flushes its
+                    // trailing bytes on an explicit, awaited shutdown (see
+                    // `write_all_and_shutdown`).
+                    let mut writer = writer;
+                    writer.shutdown().await?;
+                    drop(writer);
+                    tokio::fs::File::open(&tmp_path).await?.sync_all().await?;
+                    tokio::fs::rename(&tmp_path, &dest_path).await?;
+                    Ok(())
+                }
+                .await;
+
+                if let Err(e) = result {
+                    let _ = tokio::fs::remove_file(&tmp_path).await;
+                    errors.push(SingleIoError {
+                        attempts: 1,
+                        stage: Stage::Write,
+                        target: spec.raw.clone(),
+                        error: Box::new(e),
+                    });
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(AggregateError { errors })
+        }
+    }
+
+    /// Like `write_all`, but fans the per-output work out with bounded
+    /// concurrency via `futures::stream::buffer_unordered` when
+    /// `with_concurrency` has been set; otherwise falls back to `write_all`.
+    ///
+    /// Error-policy semantics mirror `read_all_parallel`: under
+    /// `ErrorPolicy::FastFail`, the first error flips a cancellation flag so
+    /// outputs not yet started skip their work, and only that first error is
+    /// returned. Under `ErrorPolicy::Accumulate`, every output is written and
+    /// all errors are gathered into one `AggregateError`.
+    pub async fn write_all_parallel<T>(&self, values: &[T]) -> Result<(), AggregateError>
+    where
+        T: Serialize + Sync,
+    {
+        let Some(concurrency) = self.concurrency else {
+            return self.write_all(values).await;
+        };
+
+        self.fan_out_writes(concurrency, |spec| {
+            self.with_retry_async(|| self.write_one(spec, values))
+        })
+        .await
+    }
+
+    /// Like `write_all_parallel`, but takes `concurrency` as an explicit
+    /// parameter instead of reading it from `with_concurrency`, mirroring how
+    /// `read_stream_async` takes its own `concurrency` rather than relying on
+    /// engine-level state. Useful for fanning a single write out across
+    /// outputs of very different speeds (a slow network sink alongside a
+    /// fast local file) without that concurrency also applying to reads.
+    pub async fn write_stream_async<T>(
+        &self,
+        values: &[T],
+        concurrency: usize,
+    ) -> Result<(), AggregateError>
+    where
+        T: Serialize + Sync,
+    {
+        self.fan_out_writes(concurrency, |spec| {
+            self.with_retry_async(|| self.write_one(spec, values))
+        })
+        .await
+    }
+
+    /// Single-value counterpart to `write_stream_async`, mirroring how
+    /// `write_one_value` relates to `write_all`.
+    pub async fn write_stream_async_one_value<T>(
+        &self,
+        value: &T,
+        concurrency: usize,
+    ) -> Result<(), AggregateError>
+    where
+        T: Serialize + Sync,
+    {
+        self.fan_out_writes(concurrency, |spec| {
+            self.with_retry_async(|| self.write_single(spec, value))
+        })
+        .await
+    }
+
+    /// Shared fan-out/error-collection core for `write_all_parallel`,
+    /// `write_stream_async`, and `write_stream_async_one_value`: runs
+    /// `per_output` for every output with bounded concurrency via
+    /// `futures::stream::buffer_unordered`. `concurrency` is clamped to at
+    /// least 1, same as `with_concurrency`: a literal 0 would otherwise be
+    /// passed straight to `buffer_unordered`, which never polls any of its
+    /// inner futures and hangs forever.
+    ///
+    /// Error-policy semantics mirror `read_all_parallel`: under
+    /// `ErrorPolicy::FastFail`, the first error flips a cancellation flag so
+    /// outputs not yet started skip their work, and only that first error is
+    /// returned. Under `ErrorPolicy::Accumulate`, every output is written and
+    /// all errors are gathered into one `AggregateError`.
+    async fn fan_out_writes<'a, F, Fut>(
+        &'a self,
+        concurrency: usize,
+        per_output: F,
+    ) -> Result<(), AggregateError>
+    where
+        F: Fn(&'a AsyncOutputSpec) -> Fut,
+        Fut: Future<Output = Result<(), SingleIoError>> + 'a,
+    {
+        let len = self.outputs.len();
+        if len == 0 {
+            return Ok(());
+        }
+        let concurrency = concurrency.max(1).min(len);
+
+        let cancelled = AtomicBool::new(false);
+        let first_error: Mutex<Option<SingleIoError>> = Mutex::new(None);
+        let errors: Mutex<Vec<SingleIoError>> = Mutex::new(Vec::new());
+
+        let tasks = self.outputs.iter().map(|spec| {
+            let fut = per_output(spec);
+            async {
+                if matches!(self.error_policy, ErrorPolicy::FastFail)
+                    && cancelled.load(Ordering::SeqCst)
+                {
+                    return;
+                }
+                if let Err(e) = fut.await {
+                    if matches!(self.error_policy, ErrorPolicy::FastFail) {
+                        if !cancelled.swap(true, Ordering::SeqCst) {
+                            *first_error.lock().unwrap() = Some(e);
+                        }
+                    } else {
+                        errors.lock().unwrap().push(e);
+                    }
+                }
+            }
+        });
+
+        stream::iter(tasks)
+            .buffer_unordered(concurrency)
+            .collect::<Vec<()>>()
+            .await;
+
+        if matches!(self.error_policy, ErrorPolicy::FastFail) {
+            if let Some(e) = first_error.into_inner().unwrap() {
+                return Err(AggregateError { errors: vec![e] });
+            }
+        } else {
+            let errors = errors.into_inner().unwrap();
+            if !errors.is_empty() {
+                return Err(AggregateError { errors });
+            }
+        }
+
+        Ok(())
+    }
+
     /// Write a single value to all outputs.
     pub async fn write_one_value<T>(&self, value: &T) -> Result<(), AggregateError>
     where
@@ -243,7 +1035,7 @@ impl AsyncIoEngine {
         let mut errors = Vec::new();
 
         for spec in &self.outputs {
-            if let Err(e) = self.write_single(spec, value).await {
+            if let Err(e) = self.with_retry_async(|| self.write_single(spec, value)).await {
                 errors.push(e);
                 if matches!(self.error_policy, ErrorPolicy::FastFail) {
                     return Err(AggregateError { errors });
@@ -260,6 +1052,37 @@ impl AsyncIoEngine {
 
     /// Write values to a single output.
     async fn write_one<T>(&self, spec: &AsyncOutputSpec, values: &[T]) -> Result<(), SingleIoError>
+    where
+        T: Serialize + Sync,
+    {
+        #[cfg(feature = "tracing")]
+        let _span = crate::trace::operation_span("write", &spec.raw).entered();
+        #[cfg(feature = "tracing")]
+        let start = std::time::Instant::now();
+
+        let result = self.write_one_impl(spec, values).await;
+
+        #[cfg(feature = "tracing")]
+        {
+            let bytes = result.as_ref().ok().copied().unwrap_or(0);
+            crate::trace::record_outcome(
+                &result,
+                Stage::Serialize,
+                &spec.raw,
+                spec.explicit_format.as_ref(),
+                bytes,
+                start,
+            );
+        }
+
+        result.map(|_| ())
+    }
+
+    async fn write_one_impl<T>(
+        &self,
+        spec: &AsyncOutputSpec,
+        values: &[T],
+    ) -> Result<usize, SingleIoError>
     where
         T: Serialize + Sync,
     {
@@ -267,11 +1090,21 @@ impl AsyncIoEngine {
         // serialization to it so that custom formats participate fully in
         // encoding. Otherwise, fall back to the async-format helpers.
         let bytes = if let Some(sync_registry) = &self.sync_registry {
-            match sync_registry.serialize_value(
-                spec.explicit_format.as_ref(),
-                &spec.format_candidates,
-                &values,
-            ) {
+            let result = match &spec.output_options {
+                Some(options) => sync_registry.serialize_value_with_options(
+                    spec.explicit_format.as_ref(),
+                    &spec.format_candidates,
+                    &values,
+                    options,
+                ),
+                None => sync_registry.serialize_value(
+                    spec.explicit_format.as_ref(),
+                    &spec.format_candidates,
+                    &values,
+                ),
+            };
+
+            match result {
                 Ok(bytes) => bytes,
                 Err(e) => {
                     let stage = match e {
@@ -282,6 +1115,7 @@ impl AsyncIoEngine {
                     };
 
                     return Err(SingleIoError {
+                        attempts: 1,
                         stage,
                         target: spec.raw.clone(),
                         error: Box::new(e),
@@ -289,32 +1123,85 @@ impl AsyncIoEngine {
                 }
             }
         } else {
-            let kind = self.resolve_output_kind(spec)?;
+            // Resolve and serialize using the async registry, which also
+            // dispatches to any registered `AsyncCustomFormat` before falling
+            // back to the built-in async-format helpers.
+            let result = match &spec.output_options {
+                Some(options) => {
+                    self.registry
+                        .serialize_value_async_with_options(
+                            spec.explicit_format.as_ref(),
+                            &spec.format_candidates,
+                            &values,
+                            options,
+                        )
+                        .await
+                }
+                None => {
+                    self.registry
+                        .serialize_value_async(
+                            spec.explicit_format.as_ref(),
+                            &spec.format_candidates,
+                            &values,
+                        )
+                        .await
+                }
+            };
 
-            format::serialize_async(kind, &values)
-                .await
-                .map_err(|e| SingleIoError {
-                    stage: Stage::Serialize,
+            result.map_err(|e| {
+                let stage = match e {
+                    format::FormatError::UnknownFormat(_)
+                    | format::FormatError::NoFormatMatched
+                    | format::FormatError::NotEnabled(_) => Stage::ResolveOutput,
+                    _ => Stage::Serialize,
+                };
+
+                SingleIoError {
+                    attempts: 1,
+                    stage,
                     target: spec.raw.clone(),
                     error: Box::new(e),
-                })?
+                }
+            })?
         };
 
-        // Open the output stream
-        let mut writer = self.open_output(spec).await?;
-
-        // Write bytes
-        tokio::io::AsyncWriteExt::write_all(&mut *writer, &bytes)
-            .await
-            .map_err(|e| SingleIoError {
-                stage: Stage::Serialize,
-                target: spec.raw.clone(),
-                error: Box::new(e),
-            })
+        self.write_output_bytes(spec, &bytes).await?;
+        Ok(bytes.len())
     }
 
     /// Write a single value to a specific output.
     async fn write_single<T>(&self, spec: &AsyncOutputSpec, value: &T) -> Result<(), SingleIoError>
+    where
+        T: Serialize + Sync,
+    {
+        #[cfg(feature = "tracing")]
+        let _span = crate::trace::operation_span("write", &spec.raw).entered();
+        #[cfg(feature = "tracing")]
+        let start = std::time::Instant::now();
+
+        let result = self.write_single_impl(spec, value).await;
+
+        #[cfg(feature = "tracing")]
+        {
+            let bytes = result.as_ref().ok().copied().unwrap_or(0);
+            crate::trace::record_outcome(
+                &result,
+                Stage::Serialize,
+                &spec.raw,
+                spec.explicit_format.as_ref(),
+                bytes,
+                start,
+            );
+        }
+
+        result.map(|_| ())
+    }
+
+    async fn write_single_impl<T>(
+        &self,
+        spec: &AsyncOutputSpec,
+        value: &T,
+    ) -> Result<usize, SingleIoError>
     where
         T: Serialize + Sync,
     {
@@ -322,11 +1209,21 @@ impl AsyncIoEngine {
         // serialization to it so that custom formats participate fully in
         // encoding. Otherwise, fall back to the async-format helpers.
         let bytes = if let Some(sync_registry) = &self.sync_registry {
-            match sync_registry.serialize_value(
-                spec.explicit_format.as_ref(),
-                &spec.format_candidates,
-                value,
-            ) {
+            let result = match &spec.output_options {
+                Some(options) => sync_registry.serialize_value_with_options(
+                    spec.explicit_format.as_ref(),
+                    &spec.format_candidates,
+                    value,
+                    options,
+                ),
+                None => sync_registry.serialize_value(
+                    spec.explicit_format.as_ref(),
+                    &spec.format_candidates,
+                    value,
+                ),
+            };
+
+            match result {
                 Ok(bytes) => bytes,
                 Err(e) => {
                     let stage = match e {
@@ -337,6 +1234,7 @@ impl AsyncIoEngine {
                     };
 
                     return Err(SingleIoError {
+                        attempts: 1,
                         stage,
                         target: spec.raw.clone(),
                         error: Box::new(e),
@@ -344,65 +1242,207 @@ impl AsyncIoEngine {
                 }
             }
         } else {
-            // Resolve the format
-            let kind = self
-                .registry
-                .resolve(spec.explicit_format.as_ref(), &spec.format_candidates)
-                .map_err(|e| SingleIoError {
-                    stage: Stage::ResolveOutput,
-                    target: spec.raw.clone(),
-                    error: Box::new(e),
-                })?;
+            // Resolve and serialize using the async registry, which also
+            // dispatches to any registered `AsyncCustomFormat` before falling
+            // back to the built-in async-format helpers.
+            let result = match &spec.output_options {
+                Some(options) => {
+                    self.registry
+                        .serialize_value_async_with_options(
+                            spec.explicit_format.as_ref(),
+                            &spec.format_candidates,
+                            value,
+                            options,
+                        )
+                        .await
+                }
+                None => {
+                    self.registry
+                        .serialize_value_async(
+                            spec.explicit_format.as_ref(),
+                            &spec.format_candidates,
+                            value,
+                        )
+                        .await
+                }
+            };
 
-            // Serialize to bytes
-            format::serialize_async(kind, value)
-                .await
-                .map_err(|e| SingleIoError {
-                    stage: Stage::Serialize,
+            result.map_err(|e| {
+                let stage = match e {
+                    format::FormatError::UnknownFormat(_)
+                    | format::FormatError::NoFormatMatched
+                    | format::FormatError::NotEnabled(_) => Stage::ResolveOutput,
+                    _ => Stage::Serialize,
+                };
+
+                SingleIoError {
+                    attempts: 1,
+                    stage,
                     target: spec.raw.clone(),
                     error: Box::new(e),
-                })?
+                }
+            })?
         };
 
-        // Open the output stream
-        let mut writer = self.open_output(spec).await?;
+        self.write_output_bytes(spec, &bytes).await?;
+        Ok(bytes.len())
+    }
 
-        // Write bytes
-        tokio::io::AsyncWriteExt::write_all(&mut *writer, &bytes)
-            .await
-            .map_err(|e| SingleIoError {
-                stage: Stage::Serialize,
-                target: spec.raw.clone(),
-                error: Box::new(e),
-            })
+    /// Writes `bytes` to `writer` and shuts it down, mapping any failure to a
+    /// `Stage::Write` error against `target` (the bytes are already
+    /// serialized by this point, so a failure here is the I/O write call
+    /// itself - see `Stage::Write`).
+    ///
+    /// The shutdown is what lets a compressing `AsyncWrite` (see
+    /// `crate::io::async_compression`) flush its footer: unlike the sync
+    /// encoders, which finish on `Drop`, `async-compression`'s encoders need
+    /// an explicit, awaited shutdown to write their closing bytes. For
+    /// uncompressed targets this is a harmless flush-and-close.
+    async fn write_all_and_shutdown(
+        mut writer: Box<dyn tokio::io::AsyncWrite + Unpin + Send>,
+        bytes: &[u8],
+        target: &str,
+    ) -> Result<(), SingleIoError> {
+        writer.write_all(bytes).await.map_err(|e| SingleIoError {
+            attempts: 1,
+            stage: Stage::Write,
+            target: target.to_string(),
+            error: Box::new(e),
+        })?;
+        writer.shutdown().await.map_err(|e| SingleIoError {
+            attempts: 1,
+            stage: Stage::Write,
+            target: target.to_string(),
+            error: Box::new(e),
+        })
     }
 
-    /// Open an output based on the file exists policy.
-    async fn open_output(
+    async fn write_output_bytes(
         &self,
         spec: &AsyncOutputSpec,
-    ) -> Result<Box<dyn tokio::io::AsyncWrite + Unpin + Send>, SingleIoError> {
-        let result = match spec.file_exists_policy {
-            FileExistsPolicy::Overwrite => spec.target.open_overwrite().await,
-            FileExistsPolicy::Append => spec.target.open_append().await,
-            FileExistsPolicy::Error => spec.target.open_overwrite().await,
+        bytes: &[u8],
+    ) -> Result<(), SingleIoError> {
+        match spec.file_exists_policy {
+            FileExistsPolicy::Append => {
+                let writer = spec.target.open_append().await.map_err(|e| SingleIoError {
+                    attempts: 1,
+                    stage: Stage::Open,
+                    target: spec.raw.clone(),
+                    error: Box::new(e),
+                })?;
+                Self::write_all_and_shutdown(writer, bytes, &spec.raw).await
+            }
+            FileExistsPolicy::Error => {
+                if let Some(path) = spec.target.file_path() {
+                    if tokio::fs::metadata(path).await.is_ok() {
+                        return Err(SingleIoError {
+                            attempts: 1,
+                            stage: Stage::Open,
+                            target: spec.raw.clone(),
+                            error: Box::new(std::io::Error::new(
+                                std::io::ErrorKind::AlreadyExists,
+                                format!("output file already exists: {}", path.display()),
+                            )),
+                        });
+                    }
+                }
+                let writer = spec
+                    .target
+                    .open_overwrite()
+                    .await
+                    .map_err(|e| SingleIoError {
+                        attempts: 1,
+                        stage: Stage::Open,
+                        target: spec.raw.clone(),
+                        error: Box::new(e),
+                    })?;
+                Self::write_all_and_shutdown(writer, bytes, &spec.raw).await
+            }
+            FileExistsPolicy::Overwrite => {
+                let writer = spec
+                    .target
+                    .open_overwrite()
+                    .await
+                    .map_err(|e| SingleIoError {
+                        attempts: 1,
+                        stage: Stage::Open,
+                        target: spec.raw.clone(),
+                        error: Box::new(e),
+                    })?;
+                Self::write_all_and_shutdown(writer, bytes, &spec.raw).await
+            }
+            FileExistsPolicy::AtomicOverwrite => match spec.target.file_path() {
+                Some(path) => self.write_atomic(spec, path, bytes).await,
+                None => {
+                    let writer =
+                        spec.target
+                            .open_overwrite()
+                            .await
+                            .map_err(|e| SingleIoError {
+                                attempts: 1,
+                                stage: Stage::Open,
+                                target: spec.raw.clone(),
+                                error: Box::new(e),
+                            })?;
+                    Self::write_all_and_shutdown(writer, bytes, &spec.raw).await
+                }
+            },
+        }
+    }
+
+    /// Write `bytes` to `path` transactionally: write to a temp file in the
+    /// same directory, flush and fsync it, then rename it over `path`. See
+    /// the sync engine's `write_atomic` for the rationale; this mirrors it
+    /// using `tokio::fs`.
+    async fn write_atomic(
+        &self,
+        spec: &AsyncOutputSpec,
+        path: &Path,
+        bytes: &[u8],
+    ) -> Result<(), SingleIoError> {
+        let dir = path.parent().filter(|p| !p.as_os_str().is_empty());
+        let file_name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_else(|| "output".to_string());
+        let unique = ATOMIC_WRITE_COUNTER.fetch_add(1, Ordering::Relaxed);
+        let tmp_name = format!(".{file_name}.tmp-{}-{unique}", std::process::id());
+        let tmp_path = match dir {
+            Some(dir) => dir.join(tmp_name),
+            None => std::path::PathBuf::from(tmp_name),
         };
 
-        result.map_err(|e| SingleIoError {
-            stage: Stage::Open,
-            target: spec.raw.clone(),
-            error: Box::new(e),
-        })
-    }
+        let result: std::io::Result<()> = async {
+            // `open_overwrite` would create the parent dir for us, but we
+            // bypass it here to go straight to the temp file.
+            if let Some(dir) = dir {
+                tokio::fs::create_dir_all(dir).await?;
+            }
+            let mut writer = spec.target.open_overwrite_at(&tmp_path).await?;
+            writer.write_all(bytes).await?;
+            // Shutdown (not just drop) is what lets a wrapping target's
+            // compressing/filtering `AsyncWrite` flush its trailing bytes
+            // before we fsync the temp file - see `write_all_and_shutdown`.
+            writer.shutdown().await?;
+            drop(writer);
+            tokio::fs::File::open(&tmp_path).await?.sync_all().await?;
+            tokio::fs::rename(&tmp_path, path).await?;
+            Ok(())
+        }
+        .await;
 
-    fn resolve_output_kind(&self, spec: &AsyncOutputSpec) -> Result<FormatKind, SingleIoError> {
-        self.registry
-            .resolve(spec.explicit_format.as_ref(), &spec.format_candidates)
-            .map_err(|e| SingleIoError {
-                stage: Stage::ResolveOutput,
-                target: spec.raw.clone(),
-                error: Box::new(e),
-            })
+        match result {
+            Ok(()) => Ok(()),
+            Err(e) => {
+                let _ = tokio::fs::remove_file(&tmp_path).await;
+                Err(SingleIoError {
+                    attempts: 1,
+                    stage: Stage::Write,
+                    target: spec.raw.clone(),
+                    error: Box::new(e),
+                })
+            }
+        }
     }
 
     /// Create a per-input stream of records for the given spec.
@@ -419,10 +1459,11 @@ impl AsyncIoEngine {
         T: DeserializeOwned + Send + 'static,
     {
         // Open the input stream
-        let mut reader = match spec.provider.open().await {
+        let reader = match spec.provider.open().await {
             Ok(r) => r,
             Err(e) => {
                 let err = SingleIoError {
+                    attempts: 1,
                     stage: Stage::Open,
                     target: spec.raw.clone(),
                     error: Box::new(e),
@@ -431,17 +1472,6 @@ impl AsyncIoEngine {
             }
         };
 
-        // Read all bytes into an internal buffer
-        let mut buffer = Vec::new();
-        if let Err(e) = reader.read_to_end(&mut buffer).await {
-            let err = SingleIoError {
-                stage: Stage::Open,
-                target: spec.raw.clone(),
-                error: Box::new(e),
-            };
-            return stream::iter(std::iter::once(Err(err))).boxed();
-        }
-
         // Resolve the format. If a sync registry is available we use it so
         // that custom formats (and their streaming handlers) participate in
         // resolution; otherwise we fall back to the async registry. Resolution
@@ -453,6 +1483,7 @@ impl AsyncIoEngine {
                 Ok(k) => k,
                 Err(e) => {
                     let err = SingleIoError {
+                        attempts: 1,
                         stage: Stage::Parse,
                         target: spec.raw.clone(),
                         error: Box::new(e),
@@ -468,6 +1499,7 @@ impl AsyncIoEngine {
                 Ok(k) => k,
                 Err(e) => {
                     let err = SingleIoError {
+                        attempts: 1,
                         stage: Stage::Parse,
                         target: spec.raw.clone(),
                         error: Box::new(e),
@@ -479,25 +1511,44 @@ impl AsyncIoEngine {
 
         let target = spec.raw.clone();
 
-        // Use format-specific streaming helpers where available.
-        if let FormatKind::Json = kind {
-            #[cfg(feature = "json")]
-            {
-                let reader = std::io::Cursor::new(buffer);
-                let iter = crate::format::deserialize_json_stream::<T, _>(reader);
-                return Self::iter_to_stream(iter, target);
-            }
-            #[cfg(not(feature = "json"))]
-            {
-                let err = SingleIoError {
-                    stage: Stage::Parse,
-                    target,
-                    error: Box::new(crate::format::FormatError::NotEnabled(kind)),
-                };
-                return stream::iter(std::iter::once(Err(err))).boxed();
-            }
+        // A format with a true `AsyncStreamFormat` decoder gets to consume
+        // `reader` directly, at most one record's worth of bytes at a time,
+        // instead of buffering the whole input up front.
+        if let Some(stream) = self
+            .registry
+            .deserialize_stream_async::<T>(kind, Box::new(tokio::io::BufReader::new(reader)))
+        {
+            let target_for_stream = target.clone();
+            return stream
+                .map(move |res| {
+                    res.map_err(|e| SingleIoError {
+                        attempts: 1,
+                        stage: Stage::Parse,
+                        target: target_for_stream.clone(),
+                        error: Box::new(e),
+                    })
+                })
+                .boxed();
+        }
+
+        // Otherwise, fall back to the existing buffer-and-replay path: read
+        // the whole input, then reuse the sync streaming helpers over an
+        // in-memory cursor.
+        let mut reader = reader;
+        let mut buffer = Vec::new();
+        if let Err(e) = reader.read_to_end(&mut buffer).await {
+            let err = SingleIoError {
+                attempts: 1,
+                stage: Stage::Open,
+                target: spec.raw.clone(),
+                error: Box::new(e),
+            };
+            return stream::iter(std::iter::once(Err(err))).boxed();
         }
 
+        // Use format-specific streaming helpers where available. JSON is
+        // handled above via `AsyncStreamFormat` and never reaches here.
+
         // If we have a sync registry and the resolved kind is a custom format,
         // bridge to the sync FormatRegistry's streaming implementation. This
         // supports custom streaming handlers and falls back to non-streaming
@@ -514,6 +1565,7 @@ impl AsyncIoEngine {
                 Ok(iter) => iter,
                 Err(e) => {
                     let err = SingleIoError {
+                        attempts: 1,
                         stage: Stage::Parse,
                         target,
                         error: Box::new(e),
@@ -538,6 +1590,7 @@ impl AsyncIoEngine {
             #[cfg(not(feature = "csv"))]
             {
                 let err = SingleIoError {
+                    attempts: 1,
                     stage: Stage::Parse,
                     target,
                     error: Box::new(crate::format::FormatError::NotEnabled(kind)),
@@ -557,6 +1610,7 @@ impl AsyncIoEngine {
             #[cfg(not(feature = "yaml"))]
             {
                 let err = SingleIoError {
+                    attempts: 1,
                     stage: Stage::Parse,
                     target,
                     error: Box::new(crate::format::FormatError::NotEnabled(kind)),
@@ -575,6 +1629,7 @@ impl AsyncIoEngine {
             #[cfg(not(feature = "plaintext"))]
             {
                 let err = SingleIoError {
+                    attempts: 1,
                     stage: Stage::Parse,
                     target,
                     error: Box::new(crate::format::FormatError::NotEnabled(kind)),
@@ -583,10 +1638,15 @@ impl AsyncIoEngine {
             }
         }
 
-        // Other formats (including unsupported/custom): fall back to
-        // non-streaming single-item deserialization.
-        let value = format::deserialize_async::<T>(kind, &buffer).await;
+        // Other formats (including async custom formats without a streaming
+        // handler): fall back to non-streaming single-item deserialization,
+        // which also dispatches to any registered `AsyncCustomFormat`.
+        let value = self
+            .registry
+            .deserialize_value_async::<T>(Some(&kind), &[], &buffer)
+            .await;
         let result = value.map_err(|e| SingleIoError {
+            attempts: 1,
             stage: Stage::Parse,
             target,
             error: Box::new(e),
@@ -602,6 +1662,7 @@ impl AsyncIoEngine {
     {
         let mapped = iter.map(move |res| {
             res.map_err(|e| SingleIoError {
+                attempts: 1,
                 stage: Stage::Parse,
                 target: target.clone(),
                 error: Box::new(e),
@@ -624,4 +1685,73 @@ impl AsyncIoEngine {
         let futs = self.inputs.iter().map(|spec| self.read_one::<T>(spec));
         stream::iter(futs).buffer_unordered(concurrency).boxed()
     }
+
+    /// Create a stream that reads inputs with bounded concurrency, like
+    /// `read_stream_async`, but preserving input order.
+    ///
+    /// Uses `buffered` instead of `buffer_unordered`: up to `concurrency`
+    /// inputs are read concurrently, but results are yielded in the original
+    /// input order.
+    pub fn read_stream_async_ordered<T>(
+        &self,
+        concurrency: usize,
+    ) -> BoxStream<'_, Result<T, SingleIoError>>
+    where
+        T: DeserializeOwned + Send + 'static,
+    {
+        let futs = self.inputs.iter().map(|spec| self.read_one::<T>(spec));
+        stream::iter(futs).buffered(concurrency).boxed()
+    }
+
+    /// Re-run the full resolve→decode→encode→write pipeline every time a
+    /// watched input file changes, yielding one `RunReport` per run.
+    ///
+    /// The paths watched are `AsyncInputProvider::watch_path()` of every
+    /// input (i.e. file-backed inputs, including compressed ones); inline
+    /// (`=...`) and stdin inputs have no `watch_path` and are simply
+    /// re-read as-is on every run without ever triggering one themselves.
+    /// Changes are detected by polling file mtimes (there's no filesystem
+    /// notification crate in this dependency tree) every 50ms, and a burst
+    /// of changes within `watch_debounce` of the first one is coalesced
+    /// into a single rerun.
+    ///
+    /// The stream never ends on its own; drop it to stop watching. The
+    /// first item is produced immediately, before any file has changed.
+    pub fn run_watched<T>(&self) -> impl Stream<Item = Result<RunReport, AggregateError>> + '_
+    where
+        T: DeserializeOwned + Serialize + Send + Sync + 'static,
+    {
+        let watched_paths: Vec<PathBuf> = self
+            .inputs
+            .iter()
+            .filter_map(|spec| spec.provider.watch_path().map(Path::to_path_buf))
+            .collect();
+
+        stream::unfold(WatchState::new(watched_paths), move |mut state| async move {
+            let changed_paths = if state.first_run {
+                state.first_run = false;
+                state.establish_baseline().await;
+                Vec::new()
+            } else {
+                state.await_next_change(self.watch_debounce).await
+            };
+
+            let report = self.run_once::<T>(changed_paths).await;
+            Some((report, state))
+        })
+    }
+
+    /// One resolve→decode→encode→write pass for `run_watched`.
+    async fn run_once<T>(&self, changed_paths: Vec<PathBuf>) -> Result<RunReport, AggregateError>
+    where
+        T: DeserializeOwned + Serialize + Send + Sync + 'static,
+    {
+        let values = self.read_all::<T>().await?;
+        let items_read = values.len();
+        self.write_all(&values).await?;
+        Ok(RunReport {
+            changed_paths,
+            items_read,
+        })
+    }
 }
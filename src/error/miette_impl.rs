@@ -3,9 +3,27 @@
 use miette::{Diagnostic, Severity};
 use thiserror::Error;
 
-use super::{AggregateError, SingleIoError};
+use super::{AggregateError, SingleIoError, Stage};
 
-#[derive(Debug, Error, Diagnostic)]
+/// Stable, machine-readable diagnostic code for a `Stage`. These are part of
+/// the crate's public error surface (tools grep/match on them), so treat
+/// renames as breaking changes.
+fn stage_code(stage: Stage) -> &'static str {
+    match stage {
+        Stage::ResolveInput => "multiio::resolve_input",
+        Stage::ResolveOutput => "multiio::resolve_output",
+        Stage::Open => "multiio::open",
+        Stage::Parse => "multiio::parse",
+        Stage::Serialize => "multiio::serialize",
+        Stage::Write => "multiio::write",
+    }
+}
+
+/// `miette::Diagnostic` isn't derived here (only thiserror's `Error` is):
+/// the derive macro only supports a fixed code at the struct level, but
+/// `code` needs to vary per-instance with `Stage`, so `Diagnostic` is
+/// implemented by hand below.
+#[derive(Debug, Error)]
 #[error("{message}")]
 pub struct IoDiagnostic {
     pub message: String,
@@ -13,53 +31,122 @@ pub struct IoDiagnostic {
     #[source]
     pub source: Option<Box<dyn std::error::Error + Send + Sync>>,
 
-    #[help]
     pub help: Option<String>,
 
-    #[diagnostic(severity)]
     pub severity: Severity,
+
+    /// Stable code such as `multiio::parse`, derived from the originating
+    /// `Stage`. See `stage_code`.
+    pub code: String,
+
+    /// The offending input, named after the target it came from, so that
+    /// `span` below can be rendered as a labeled underline instead of a flat
+    /// "invalid type at line N" string. Only set when the error carries a
+    /// located source (see `SingleIoError::span`).
+    pub source_code: Option<miette::NamedSource<String>>,
+
+    pub span: Option<miette::SourceSpan>,
+
+    /// The rest of an `AggregateError`'s errors beyond the first, surfaced
+    /// through `Diagnostic::related` so a miette report shows every error
+    /// instead of silently dropping all but the first.
+    pub related: Vec<IoDiagnostic>,
+}
+
+impl Diagnostic for IoDiagnostic {
+    fn code<'a>(&'a self) -> Option<Box<dyn std::fmt::Display + 'a>> {
+        Some(Box::new(&self.code))
+    }
+
+    fn severity(&self) -> Option<Severity> {
+        Some(self.severity)
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn std::fmt::Display + 'a>> {
+        self.help
+            .as_ref()
+            .map(|h| Box::new(h) as Box<dyn std::fmt::Display + 'a>)
+    }
+
+    fn source_code(&self) -> Option<&dyn miette::SourceCode> {
+        self.source_code
+            .as_ref()
+            .map(|s| s as &dyn miette::SourceCode)
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = miette::LabeledSpan> + '_>> {
+        self.span.map(|s| {
+            Box::new(std::iter::once(miette::LabeledSpan::new_with_span(
+                Some("here".into()),
+                s,
+            ))) as Box<dyn Iterator<Item = miette::LabeledSpan>>
+        })
+    }
+
+    fn related<'a>(&'a self) -> Option<Box<dyn Iterator<Item = &'a dyn Diagnostic> + 'a>> {
+        if self.related.is_empty() {
+            None
+        } else {
+            Some(Box::new(self.related.iter().map(|d| d as &dyn Diagnostic)))
+        }
+    }
 }
 
 impl From<SingleIoError> for IoDiagnostic {
     fn from(e: SingleIoError) -> Self {
+        let span = e.span();
+        let source_bytes = e.source_bytes();
+        let stage = e.stage;
+        let message = format!("[{}] on '{}'", stage, e.target);
+        let source_code = source_bytes.map(|bytes| {
+            miette::NamedSource::new(e.target.clone(), String::from_utf8_lossy(&bytes).into_owned())
+        });
+
         IoDiagnostic {
-            message: format!("[{}] on '{}'", e.stage, e.target),
+            message,
             source: Some(e.error),
             help: Some("Check your I/O arguments and formats".into()),
             severity: Severity::Error,
+            code: stage_code(stage).to_string(),
+            source_code,
+            span: span.map(miette::SourceSpan::from),
+            related: Vec::new(),
         }
     }
 }
 
-impl From<AggregateError> for miette::Report {
+/// Diagnostic used for the empty-`AggregateError` edge case, which should
+/// never occur in practice (an `AggregateError` is only ever constructed
+/// from at least one `SingleIoError`) but is handled honestly rather than
+/// panicking.
+fn unknown_error_diagnostic() -> IoDiagnostic {
+    IoDiagnostic {
+        message: "Unknown I/O error".into(),
+        source: None,
+        help: None,
+        severity: Severity::Error,
+        code: "multiio::unknown".into(),
+        source_code: None,
+        span: None,
+        related: Vec::new(),
+    }
+}
+
+impl From<AggregateError> for IoDiagnostic {
     fn from(agg: AggregateError) -> Self {
-        let first = agg.errors.into_iter().next();
-        let diag = if let Some(e) = first {
-            IoDiagnostic::from(e)
-        } else {
-            IoDiagnostic {
-                message: "Unknown I/O error".into(),
-                source: None,
-                help: None,
-                severity: Severity::Error,
-            }
+        let mut errors = agg.errors.into_iter();
+        let Some(first) = errors.next() else {
+            return unknown_error_diagnostic();
         };
-        miette::Report::new(diag)
+
+        let mut primary = IoDiagnostic::from(first);
+        primary.related = errors.map(IoDiagnostic::from).collect();
+        primary
     }
 }
 
-impl From<AggregateError> for IoDiagnostic {
+impl From<AggregateError> for miette::Report {
     fn from(agg: AggregateError) -> Self {
-        let first = agg.errors.into_iter().next();
-        if let Some(e) = first {
-            IoDiagnostic::from(e)
-        } else {
-            IoDiagnostic {
-                message: "Unknown I/O error".into(),
-                source: None,
-                help: None,
-                severity: Severity::Error,
-            }
-        }
+        miette::Report::new(IoDiagnostic::from(agg))
     }
 }
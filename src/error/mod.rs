@@ -7,9 +7,34 @@
 //! - `AggregateError`: A collection of errors when using `Accumulate` policy
 
 use std::fmt;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
 
 use thiserror::Error;
 
+/// Monotonic counter mixed into retry backoff delays so that concurrent
+/// retries within the same process don't all wake up in lockstep.
+static RETRY_JITTER_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// Compute the delay before the given retry attempt (1-based) under capped
+/// exponential backoff, with jitter so that concurrent retries spread out
+/// instead of thundering back in lockstep. Jitter is derived from a
+/// monotonic counter mixed with the process id rather than a random-number
+/// generator, the same trick `write_atomic` uses for unique temp file names.
+///
+/// Shared by the sync and async engines so `ErrorPolicy::Retry` behaves
+/// identically regardless of which one is driving the retry loop.
+pub(crate) fn backoff_delay(base_delay: Duration, max_delay: Duration, attempt: u32) -> Duration {
+    let factor = 1u32.checked_shl(attempt).unwrap_or(u32::MAX);
+    let capped = base_delay.saturating_mul(factor).min(max_delay);
+
+    let half = capped / 2;
+    let jitter_seed =
+        RETRY_JITTER_COUNTER.fetch_add(1, Ordering::Relaxed) ^ (std::process::id() as u64);
+    let frac = (jitter_seed % 1000) as f64 / 1000.0;
+    half + (capped - half).mul_f64(frac)
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum ErrorPolicy {
     /// Stop at the first error encountered
@@ -17,6 +42,15 @@ pub enum ErrorPolicy {
     /// Collect all errors and return them together
     #[default]
     Accumulate,
+    /// Retry transient errors (see `SingleIoError::is_transient`) with capped
+    /// exponential backoff, up to `max_attempts` total attempts per input or
+    /// output. Errors classified as permanent are returned immediately
+    /// without retrying.
+    Retry {
+        max_attempts: u32,
+        base_delay: Duration,
+        max_delay: Duration,
+    },
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -26,7 +60,16 @@ pub enum Stage {
     /// Error while opening the I/O stream
     Open,
     Parse,
+    /// Error converting a value into bytes for the target format. Distinct
+    /// from [`Stage::Write`], which is for the I/O write call itself:
+    /// a `Serialize` error can never succeed by retrying, but a `Write`
+    /// error might be a transient I/O hiccup.
     Serialize,
+    /// Error from the actual write (or flush/shutdown) call against the
+    /// output stream, once the value has already been successfully
+    /// serialized into bytes. Unlike `Serialize`, these are classified by
+    /// the underlying `io::ErrorKind` and can be transient.
+    Write,
 }
 
 impl fmt::Display for Stage {
@@ -37,6 +80,59 @@ impl fmt::Display for Stage {
             Stage::Open => write!(f, "Open"),
             Stage::Parse => write!(f, "Parse"),
             Stage::Serialize => write!(f, "Serialize"),
+            Stage::Write => write!(f, "Write"),
+        }
+    }
+}
+
+/// Stable, small classification of a [`SingleIoError`] for callers that want
+/// to react programmatically (e.g. "retry transient network errors, but
+/// surface malformed input to the user") without string-matching or
+/// downcasting the boxed error themselves.
+///
+/// Unlike [`is_transient`](SingleIoError::is_transient), which only answers
+/// "should this be retried", `class` preserves enough detail to distinguish,
+/// say, a missing file from a permission failure, or a parse error from a
+/// serialize error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorClass {
+    /// The target didn't exist (`std::io::ErrorKind::NotFound`).
+    NotFound,
+    /// The target existed but access was denied (`PermissionDenied`).
+    PermissionDenied,
+    /// The operation exceeded its deadline (`TimedOut`).
+    TimedOut,
+    /// The operation was interrupted and could be retried (`Interrupted`).
+    Interrupted,
+    /// The bytes read were not valid for what was being decoded
+    /// (`InvalidData`), distinct from a higher-level [`Stage::Parse`]
+    /// failure, which always classifies as [`ErrorClass::Parse`] instead.
+    InvalidData,
+    /// The connection was reset by the peer (`ConnectionReset`).
+    ConnectionReset,
+    /// A [`Stage::Parse`] failure: the input didn't match the expected
+    /// format and retrying with the same bytes can never succeed.
+    Parse,
+    /// A [`Stage::Serialize`] failure: the value couldn't be encoded into
+    /// the target format.
+    Serialize,
+    /// Anything that doesn't fit the above: other `io::ErrorKind`s, and
+    /// non-I/O, non-format errors.
+    Other,
+}
+
+impl fmt::Display for ErrorClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorClass::NotFound => write!(f, "not found"),
+            ErrorClass::PermissionDenied => write!(f, "permission denied"),
+            ErrorClass::TimedOut => write!(f, "timed out"),
+            ErrorClass::Interrupted => write!(f, "interrupted"),
+            ErrorClass::InvalidData => write!(f, "invalid data"),
+            ErrorClass::ConnectionReset => write!(f, "connection reset"),
+            ErrorClass::Parse => write!(f, "parse error"),
+            ErrorClass::Serialize => write!(f, "serialize error"),
+            ErrorClass::Other => write!(f, "other"),
         }
     }
 }
@@ -49,6 +145,9 @@ pub struct SingleIoError {
     pub target: String,
     /// The underlying error
     pub error: Box<dyn std::error::Error + Send + Sync>,
+    /// Number of attempts made before this error was returned. Greater than
+    /// 1 indicates the operation was retried under `ErrorPolicy::Retry`.
+    pub attempts: u32,
 }
 
 impl fmt::Display for SingleIoError {
@@ -63,6 +162,90 @@ impl std::error::Error for SingleIoError {
     }
 }
 
+impl SingleIoError {
+    /// Whether this error is likely transient and worth retrying under
+    /// `ErrorPolicy::Retry`.
+    ///
+    /// Parse/serialize errors are always treated as permanent, since retrying
+    /// the same malformed bytes can never succeed. I/O errors - including
+    /// `Stage::Write` failures, since those are the write call itself rather
+    /// than the value-to-bytes conversion - are classified by
+    /// `std::io::ErrorKind`: interruptions, timeouts, and connection hiccups
+    /// are transient; missing files and permission failures are not.
+    /// Anything else (including non-I/O errors) is treated as permanent.
+    pub fn is_transient(&self) -> bool {
+        if matches!(self.stage, Stage::Parse | Stage::Serialize) {
+            return false;
+        }
+
+        let Some(io_err) = self.error.downcast_ref::<std::io::Error>() else {
+            return false;
+        };
+
+        matches!(
+            io_err.kind(),
+            std::io::ErrorKind::Interrupted
+                | std::io::ErrorKind::WouldBlock
+                | std::io::ErrorKind::TimedOut
+                | std::io::ErrorKind::UnexpectedEof
+                | std::io::ErrorKind::ConnectionReset
+                | std::io::ErrorKind::ConnectionAborted
+                | std::io::ErrorKind::BrokenPipe
+                | std::io::ErrorKind::NotConnected
+        )
+    }
+
+    /// Stable classification of this error for programmatic handling; see
+    /// [`ErrorClass`].
+    ///
+    /// `Stage::Parse`/`Stage::Serialize` take priority over the underlying
+    /// error's own shape, since those stages already pin down the right
+    /// bucket regardless of what produced them. Otherwise (including
+    /// `Stage::Write`, which is the write call itself, not the value-to-bytes
+    /// conversion), a `std::io::Error` is classified by its `ErrorKind`, and
+    /// anything else falls back to [`ErrorClass::Other`].
+    pub fn class(&self) -> ErrorClass {
+        match self.stage {
+            Stage::Parse => return ErrorClass::Parse,
+            Stage::Serialize => return ErrorClass::Serialize,
+            Stage::ResolveInput | Stage::ResolveOutput | Stage::Open | Stage::Write => {}
+        }
+
+        let Some(io_err) = self.error.downcast_ref::<std::io::Error>() else {
+            return ErrorClass::Other;
+        };
+
+        match io_err.kind() {
+            std::io::ErrorKind::NotFound => ErrorClass::NotFound,
+            std::io::ErrorKind::PermissionDenied => ErrorClass::PermissionDenied,
+            std::io::ErrorKind::TimedOut => ErrorClass::TimedOut,
+            std::io::ErrorKind::Interrupted => ErrorClass::Interrupted,
+            std::io::ErrorKind::InvalidData => ErrorClass::InvalidData,
+            std::io::ErrorKind::ConnectionReset => ErrorClass::ConnectionReset,
+            _ => ErrorClass::Other,
+        }
+    }
+
+    /// Byte offset and length of the offending region in the original input,
+    /// when the underlying error exposes one. Currently set for JSON, YAML,
+    /// and TOML parse errors (see `FormatError::SerdeSpanned`); `None` for
+    /// everything else, including all `Serialize`-stage errors.
+    pub fn span(&self) -> Option<(usize, usize)> {
+        self.error
+            .downcast_ref::<crate::format::FormatError>()
+            .and_then(|e| e.span())
+    }
+
+    /// The original input bytes that `span` refers to, when a span is
+    /// present.
+    pub fn source_bytes(&self) -> Option<std::sync::Arc<[u8]>> {
+        self.error
+            .downcast_ref::<crate::format::FormatError>()
+            .and_then(|e| e.source_bytes())
+            .cloned()
+    }
+}
+
 /// An aggregate of multiple I/O errors.
 ///
 /// This is returned when using `ErrorPolicy::Accumulate` and multiple errors occurred.
@@ -78,6 +261,19 @@ impl fmt::Display for AggregateError {
         for (i, e) in self.errors.iter().enumerate() {
             writeln!(f, "  #{}: {}", i + 1, e)?;
         }
+
+        let counts = self.count_by_class();
+        if !counts.is_empty() {
+            write!(f, "Summary: ")?;
+            for (i, (class, count)) in counts.iter().enumerate() {
+                if i > 0 {
+                    write!(f, ", ")?;
+                }
+                write!(f, "{count} {class}")?;
+            }
+            writeln!(f)?;
+        }
+
         Ok(())
     }
 }
@@ -99,6 +295,37 @@ impl AggregateError {
     pub fn len(&self) -> usize {
         self.errors.len()
     }
+
+    /// Count of contained errors per [`ErrorClass`], in declaration order,
+    /// omitting classes with zero matches. Lets a caller report, e.g.,
+    /// "2 timed out, 1 malformed input" without inspecting each boxed
+    /// error's source.
+    pub fn count_by_class(&self) -> Vec<(ErrorClass, usize)> {
+        const CLASSES: [ErrorClass; 9] = [
+            ErrorClass::NotFound,
+            ErrorClass::PermissionDenied,
+            ErrorClass::TimedOut,
+            ErrorClass::Interrupted,
+            ErrorClass::InvalidData,
+            ErrorClass::ConnectionReset,
+            ErrorClass::Parse,
+            ErrorClass::Serialize,
+            ErrorClass::Other,
+        ];
+
+        CLASSES
+            .into_iter()
+            .filter_map(|class| {
+                let count = self.errors.iter().filter(|e| e.class() == class).count();
+                (count > 0).then_some((class, count))
+            })
+            .collect()
+    }
+
+    /// Whether any contained error falls into `class`.
+    pub fn has_class(&self, class: ErrorClass) -> bool {
+        self.errors.iter().any(|e| e.class() == class)
+    }
 }
 
 impl From<SingleIoError> for AggregateError {
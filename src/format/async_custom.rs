@@ -0,0 +1,161 @@
+//! Async custom format support for user-defined formats backed by async codecs.
+//!
+//! This mirrors `CustomFormat`, but the deserialize/serialize hooks return
+//! futures instead of plain results, so a format can be backed by an
+//! async-native codec (e.g. compression or a network-assisted transform)
+//! without blocking the async runtime.
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use serde::{Serialize, de::DeserializeOwned};
+
+use super::FormatError;
+
+/// A boxed, `Send` future, matching `futures::future::BoxFuture` without
+/// pulling in the `futures` re-export for this leaf module.
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+/// Type alias for an async custom deserialize function.
+///
+/// Takes raw bytes and returns a future resolving to a `serde_json::Value`,
+/// which can then be converted to the target type.
+pub type AsyncDeserializeFn =
+    Arc<dyn Fn(&[u8]) -> BoxFuture<'static, Result<serde_json::Value, FormatError>> + Send + Sync>;
+
+/// Type alias for an async custom serialize function.
+///
+/// Takes a `serde_json::Value` and returns a future resolving to serialized
+/// bytes.
+pub type AsyncSerializeFn = Arc<
+    dyn Fn(&serde_json::Value) -> BoxFuture<'static, Result<Vec<u8>, FormatError>> + Send + Sync,
+>;
+
+/// An async custom format handler that can be registered with an
+/// `AsyncFormatRegistry`.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use multiio::format::{AsyncCustomFormat, FormatError};
+///
+/// let gzip_json = AsyncCustomFormat::new("gzip-json", &["gz"])
+///     .with_deserialize(|bytes| {
+///         let bytes = bytes.to_vec();
+///         Box::pin(async move {
+///             let decompressed = decompress(&bytes).await?;
+///             serde_json::from_slice(&decompressed)
+///                 .map_err(|e| FormatError::Serde(Box::new(e)))
+///         })
+///     })
+///     .with_serialize(|value| {
+///         let value = value.clone();
+///         Box::pin(async move {
+///             let bytes = serde_json::to_vec(&value)
+///                 .map_err(|e| FormatError::Serde(Box::new(e)))?;
+///             compress(&bytes).await
+///         })
+///     });
+///
+/// registry.register_custom(gzip_json);
+/// ```
+#[derive(Clone)]
+pub struct AsyncCustomFormat {
+    /// Unique name for this format
+    pub name: &'static str,
+    /// File extensions associated with this format
+    pub extensions: &'static [&'static str],
+    /// Async deserialize function
+    pub deserialize_fn: Option<AsyncDeserializeFn>,
+    /// Async serialize function
+    pub serialize_fn: Option<AsyncSerializeFn>,
+}
+
+impl std::fmt::Debug for AsyncCustomFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AsyncCustomFormat")
+            .field("name", &self.name)
+            .field("extensions", &self.extensions)
+            .field("has_deserialize", &self.deserialize_fn.is_some())
+            .field("has_serialize", &self.serialize_fn.is_some())
+            .finish()
+    }
+}
+
+impl AsyncCustomFormat {
+    /// Create a new async custom format with the given name and extensions.
+    pub fn new(name: &'static str, extensions: &'static [&'static str]) -> Self {
+        Self {
+            name,
+            extensions,
+            deserialize_fn: None,
+            serialize_fn: None,
+        }
+    }
+
+    /// Set the deserialize function.
+    pub fn with_deserialize<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&[u8]) -> BoxFuture<'static, Result<serde_json::Value, FormatError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.deserialize_fn = Some(Arc::new(f));
+        self
+    }
+
+    /// Set the serialize function.
+    pub fn with_serialize<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&serde_json::Value) -> BoxFuture<'static, Result<Vec<u8>, FormatError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.serialize_fn = Some(Arc::new(f));
+        self
+    }
+
+    /// Deserialize bytes to a typed value.
+    pub async fn deserialize<T: DeserializeOwned>(&self, bytes: &[u8]) -> Result<T, FormatError> {
+        let deserialize_fn = self.deserialize_fn.as_ref().ok_or_else(|| {
+            FormatError::Other(Box::new(std::io::Error::new(
+                std::io::ErrorKind::Unsupported,
+                format!(
+                    "Async custom format '{}' does not support deserialization",
+                    self.name
+                ),
+            )))
+        })?;
+
+        let value = deserialize_fn(bytes).await?;
+        serde_json::from_value(value).map_err(|e| FormatError::Serde(Box::new(e)))
+    }
+
+    /// Serialize a typed value to bytes.
+    pub async fn serialize<T: Serialize>(&self, value: &T) -> Result<Vec<u8>, FormatError> {
+        let serialize_fn = self.serialize_fn.as_ref().ok_or_else(|| {
+            FormatError::Other(Box::new(std::io::Error::new(
+                std::io::ErrorKind::Unsupported,
+                format!(
+                    "Async custom format '{}' does not support serialization",
+                    self.name
+                ),
+            )))
+        })?;
+
+        let json_value =
+            serde_json::to_value(value).map_err(|e| FormatError::Serde(Box::new(e)))?;
+        serialize_fn(&json_value).await
+    }
+
+    /// Check if this format matches the given extension.
+    pub fn matches_extension(&self, ext: &str) -> bool {
+        let ext_lower = ext.to_ascii_lowercase();
+        self.extensions
+            .iter()
+            .any(|e| e.eq_ignore_ascii_case(&ext_lower))
+    }
+}
@@ -1,7 +1,13 @@
+use futures::stream::BoxStream;
 use serde::{Serialize, de::DeserializeOwned};
-use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use tokio::io::{AsyncBufRead, AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
 
-use super::{FormatError, FormatKind};
+use super::{
+    AsyncCustomFormat, AsyncStreamFormat, CsvStreamFormat, FormatError, FormatKind,
+    JsonStreamFormat, NdjsonStreamFormat, OutputOptions,
+};
+#[cfg(feature = "plaintext")]
+use super::PlaintextStreamFormat;
 
 /// Async deserialize from bytes using the specified format.
 pub async fn deserialize_async<T: DeserializeOwned + Send>(
@@ -19,6 +25,15 @@ pub async fn serialize_async<T: Serialize + Sync>(
     super::serialize(kind, value)
 }
 
+/// Async serialize to bytes using the specified format, honoring `options`.
+pub async fn serialize_async_with_options<T: Serialize + Sync>(
+    kind: FormatKind,
+    value: &T,
+    options: &OutputOptions,
+) -> Result<Vec<u8>, FormatError> {
+    super::serialize_with_options(kind, value, options)
+}
+
 /// Async deserialize from an async reader.
 pub async fn deserialize_from_async_reader<T: DeserializeOwned + Send>(
     kind: FormatKind,
@@ -44,6 +59,7 @@ pub async fn serialize_to_async_writer<T: Serialize + Sync>(
 #[derive(Debug, Clone)]
 pub struct AsyncFormatRegistry {
     formats: Vec<FormatKind>,
+    custom_formats: Vec<AsyncCustomFormat>,
 }
 
 impl Default for AsyncFormatRegistry {
@@ -56,9 +72,33 @@ impl AsyncFormatRegistry {
     pub fn new() -> Self {
         Self {
             formats: Vec::new(),
+            custom_formats: Vec::new(),
         }
     }
 
+    /// Register an async custom format, making its `FormatKind::Custom(name)`
+    /// resolvable and usable by `deserialize_value_async`/`serialize_value_async`.
+    pub fn register_custom(&mut self, format: AsyncCustomFormat) {
+        self.register(FormatKind::Custom(format.name));
+        self.custom_formats.push(format);
+    }
+
+    /// Builder-style variant of `register_custom`.
+    pub fn with_custom_format(mut self, format: AsyncCustomFormat) -> Self {
+        self.register_custom(format);
+        self
+    }
+
+    /// Look up a registered async custom format by name.
+    pub fn get_custom(&self, name: &str) -> Option<&AsyncCustomFormat> {
+        self.custom_formats.iter().find(|f| f.name == name)
+    }
+
+    /// All registered async custom formats.
+    pub fn custom_formats(&self) -> &[AsyncCustomFormat] {
+        &self.custom_formats
+    }
+
     /// Register a format.
     pub fn register(&mut self, kind: FormatKind) {
         if !self.formats.contains(&kind) {
@@ -80,6 +120,8 @@ impl AsyncFormatRegistry {
     /// Get format kind for a file extension.
     pub fn kind_for_extension(&self, ext: &str) -> Option<FormatKind> {
         let ext_lower = ext.to_ascii_lowercase();
+
+        // Check built-in formats first
         for kind in &self.formats {
             if kind
                 .extensions()
@@ -89,6 +131,14 @@ impl AsyncFormatRegistry {
                 return Some(*kind);
             }
         }
+
+        // Check async custom formats
+        for custom in &self.custom_formats {
+            if custom.matches_extension(&ext_lower) {
+                return Some(FormatKind::Custom(custom.name));
+            }
+        }
+
         None
     }
 
@@ -116,6 +166,99 @@ impl AsyncFormatRegistry {
     pub fn formats(&self) -> &[FormatKind] {
         &self.formats
     }
+
+    /// Deserialize using this registry.
+    ///
+    /// Automatically handles both built-in formats and async custom formats
+    /// registered via `register_custom`.
+    pub async fn deserialize_value_async<T: DeserializeOwned + Send>(
+        &self,
+        explicit: Option<&FormatKind>,
+        candidates: &[FormatKind],
+        bytes: &[u8],
+    ) -> Result<T, FormatError> {
+        let kind = self.resolve(explicit, candidates)?;
+
+        if let FormatKind::Custom(name) = &kind {
+            let custom = self
+                .get_custom(name)
+                .ok_or_else(|| FormatError::UnknownFormat(kind))?;
+            return custom.deserialize(bytes).await;
+        }
+
+        deserialize_async(kind, bytes).await
+    }
+
+    /// Serialize using this registry.
+    ///
+    /// Automatically handles both built-in formats and async custom formats
+    /// registered via `register_custom`.
+    pub async fn serialize_value_async<T: Serialize + Sync>(
+        &self,
+        explicit: Option<&FormatKind>,
+        candidates: &[FormatKind],
+        value: &T,
+    ) -> Result<Vec<u8>, FormatError> {
+        let kind = self.resolve(explicit, candidates)?;
+
+        if let FormatKind::Custom(name) = &kind {
+            let custom = self
+                .get_custom(name)
+                .ok_or_else(|| FormatError::UnknownFormat(kind))?;
+            return custom.serialize(value).await;
+        }
+
+        serialize_async(kind, value).await
+    }
+
+    /// Serialize using this registry, honoring `OutputOptions` where the
+    /// resolved format supports them.
+    ///
+    /// Async custom formats ignore `options`, since their serialize function
+    /// has no hook for formatting hints.
+    pub async fn serialize_value_async_with_options<T: Serialize + Sync>(
+        &self,
+        explicit: Option<&FormatKind>,
+        candidates: &[FormatKind],
+        value: &T,
+        options: &OutputOptions,
+    ) -> Result<Vec<u8>, FormatError> {
+        let kind = self.resolve(explicit, candidates)?;
+
+        if let FormatKind::Custom(name) = &kind {
+            let custom = self
+                .get_custom(name)
+                .ok_or_else(|| FormatError::UnknownFormat(kind))?;
+            return custom.serialize(value).await;
+        }
+
+        serialize_async_with_options(kind, value, options).await
+    }
+
+    /// Decode `kind` incrementally from `reader` if a true streaming decoder
+    /// is available for it, returning `None` when the format has no
+    /// `AsyncStreamFormat` implementation so the caller can fall back to the
+    /// buffer-and-replay path.
+    pub fn deserialize_stream_async<'a, T>(
+        &self,
+        kind: FormatKind,
+        reader: Box<dyn AsyncBufRead + Unpin + Send + 'a>,
+    ) -> Option<BoxStream<'a, Result<T, FormatError>>>
+    where
+        T: DeserializeOwned + Send + 'a,
+    {
+        match kind {
+            #[cfg(feature = "json")]
+            FormatKind::Json => Some(JsonStreamFormat.deserialize_stream_async(reader)),
+            #[cfg(feature = "ndjson")]
+            FormatKind::Ndjson => Some(NdjsonStreamFormat.deserialize_stream_async(reader)),
+            #[cfg(feature = "csv")]
+            FormatKind::Csv => Some(CsvStreamFormat.deserialize_stream_async(reader)),
+            #[cfg(feature = "plaintext")]
+            FormatKind::Plaintext => Some(PlaintextStreamFormat.deserialize_stream_async(reader)),
+            _ => None,
+        }
+    }
 }
 
 /// Create a default async registry with all enabled formats.
@@ -0,0 +1,506 @@
+//! True incremental async streaming for formats that support it.
+//!
+//! This complements the buffer-and-replay path used elsewhere in the async
+//! engine (see the "Async engine streaming" notes in the crate root docs): a
+//! format that implements [`AsyncStreamFormat`] can decode records directly
+//! off an `AsyncBufRead` as they arrive, instead of first reading the whole
+//! input into a `Vec<u8>`.
+//!
+//! Implementors must keep at most one record's worth of bytes (plus the
+//! reader's own internal buffer) live at a time, and the returned stream must
+//! be `Send` so it can be polled concurrently across many inputs, mirroring
+//! `AsyncIoEngine::read_records_async`'s `buffer_unordered(concurrency)`.
+//! Formats without an incremental decoder (e.g. YAML, whose `serde_yaml`
+//! streaming isn't `Send`) simply don't implement this trait and fall back to
+//! the existing buffered path.
+
+use futures::stream::{self, BoxStream, Stream, StreamExt};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+
+use super::{FormatError, FormatKind};
+
+/// A format that can decode one record at a time directly from an async
+/// reader.
+pub trait AsyncStreamFormat {
+    /// Decode a stream of records from `reader`, yielding one item as soon as
+    /// its bytes are fully read rather than after the whole input completes.
+    fn deserialize_stream_async<'a, T>(
+        &self,
+        reader: Box<dyn AsyncBufRead + Unpin + Send + 'a>,
+    ) -> BoxStream<'a, Result<T, FormatError>>
+    where
+        T: DeserializeOwned + Send + 'a;
+}
+
+/// Scans a byte-oriented JSON source (a top-level array, concatenated
+/// top-level values, or NDJSON) and yields one decoded `T` per value.
+///
+/// The scanner tracks brace/bracket depth and string/escape state so it knows
+/// exactly where one JSON value ends and the next begins, without ever
+/// holding more than the current value's bytes in memory.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct JsonStreamFormat;
+
+impl AsyncStreamFormat for JsonStreamFormat {
+    fn deserialize_stream_async<'a, T>(
+        &self,
+        reader: Box<dyn AsyncBufRead + Unpin + Send + 'a>,
+    ) -> BoxStream<'a, Result<T, FormatError>>
+    where
+        T: DeserializeOwned + Send + 'a,
+    {
+        stream::unfold(Some(reader), |state| async move {
+            let mut reader = state?;
+            match next_json_value(&mut reader).await {
+                Ok(Some(bytes)) => {
+                    let value = serde_json::from_slice::<T>(&bytes)
+                        .map_err(|e| FormatError::Serde(Box::new(e)));
+                    Some((value, Some(reader)))
+                }
+                Ok(None) => None,
+                Err(e) => Some((Err(FormatError::Io(e)), None)),
+            }
+        })
+        .boxed()
+    }
+}
+
+/// Reads exactly one JSON value's worth of bytes from `reader`, skipping any
+/// leading whitespace, commas, or array brackets that separate values.
+///
+/// Returns `Ok(None)` once the reader is exhausted without encountering the
+/// start of another value.
+async fn next_json_value<R>(reader: &mut R) -> std::io::Result<Option<Vec<u8>>>
+where
+    R: AsyncBufRead + Unpin,
+{
+    // Skip insignificant bytes between values: whitespace, the top-level
+    // array's `[`/`]`/`,` separators.
+    loop {
+        let mut one = [0u8; 1];
+        match read_exact_or_eof(reader, &mut one).await? {
+            0 => return Ok(None),
+            _ => {}
+        }
+        let b = one[0];
+        if b.is_ascii_whitespace() || b == b',' || b == b'[' || b == b']' {
+            continue;
+        }
+
+        // `b` is the first byte of a value; collect the rest.
+        let mut out = vec![b];
+        let mut depth: i64 = match b {
+            b'{' | b'[' => 1,
+            _ => 0,
+        };
+        let mut in_string = b == b'"';
+        let mut escaped = false;
+
+        if depth == 0 && !in_string {
+            // A bare scalar (number/bool/null) ends at the next structural
+            // byte; read until whitespace/comma/bracket.
+            loop {
+                let mut next = [0u8; 1];
+                let peeked = peek_one(reader).await?;
+                match peeked {
+                    None => break,
+                    Some(p) if p.is_ascii_whitespace() || p == b',' || p == b']' || p == b'}' => {
+                        break;
+                    }
+                    Some(_) => {
+                        if read_exact_or_eof(reader, &mut next).await? == 0 {
+                            break;
+                        }
+                        out.push(next[0]);
+                    }
+                }
+            }
+            return Ok(Some(out));
+        }
+
+        while depth > 0 || in_string {
+            let mut next = [0u8; 1];
+            if read_exact_or_eof(reader, &mut next).await? == 0 {
+                break;
+            }
+            let c = next[0];
+            out.push(c);
+
+            if in_string {
+                if escaped {
+                    escaped = false;
+                } else if c == b'\\' {
+                    escaped = true;
+                } else if c == b'"' {
+                    in_string = false;
+                }
+                continue;
+            }
+
+            match c {
+                b'"' => in_string = true,
+                b'{' | b'[' => depth += 1,
+                b'}' | b']' => depth -= 1,
+                _ => {}
+            }
+        }
+
+        return Ok(Some(out));
+    }
+}
+
+async fn peek_one<R>(reader: &mut R) -> std::io::Result<Option<u8>>
+where
+    R: AsyncBufRead + Unpin,
+{
+    let buf = reader.fill_buf().await?;
+    Ok(buf.first().copied())
+}
+
+async fn read_exact_or_eof<R>(reader: &mut R, buf: &mut [u8; 1]) -> std::io::Result<usize>
+where
+    R: AsyncBufRead + Unpin,
+{
+    reader.read(buf).await
+}
+
+/// Reads CSV rows one line at a time and yields one decoded `T` per row,
+/// using the first line as the header.
+///
+/// Like the rest of this module, this never holds more than one row's worth
+/// of bytes in memory. Unlike a full CSV parser, it treats each line as a
+/// complete record, so it can't handle a quoted field containing an embedded
+/// newline; well-formed CSV without such fields (the common case) streams
+/// correctly.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CsvStreamFormat;
+
+impl AsyncStreamFormat for CsvStreamFormat {
+    fn deserialize_stream_async<'a, T>(
+        &self,
+        reader: Box<dyn AsyncBufRead + Unpin + Send + 'a>,
+    ) -> BoxStream<'a, Result<T, FormatError>>
+    where
+        T: DeserializeOwned + Send + 'a,
+    {
+        stream::unfold(
+            (reader, None::<Vec<String>>),
+            |(mut reader, mut headers)| async move {
+                loop {
+                    let mut line = String::new();
+                    match reader.read_line(&mut line).await {
+                        Ok(0) => return None,
+                        Ok(_) => {}
+                        Err(e) => return Some((Err(FormatError::Io(e)), (reader, headers))),
+                    }
+
+                    let trimmed = line.trim_end_matches(['\n', '\r']);
+                    if trimmed.is_empty() {
+                        continue;
+                    }
+
+                    let record = match parse_csv_line(trimmed) {
+                        Ok(record) => record,
+                        Err(e) => return Some((Err(e), (reader, headers))),
+                    };
+
+                    if headers.is_none() {
+                        headers = Some(record);
+                        continue;
+                    }
+
+                    let header_row = headers.clone().expect("headers set above");
+                    let mut obj = serde_json::Map::new();
+                    for (field, header) in record.into_iter().zip(header_row.iter()) {
+                        obj.insert(header.clone(), serde_json::Value::String(field));
+                    }
+
+                    let value = serde_json::from_value::<T>(serde_json::Value::Object(obj))
+                        .map_err(|e| FormatError::Serde(Box::new(e)));
+                    return Some((value, (reader, headers)));
+                }
+            },
+        )
+        .boxed()
+    }
+}
+
+/// Reads NDJSON one line at a time, decoding each non-blank line as its own
+/// JSON value as soon as it's read.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NdjsonStreamFormat;
+
+impl AsyncStreamFormat for NdjsonStreamFormat {
+    fn deserialize_stream_async<'a, T>(
+        &self,
+        reader: Box<dyn AsyncBufRead + Unpin + Send + 'a>,
+    ) -> BoxStream<'a, Result<T, FormatError>>
+    where
+        T: DeserializeOwned + Send + 'a,
+    {
+        stream::unfold(reader, |mut reader| async move {
+            loop {
+                let mut line = String::new();
+                match reader.read_line(&mut line).await {
+                    Ok(0) => return None,
+                    Ok(_) => {}
+                    Err(e) => return Some((Err(FormatError::Io(e)), reader)),
+                }
+
+                let trimmed = line.trim_end_matches(['\n', '\r']);
+                if trimmed.trim().is_empty() {
+                    continue;
+                }
+
+                let value = serde_json::from_str::<T>(trimmed)
+                    .map_err(|e| FormatError::Serde(Box::new(e)));
+                return Some((value, reader));
+            }
+        })
+        .boxed()
+    }
+}
+
+/// Reads plaintext one line at a time, decoding each line the same way the
+/// sync `deserialize_plaintext_stream` does (structured-text sniffing with a
+/// string-deserializer fallback), as soon as it's read.
+#[cfg(feature = "plaintext")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PlaintextStreamFormat;
+
+#[cfg(feature = "plaintext")]
+impl AsyncStreamFormat for PlaintextStreamFormat {
+    fn deserialize_stream_async<'a, T>(
+        &self,
+        reader: Box<dyn AsyncBufRead + Unpin + Send + 'a>,
+    ) -> BoxStream<'a, Result<T, FormatError>>
+    where
+        T: DeserializeOwned + Send + 'a,
+    {
+        stream::unfold(reader, |mut reader| async move {
+            let mut line = String::new();
+            match reader.read_line(&mut line).await {
+                Ok(0) => None,
+                Ok(_) => {
+                    let trimmed = line.trim_end_matches(['\n', '\r']).to_string();
+                    let value = super::plaintext::decode_from_string::<T>(trimmed);
+                    Some((value, reader))
+                }
+                Err(e) => Some((Err(FormatError::Io(e)), reader)),
+            }
+        })
+        .boxed()
+    }
+}
+
+fn parse_csv_line(line: &str) -> Result<Vec<String>, FormatError> {
+    let mut rdr = csv::ReaderBuilder::new()
+        .has_headers(false)
+        .from_reader(line.as_bytes());
+    match rdr.records().next() {
+        Some(Ok(record)) => Ok(record.iter().map(str::to_string).collect()),
+        Some(Err(e)) => Err(FormatError::Serde(Box::new(e))),
+        None => Ok(Vec::new()),
+    }
+}
+
+/// Decode `kind` one record at a time directly from `reader`.
+///
+/// For formats with a true incremental decoder (JSON, CSV, NDJSON,
+/// plaintext), records are yielded as soon as their bytes are read, bounding
+/// peak memory to roughly one record. YAML has no incremental decoder that's
+/// `Send` (see the module docs), so it is read fully into memory up front
+/// and then replayed as a stream of already-decoded documents. Whole-document
+/// formats (TOML, INI, Markdown, and anything else without a record-oriented
+/// representation) yield a single `FormatError::StreamingUnsupported`, so
+/// callers can fall back to the buffered path.
+pub fn deserialize_stream_from_async_reader<'a, T>(
+    kind: FormatKind,
+    reader: Box<dyn AsyncBufRead + Unpin + Send + 'a>,
+) -> BoxStream<'a, Result<T, FormatError>>
+where
+    T: DeserializeOwned + Send + 'a,
+{
+    match kind {
+        #[cfg(feature = "json")]
+        FormatKind::Json => JsonStreamFormat.deserialize_stream_async(reader),
+        #[cfg(feature = "csv")]
+        FormatKind::Csv => CsvStreamFormat.deserialize_stream_async(reader),
+        #[cfg(feature = "yaml")]
+        FormatKind::Yaml => deserialize_yaml_stream_buffered(reader),
+        #[cfg(feature = "ndjson")]
+        FormatKind::Ndjson => NdjsonStreamFormat.deserialize_stream_async(reader),
+        #[cfg(feature = "plaintext")]
+        FormatKind::Plaintext => PlaintextStreamFormat.deserialize_stream_async(reader),
+        _ => stream::iter(std::iter::once(Err(FormatError::StreamingUnsupported(kind)))).boxed(),
+    }
+}
+
+/// Reads `reader` to completion, then replays its YAML documents as a
+/// stream. `serde_yaml::Deserializer` isn't `Send`, so it can't be held
+/// across `.await` points the way `JsonStreamFormat` holds its scanner;
+/// eagerly collecting into a `Vec` sidesteps that without ever exposing a
+/// non-`Send` value to the caller.
+#[cfg(feature = "yaml")]
+fn deserialize_yaml_stream_buffered<'a, T>(
+    mut reader: Box<dyn AsyncBufRead + Unpin + Send + 'a>,
+) -> BoxStream<'a, Result<T, FormatError>>
+where
+    T: DeserializeOwned + Send + 'a,
+{
+    stream::once(async move {
+        let mut buffer = Vec::new();
+        if let Err(e) = reader.read_to_end(&mut buffer).await {
+            return stream::iter(vec![Err(FormatError::Io(e))]).boxed();
+        }
+        let items: Vec<Result<T, FormatError>> =
+            super::deserialize_yaml_stream::<T, _>(std::io::Cursor::new(buffer)).collect();
+        stream::iter(items).boxed()
+    })
+    .flatten()
+    .boxed()
+}
+
+/// Encode a stream of values as `kind`, writing each record to `writer` as
+/// soon as it's serialized rather than buffering the whole output.
+///
+/// JSON is written as a top-level array (`[`, comma-separated elements,
+/// `]`); CSV as a header row followed by one row per record; YAML as
+/// `---`-separated documents. Whole-document formats return
+/// `FormatError::StreamingUnsupported` without consuming `stream`, so callers
+/// can fall back to the buffered path.
+pub async fn serialize_stream_to_async_writer<T, S>(
+    kind: FormatKind,
+    stream: S,
+    writer: &mut (dyn AsyncWrite + Unpin + Send),
+) -> Result<(), FormatError>
+where
+    T: Serialize,
+    S: Stream<Item = T> + Unpin,
+{
+    match kind {
+        #[cfg(feature = "json")]
+        FormatKind::Json => serialize_stream_json(stream, writer).await,
+        #[cfg(feature = "csv")]
+        FormatKind::Csv => serialize_stream_csv(stream, writer).await,
+        #[cfg(feature = "yaml")]
+        FormatKind::Yaml => serialize_stream_yaml(stream, writer).await,
+        #[cfg(feature = "ndjson")]
+        FormatKind::Ndjson => serialize_stream_ndjson(stream, writer).await,
+        _ => Err(FormatError::StreamingUnsupported(kind)),
+    }
+}
+
+#[cfg(feature = "json")]
+async fn serialize_stream_json<T, S>(
+    mut stream: S,
+    writer: &mut (dyn AsyncWrite + Unpin + Send),
+) -> Result<(), FormatError>
+where
+    T: Serialize,
+    S: Stream<Item = T> + Unpin,
+{
+    writer.write_all(b"[").await?;
+
+    let mut first = true;
+    while let Some(item) = stream.next().await {
+        if !first {
+            writer.write_all(b",").await?;
+        }
+        first = false;
+
+        let bytes = serde_json::to_vec(&item).map_err(|e| FormatError::Serde(Box::new(e)))?;
+        writer.write_all(&bytes).await?;
+    }
+
+    writer.write_all(b"]").await?;
+    Ok(())
+}
+
+#[cfg(feature = "csv")]
+async fn serialize_stream_csv<T, S>(
+    mut stream: S,
+    writer: &mut (dyn AsyncWrite + Unpin + Send),
+) -> Result<(), FormatError>
+where
+    T: Serialize,
+    S: Stream<Item = T> + Unpin,
+{
+    let mut wrote_header = false;
+
+    while let Some(item) = stream.next().await {
+        let json_value = serde_json::to_value(&item).map_err(|e| FormatError::Serde(Box::new(e)))?;
+        let serde_json::Value::Object(obj) = json_value else {
+            return Err(FormatError::Other(Box::new(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                "CSV streaming requires each record to serialize to an object",
+            ))));
+        };
+
+        let mut row = csv::WriterBuilder::new()
+            .has_headers(false)
+            .from_writer(Vec::new());
+
+        if !wrote_header {
+            let headers: Vec<&str> = obj.keys().map(|s| s.as_str()).collect();
+            row.write_record(&headers)
+                .map_err(|e| FormatError::Serde(Box::new(e)))?;
+            wrote_header = true;
+        }
+
+        let record: Vec<String> = obj
+            .values()
+            .map(|v| match v {
+                serde_json::Value::String(s) => s.clone(),
+                other => other.to_string(),
+            })
+            .collect();
+        row.write_record(&record)
+            .map_err(|e| FormatError::Serde(Box::new(e)))?;
+
+        let bytes = row
+            .into_inner()
+            .map_err(|e| FormatError::Other(Box::new(e)))?;
+        writer.write_all(&bytes).await?;
+    }
+
+    Ok(())
+}
+
+#[cfg(feature = "ndjson")]
+async fn serialize_stream_ndjson<T, S>(
+    mut stream: S,
+    writer: &mut (dyn AsyncWrite + Unpin + Send),
+) -> Result<(), FormatError>
+where
+    T: Serialize,
+    S: Stream<Item = T> + Unpin,
+{
+    while let Some(item) = stream.next().await {
+        let bytes = serde_json::to_vec(&item).map_err(|e| FormatError::Serde(Box::new(e)))?;
+        writer.write_all(&bytes).await?;
+        writer.write_all(b"\n").await?;
+    }
+
+    Ok(())
+}
+
+#[cfg(feature = "yaml")]
+async fn serialize_stream_yaml<T, S>(
+    mut stream: S,
+    writer: &mut (dyn AsyncWrite + Unpin + Send),
+) -> Result<(), FormatError>
+where
+    T: Serialize,
+    S: Stream<Item = T> + Unpin,
+{
+    while let Some(item) = stream.next().await {
+        let doc = serde_yaml::to_string(&item).map_err(|e| FormatError::Serde(Box::new(e)))?;
+        writer.write_all(b"---\n").await?;
+        writer.write_all(doc.as_bytes()).await?;
+    }
+
+    Ok(())
+}
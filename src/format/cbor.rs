@@ -0,0 +1,57 @@
+//! CBOR (Concise Binary Object Representation) format implementation.
+
+use serde::{Serialize, de::DeserializeOwned};
+use std::io::Read;
+
+use super::FormatError;
+
+pub(crate) fn deserialize<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, FormatError> {
+    ciborium::de::from_reader(bytes).map_err(|e| FormatError::Serde(Box::new(e)))
+}
+
+pub(crate) fn serialize<T: Serialize>(value: &T) -> Result<Vec<u8>, FormatError> {
+    let mut buf = Vec::new();
+    ciborium::ser::into_writer(value, &mut buf).map_err(|e| FormatError::Serde(Box::new(e)))?;
+    Ok(buf)
+}
+
+/// CBOR is a fixed binary encoding with no notion of compact vs. pretty
+/// whitespace, indent, or key order, so `options` has nothing to act on here.
+pub(crate) fn serialize_with_options<T: Serialize>(
+    value: &T,
+    _options: &super::OutputOptions,
+) -> Result<Vec<u8>, FormatError> {
+    serialize(value)
+}
+
+/// Stream concatenated top-level CBOR values off a reader, one per record,
+/// the same way [`super::json::stream_deserialize`] treats whitespace-
+/// separated top-level JSON documents: each call to `ciborium::de::from_reader`
+/// consumes exactly one value, leaving the reader positioned at the start of
+/// the next one, until it runs out of bytes.
+pub(crate) fn stream_deserialize<T, R>(reader: R) -> impl Iterator<Item = Result<T, FormatError>>
+where
+    T: DeserializeOwned,
+    R: Read,
+{
+    let mut reader = reader;
+    let mut finished = false;
+
+    std::iter::from_fn(move || {
+        if finished {
+            return None;
+        }
+
+        match ciborium::de::from_reader::<T, _>(&mut reader) {
+            Ok(value) => Some(Ok(value)),
+            Err(ciborium::de::Error::Io(e)) if e.kind() == std::io::ErrorKind::UnexpectedEof => {
+                finished = true;
+                None
+            }
+            Err(e) => {
+                finished = true;
+                Some(Err(FormatError::Serde(Box::new(e))))
+            }
+        }
+    })
+}
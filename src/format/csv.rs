@@ -1,8 +1,262 @@
 //! CSV format implementation.
 
+use std::io::{Read, Write};
+
 use serde::{Serialize, de::DeserializeOwned};
 
-use super::FormatError;
+use super::{CustomFormat, FormatError, FormatKind, PayloadErrorKind, truncate_context};
+
+/// Build a [`FormatError::MalformedPayload`] for a CSV failure, attaching a
+/// bounded preview of `context_source` rather than the full payload.
+fn malformed(classification: PayloadErrorKind, message: impl Into<String>, context_source: &[u8]) -> FormatError {
+    FormatError::MalformedPayload {
+        kind: FormatKind::Csv,
+        classification,
+        message: message.into(),
+        context: truncate_context(context_source),
+    }
+}
+
+/// Converts the intermediate `serde_json::Value::Array` built from parsed CSV
+/// records into `T`, routing the conversion through `serde_path_to_error` so
+/// a type mismatch in a specific row (e.g. `[3].value`) is reported
+/// precisely; a value whose shape doesn't match `T` at all (no per-row path
+/// to report) falls back to the existing [`FormatError::MalformedPayload`].
+fn records_to_value<T: DeserializeOwned>(
+    json_value: serde_json::Value,
+    bytes: &[u8],
+) -> Result<T, FormatError> {
+    serde_path_to_error::deserialize(json_value).map_err(|e| {
+        if e.path().to_string() == "." {
+            malformed(PayloadErrorKind::DataShape, e.into_inner().to_string(), bytes)
+        } else {
+            super::path_tracking_error(e)
+        }
+    })
+}
+
+/// Dialect configuration for reading CSV whose shape doesn't match the
+/// builtin `FormatKind::Csv`'s fixed comma-delimited, headered default:
+/// a different delimiter or quote character, no header row at all, or a
+/// headerless file whose columns should get caller-supplied names instead
+/// of synthesized `col0`, `col1`, … ones.
+///
+/// `FormatKind` is a fixed enum, so there's no dedicated kind for "CSV with
+/// this particular dialect"; build a registrable format with
+/// [`Self::into_custom_format`] instead, the same way any other per-instance
+/// configuration is exposed through [`CustomFormat`].
+#[derive(Debug, Clone)]
+pub struct CsvOptions {
+    /// Field delimiter byte. Defaults to `,`.
+    pub delimiter: u8,
+    /// Quote character byte. Defaults to `"`.
+    pub quote: u8,
+    /// Whether the input's first row is a header row.
+    pub has_headers: bool,
+    /// Column names to use when `has_headers` is `false`. When absent,
+    /// headerless columns are synthesized as `col0`, `col1`, ….
+    pub header_names: Option<Vec<String>>,
+}
+
+impl Default for CsvOptions {
+    fn default() -> Self {
+        Self {
+            delimiter: b',',
+            quote: b'"',
+            has_headers: true,
+            header_names: None,
+        }
+    }
+}
+
+impl CsvOptions {
+    /// Options matching the builtin `FormatKind::Csv` dialect.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the field delimiter (e.g. `b'\t'` for TSV).
+    pub fn with_delimiter(mut self, delimiter: u8) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Set the quote character.
+    pub fn with_quote(mut self, quote: u8) -> Self {
+        self.quote = quote;
+        self
+    }
+
+    /// Set whether the input has a header row.
+    pub fn with_headers(mut self, has_headers: bool) -> Self {
+        self.has_headers = has_headers;
+        self
+    }
+
+    /// Supply explicit column names for headerless input. Implies
+    /// `has_headers(false)`.
+    pub fn with_header_names(mut self, names: Vec<String>) -> Self {
+        self.header_names = Some(names);
+        self.has_headers = false;
+        self
+    }
+
+    /// Build a [`CustomFormat`] that reads and writes CSV using this
+    /// dialect. Register the result with
+    /// [`super::FormatRegistry::register_custom`] under whatever name and
+    /// extensions fit (e.g. `"tsv"`, `&["tsv"]`).
+    pub fn into_custom_format(self, name: &'static str, extensions: &'static [&'static str]) -> CustomFormat {
+        let read_options = self.clone();
+        let write_options = super::OutputOptions::default()
+            .with_csv_delimiter(self.delimiter)
+            .with_csv_quote(self.quote)
+            .with_csv_header(self.has_headers);
+
+        CustomFormat::new(name, extensions)
+            .with_deserialize(move |bytes| deserialize_with_dialect(bytes, &read_options))
+            .with_serialize(move |value| serialize_with_options(value, &write_options))
+    }
+}
+
+/// Infer the `serde_json::Value` a raw CSV field represents: integer, then
+/// float, then `true`/`false`, falling back to a string when none of those
+/// parse (or when `quoted` overrides inference — see [`quoted_fields_per_line`]).
+fn infer_scalar(field: &str, quoted: bool) -> serde_json::Value {
+    if quoted {
+        return serde_json::Value::String(field.to_string());
+    }
+    if let Ok(i) = field.parse::<i64>() {
+        return serde_json::Value::Number(i.into());
+    }
+    if let Ok(f) = field.parse::<f64>()
+        && let Some(n) = serde_json::Number::from_f64(f)
+    {
+        return serde_json::Value::Number(n);
+    }
+    match field {
+        "true" => serde_json::Value::Bool(true),
+        "false" => serde_json::Value::Bool(false),
+        _ => serde_json::Value::String(field.to_string()),
+    }
+}
+
+/// Cheap, line-oriented scan of the raw CSV text for which fields were
+/// wrapped in quotes in the source, used to override scalar inference so a
+/// quoted `"42"` stays a string even though it also parses as a number.
+///
+/// Unlike the real `csv` parse this assumes one record per physical line and
+/// has no notion of a quoted field that embeds the delimiter or a newline.
+/// A quoted field that embeds a newline makes this scan's physical-line
+/// count diverge from `csv::Reader`'s record count (the embedded newline's
+/// two halves count as two lines here but one record there), which would
+/// silently misattribute quote info to every record after it, not just the
+/// one containing the embedded newline. Callers must not index into this
+/// directly for that reason - see [`quoted_fields_for_records`], which
+/// checks the lengths actually line up before trusting it.
+fn quoted_fields_per_line(bytes: &[u8], delimiter: u8, quote: u8) -> Vec<Vec<bool>> {
+    let delimiter = delimiter as char;
+    let quote = quote as char;
+    String::from_utf8_lossy(bytes)
+        .lines()
+        .map(|line| {
+            line.split(delimiter)
+                .map(|field| {
+                    let field = field.trim_end_matches('\r');
+                    field.len() >= 2 && field.starts_with(quote) && field.ends_with(quote)
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// [`quoted_fields_per_line`], validated against the real parsed `csv::Reader`
+/// record count before use. `has_headers` strips the header's own line the
+/// same way the caller already strips it from `records`.
+///
+/// If a quoted field embeds a newline, `quoted_fields_per_line`'s physical-
+/// line scan and the real record count diverge, and there's no cheap way to
+/// tell *which* row the embedded newline was in without re-implementing the
+/// real CSV quoting rules. Rather than risk attributing row N's quote info
+/// to row N+1 (or worse) for the rest of the document, a length mismatch
+/// disables the override entirely: every field falls back to plain
+/// [`infer_scalar`] inference, same as a quoted `"42"` would get if it were
+/// never scanned at all.
+fn quoted_fields_for_records(
+    bytes: &[u8],
+    delimiter: u8,
+    quote: u8,
+    has_headers: bool,
+    record_count: usize,
+) -> Vec<Vec<bool>> {
+    let mut quoted_lines = quoted_fields_per_line(bytes, delimiter, quote);
+    if has_headers && !quoted_lines.is_empty() {
+        quoted_lines.remove(0);
+    }
+    if quoted_lines.len() != record_count {
+        return Vec::new();
+    }
+    quoted_lines
+}
+
+fn record_to_json(record: &csv::StringRecord, headers: &[String], quoted_row: Option<&Vec<bool>>) -> serde_json::Value {
+    let mut obj = serde_json::Map::new();
+    for (i, field) in record.iter().enumerate() {
+        if let Some(header) = headers.get(i) {
+            let quoted = quoted_row.and_then(|row| row.get(i)).copied().unwrap_or(false);
+            obj.insert(header.clone(), infer_scalar(field, quoted));
+        }
+    }
+    serde_json::Value::Object(obj)
+}
+
+/// Deserialize CSV bytes honoring `options`' dialect (delimiter, quoting,
+/// and header handling), rather than the fixed comma-delimited, headered
+/// default [`deserialize`] uses.
+pub(crate) fn deserialize_with_dialect<T: DeserializeOwned>(
+    bytes: &[u8],
+    options: &CsvOptions,
+) -> Result<T, FormatError> {
+    let mut rdr = csv::ReaderBuilder::new()
+        .delimiter(options.delimiter)
+        .quote(options.quote)
+        .has_headers(options.has_headers)
+        .from_reader(bytes);
+
+    let records: Vec<csv::StringRecord> = rdr
+        .records()
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| malformed(PayloadErrorKind::Syntax, e.to_string(), bytes))?;
+
+    let headers: Vec<String> = if options.has_headers {
+        rdr.headers()
+            .map_err(|e| malformed(PayloadErrorKind::Syntax, e.to_string(), bytes))?
+            .iter()
+            .map(|s| s.to_string())
+            .collect()
+    } else if let Some(names) = &options.header_names {
+        names.clone()
+    } else {
+        let width = records.first().map(|r| r.len()).unwrap_or(0);
+        (0..width).map(|i| format!("col{i}")).collect()
+    };
+
+    let quoted_lines = quoted_fields_for_records(
+        bytes,
+        options.delimiter,
+        options.quote,
+        options.has_headers,
+        records.len(),
+    );
+
+    let json_records: Vec<serde_json::Value> = records
+        .iter()
+        .enumerate()
+        .map(|(i, record)| record_to_json(record, &headers, quoted_lines.get(i)))
+        .collect();
+
+    let json_value = serde_json::Value::Array(json_records);
+    records_to_value(json_value, bytes)
+}
 
 pub(crate) fn deserialize<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, FormatError> {
     let mut rdr = csv::ReaderBuilder::new()
@@ -12,39 +266,109 @@ pub(crate) fn deserialize<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, Format
     let records: Vec<csv::StringRecord> = rdr
         .records()
         .collect::<Result<Vec<_>, _>>()
-        .map_err(|e| FormatError::Serde(Box::new(e)))?;
+        .map_err(|e| malformed(PayloadErrorKind::Syntax, e.to_string(), bytes))?;
+
+    let headers = rdr
+        .headers()
+        .map_err(|e| malformed(PayloadErrorKind::Syntax, e.to_string(), bytes))?;
+    let headers: Vec<String> = headers.iter().map(|s| s.to_string()).collect();
 
-    let headers = rdr.headers().map_err(|e| FormatError::Serde(Box::new(e)))?;
-    let headers: Vec<&str> = headers.iter().collect();
+    let quoted_lines = quoted_fields_for_records(bytes, b',', b'"', true, records.len());
 
     let json_records: Vec<serde_json::Value> = records
         .iter()
-        .map(|record| {
-            let mut obj = serde_json::Map::new();
-            for (i, field) in record.iter().enumerate() {
-                if let Some(header) = headers.get(i) {
-                    obj.insert(
-                        (*header).to_string(),
-                        serde_json::Value::String(field.to_string()),
-                    );
-                }
-            }
-            serde_json::Value::Object(obj)
-        })
+        .enumerate()
+        .map(|(i, record)| record_to_json(record, &headers, quoted_lines.get(i)))
         .collect();
 
     let json_value = serde_json::Value::Array(json_records);
-    serde_json::from_value(json_value).map_err(|e| FormatError::Serde(Box::new(e)))
+    records_to_value(json_value, bytes)
+}
+
+/// Stream CSV records from a reader, one row at a time.
+///
+/// Unlike [`deserialize`], which collects every row into one
+/// `serde_json::Value::Array` before converting it to `T`, this reads the
+/// header row once, then builds and converts a single-row `serde_json::Map`
+/// per record — so memory use stays constant regardless of file size. Fields
+/// get the same integer/float/bool/string [`infer_scalar`] treatment as the
+/// non-streaming path, but without the quoted-value override: that relies on
+/// re-scanning the raw input line by line, which would defeat the constant-
+/// memory point of streaming, so a quoted `"42"` here is inferred as a number
+/// like any other `42`.
+pub(crate) fn stream_deserialize<T, R>(reader: R) -> impl Iterator<Item = Result<T, FormatError>>
+where
+    T: DeserializeOwned,
+    R: Read,
+{
+    let mut rdr = csv::ReaderBuilder::new()
+        .has_headers(true)
+        .from_reader(reader);
+    let mut headers: Option<Vec<String>> = None;
+    let mut record = csv::StringRecord::new();
+    let mut terminated = false;
+
+    std::iter::from_fn(move || {
+        if terminated {
+            return None;
+        }
+
+        if headers.is_none() {
+            match rdr.headers() {
+                Ok(h) => headers = Some(h.iter().map(|s| s.to_string()).collect()),
+                Err(e) => {
+                    terminated = true;
+                    return Some(Err(FormatError::Serde(Box::new(e))));
+                }
+            }
+        }
+
+        match rdr.read_record(&mut record) {
+            Ok(true) => {
+                let headers = headers.as_ref().expect("headers read above");
+                let mut obj = serde_json::Map::new();
+                for (i, field) in record.iter().enumerate() {
+                    if let Some(header) = headers.get(i) {
+                        obj.insert(header.clone(), infer_scalar(field, false));
+                    }
+                }
+                let value = serde_json::Value::Object(obj);
+                Some(serde_json::from_value::<T>(value).map_err(|e| FormatError::Serde(Box::new(e))))
+            }
+            Ok(false) => {
+                terminated = true;
+                None
+            }
+            Err(e) => {
+                terminated = true;
+                Some(Err(FormatError::Serde(Box::new(e))))
+            }
+        }
+    })
 }
 
 pub(crate) fn serialize<T: Serialize>(value: &T) -> Result<Vec<u8>, FormatError> {
+    serialize_with_options(value, &super::OutputOptions::default())
+}
+
+/// CSV has no notion of compact/pretty whitespace, indent, or key order
+/// (columns come from the header row), so only `csv_delimiter`, `csv_quote`,
+/// and `csv_header` are honored.
+pub(crate) fn serialize_with_options<T: Serialize>(
+    value: &T,
+    options: &super::OutputOptions,
+) -> Result<Vec<u8>, FormatError> {
     let json_value = serde_json::to_value(value).map_err(|e| FormatError::Serde(Box::new(e)))?;
 
-    let mut wtr = csv::Writer::from_writer(Vec::new());
+    let mut wtr = csv::WriterBuilder::new()
+        .delimiter(options.csv_delimiter)
+        .quote(options.csv_quote)
+        .from_writer(Vec::new());
 
     match json_value {
         serde_json::Value::Array(arr) => {
-            if let Some(first) = arr.first()
+            if options.csv_header
+                && let Some(first) = arr.first()
                 && let serde_json::Value::Object(obj) = first
             {
                 let headers: Vec<&str> = obj.keys().map(|s| s.as_str()).collect();
@@ -67,9 +391,11 @@ pub(crate) fn serialize<T: Serialize>(value: &T) -> Result<Vec<u8>, FormatError>
             }
         }
         serde_json::Value::Object(obj) => {
-            let headers: Vec<&str> = obj.keys().map(|s| s.as_str()).collect();
-            wtr.write_record(&headers)
-                .map_err(|e| FormatError::Serde(Box::new(e)))?;
+            if options.csv_header {
+                let headers: Vec<&str> = obj.keys().map(|s| s.as_str()).collect();
+                wtr.write_record(&headers)
+                    .map_err(|e| FormatError::Serde(Box::new(e)))?;
+            }
 
             let record: Vec<String> = obj
                 .values()
@@ -81,14 +407,70 @@ pub(crate) fn serialize<T: Serialize>(value: &T) -> Result<Vec<u8>, FormatError>
             wtr.write_record(&record)
                 .map_err(|e| FormatError::Serde(Box::new(e)))?;
         }
-        _ => {
-            return Err(FormatError::Other(Box::new(std::io::Error::new(
-                std::io::ErrorKind::InvalidData,
+        other => {
+            return Err(malformed(
+                PayloadErrorKind::DataShape,
                 "CSV format requires an array or object",
-            ))));
+                other.to_string().as_bytes(),
+            ));
         }
     }
 
     wtr.into_inner()
         .map_err(|e| FormatError::Other(Box::new(e)))
 }
+
+/// Stream-serialize values into CSV, writing a header from the first record
+/// then one row per record (the first included), rather than collecting every record
+/// into an array first. Each record must serialize to a JSON object; the
+/// header row is its key order, honoring [`super::OutputOptions::csv_header`].
+pub(crate) fn stream_serialize(
+    values: Box<dyn Iterator<Item = Result<serde_json::Value, FormatError>>>,
+    writer: &mut dyn Write,
+) -> Result<(), FormatError> {
+    stream_serialize_with_options(values, writer, &super::OutputOptions::default())
+}
+
+/// [`stream_serialize`], honoring `options`' delimiter, quote character, and
+/// whether to write a header row.
+pub(crate) fn stream_serialize_with_options(
+    values: Box<dyn Iterator<Item = Result<serde_json::Value, FormatError>>>,
+    writer: &mut dyn Write,
+    options: &super::OutputOptions,
+) -> Result<(), FormatError> {
+    let mut wtr = csv::WriterBuilder::new()
+        .delimiter(options.csv_delimiter)
+        .quote(options.csv_quote)
+        .from_writer(writer);
+    let mut header_written = !options.csv_header;
+
+    for value in values {
+        let value = value?;
+        let serde_json::Value::Object(obj) = value else {
+            return Err(malformed(
+                PayloadErrorKind::DataShape,
+                "CSV streaming requires each record to be an object",
+                value.to_string().as_bytes(),
+            ));
+        };
+
+        if !header_written {
+            let headers: Vec<&str> = obj.keys().map(|s| s.as_str()).collect();
+            wtr.write_record(&headers)
+                .map_err(|e| FormatError::Serde(Box::new(e)))?;
+            header_written = true;
+        }
+
+        let record: Vec<String> = obj
+            .values()
+            .map(|v| match v {
+                serde_json::Value::String(s) => s.clone(),
+                _ => v.to_string(),
+            })
+            .collect();
+        wtr.write_record(&record)
+            .map_err(|e| FormatError::Serde(Box::new(e)))?;
+    }
+
+    wtr.flush().map_err(|e| FormatError::Other(Box::new(e)))
+}
@@ -3,6 +3,7 @@
 //! This module allows developers to register their own format implementations
 //! without modifying the core library.
 
+use std::io::{Read, Write};
 use std::sync::Arc;
 
 use serde::{Serialize, de::DeserializeOwned};
@@ -12,15 +13,59 @@ use super::FormatError;
 /// Type alias for custom deserialize function.
 ///
 /// Takes raw bytes and returns a `serde_json::Value` which can then be
-/// converted to the target type.
+/// converted to the target type. Object key order in the returned `Value`
+/// is whatever `serde_json::Map` preserves: insertion order with the crate's
+/// `preserve_order` feature enabled (which also turns on `serde_json`'s own
+/// `preserve_order` feature), alphabetical otherwise.
 pub type DeserializeFn = Arc<dyn Fn(&[u8]) -> Result<serde_json::Value, FormatError> + Send + Sync>;
 
 /// Type alias for custom serialize function.
 ///
-/// Takes a `serde_json::Value` and returns serialized bytes.
+/// Takes a `serde_json::Value` and returns serialized bytes. With the
+/// crate's `preserve_order` feature enabled, the `Value` handed in here
+/// retains whatever field order [`DeserializeFn`] (or the caller's own
+/// `serde_json::to_value`) produced, so a format whose own writer iterates
+/// the `Value`'s object in order (rather than sorting it) round-trips field
+/// order end to end.
 pub type SerializeFn =
     Arc<dyn Fn(&serde_json::Value) -> Result<Vec<u8>, FormatError> + Send + Sync>;
 
+/// Type alias for a custom format's content-sniffing signal, consulted by
+/// [`super::FormatRegistry::detect_format`]. Takes raw bytes that already
+/// deserialized successfully and returns whether they also carry this
+/// format's own syntactic tell (as opposed to merely happening to parse).
+pub type SniffFn = Arc<dyn Fn(&[u8]) -> bool + Send + Sync>;
+
+/// Type alias for a custom format's incremental streaming decoder.
+///
+/// Takes a boxed reader and returns an iterator of values, one per record,
+/// the same way the builtin per-module `stream_deserialize` functions do.
+/// Formats without a genuine incremental decoder can leave this unset;
+/// [`CustomFormat::stream_deserialize_values`] then falls back to a single
+/// non-streaming `deserialize` call.
+pub type StreamDeserializeFn = Arc<
+    dyn Fn(Box<dyn Read>) -> Box<dyn Iterator<Item = Result<serde_json::Value, FormatError>>>
+        + Send
+        + Sync,
+>;
+
+/// Type alias for a custom format's incremental streaming encoder.
+///
+/// Takes an iterator of values and a writer, and writes each record
+/// incrementally, the same way the builtin per-module `stream_serialize`
+/// functions do. Formats without a genuine incremental encoder can leave this
+/// unset; [`CustomFormat::stream_serialize_values`] then falls back to
+/// collecting every record and making a single non-streaming `serialize`
+/// call.
+pub type StreamSerializeFn = Arc<
+    dyn Fn(
+            Box<dyn Iterator<Item = Result<serde_json::Value, FormatError>>>,
+            &mut dyn Write,
+        ) -> Result<(), FormatError>
+        + Send
+        + Sync,
+>;
+
 /// A custom format handler that can be registered with the FormatRegistry.
 ///
 /// # Example
@@ -52,6 +97,12 @@ pub struct CustomFormat {
     pub deserialize_fn: Option<DeserializeFn>,
     /// Serialize function
     pub serialize_fn: Option<SerializeFn>,
+    /// Content-sniffing signal used by `FormatRegistry::detect_format`/`detect`
+    pub sniff_fn: Option<SniffFn>,
+    /// Incremental streaming decoder, consulted by `stream_deserialize_values`
+    pub stream_deserialize_fn: Option<StreamDeserializeFn>,
+    /// Incremental streaming encoder, consulted by `stream_serialize_values`
+    pub stream_serialize_fn: Option<StreamSerializeFn>,
 }
 
 impl std::fmt::Debug for CustomFormat {
@@ -61,6 +112,9 @@ impl std::fmt::Debug for CustomFormat {
             .field("extensions", &self.extensions)
             .field("has_deserialize", &self.deserialize_fn.is_some())
             .field("has_serialize", &self.serialize_fn.is_some())
+            .field("has_sniff", &self.sniff_fn.is_some())
+            .field("has_stream_deserialize", &self.stream_deserialize_fn.is_some())
+            .field("has_stream_serialize", &self.stream_serialize_fn.is_some())
             .finish()
     }
 }
@@ -73,6 +127,9 @@ impl CustomFormat {
             extensions,
             deserialize_fn: None,
             serialize_fn: None,
+            sniff_fn: None,
+            stream_deserialize_fn: None,
+            stream_serialize_fn: None,
         }
     }
 
@@ -94,33 +151,112 @@ impl CustomFormat {
         self
     }
 
+    /// Set the content-sniffing signal consulted by
+    /// `FormatRegistry::detect_format`/`detect` to rank this format above a
+    /// bare successful parse (e.g. a magic header or a distinctive leading
+    /// character) when deciding between several formats that all happen to
+    /// parse the same bytes.
+    pub fn with_sniff<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&[u8]) -> bool + Send + Sync + 'static,
+    {
+        self.sniff_fn = Some(Arc::new(f));
+        self
+    }
+
+    /// Set the incremental streaming decoder.
+    pub fn with_stream_deserialize<F>(mut self, f: F) -> Self
+    where
+        F: Fn(Box<dyn Read>) -> Box<dyn Iterator<Item = Result<serde_json::Value, FormatError>>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.stream_deserialize_fn = Some(Arc::new(f));
+        self
+    }
+
+    /// Set the incremental streaming encoder.
+    pub fn with_stream_serialize<F>(mut self, f: F) -> Self
+    where
+        F: Fn(
+                Box<dyn Iterator<Item = Result<serde_json::Value, FormatError>>>,
+                &mut dyn Write,
+            ) -> Result<(), FormatError>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.stream_serialize_fn = Some(Arc::new(f));
+        self
+    }
+
+    /// Stream-deserialize a reader into a sequence of `serde_json::Value`s.
+    ///
+    /// Uses the registered streaming decoder when one is set; otherwise
+    /// falls back to reading the whole reader and yielding a single value
+    /// from [`Self::deserialize`].
+    pub fn stream_deserialize_values(
+        &self,
+        mut reader: Box<dyn Read>,
+    ) -> Result<Box<dyn Iterator<Item = Result<serde_json::Value, FormatError>>>, FormatError> {
+        if let Some(stream_fn) = &self.stream_deserialize_fn {
+            return Ok(stream_fn(reader));
+        }
+
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes)?;
+        let value: serde_json::Value = self.deserialize(&bytes)?;
+        Ok(Box::new(std::iter::once(Ok(value))))
+    }
+
+    /// Stream-serialize a sequence of `serde_json::Value`s to `writer`.
+    ///
+    /// Uses the registered streaming encoder when one is set; otherwise
+    /// falls back to collecting every value and making a single call to
+    /// [`Self::serialize`].
+    pub fn stream_serialize_values(
+        &self,
+        values: Box<dyn Iterator<Item = Result<serde_json::Value, FormatError>>>,
+        writer: &mut dyn Write,
+    ) -> Result<(), FormatError> {
+        if let Some(stream_fn) = &self.stream_serialize_fn {
+            return stream_fn(values, writer);
+        }
+
+        let collected: Vec<serde_json::Value> = values.collect::<Result<_, _>>()?;
+        let serialize_fn = self
+            .serialize_fn
+            .as_ref()
+            .ok_or_else(|| self.unsupported("serialization"))?;
+        let bytes = serialize_fn(&serde_json::Value::Array(collected))?;
+        writer.write_all(&bytes)?;
+        Ok(())
+    }
+
     /// Deserialize bytes to a typed value.
     pub fn deserialize<T: DeserializeOwned>(&self, bytes: &[u8]) -> Result<T, FormatError> {
-        let deserialize_fn = self.deserialize_fn.as_ref().ok_or_else(|| {
-            FormatError::Other(Box::new(std::io::Error::new(
-                std::io::ErrorKind::Unsupported,
-                format!(
-                    "Custom format '{}' does not support deserialization",
-                    self.name
-                ),
-            )))
-        })?;
+        let deserialize_fn = self
+            .deserialize_fn
+            .as_ref()
+            .ok_or_else(|| self.unsupported("deserialization"))?;
 
         let value = deserialize_fn(bytes)?;
-        serde_json::from_value(value).map_err(|e| FormatError::Serde(Box::new(e)))
+        serde_path_to_error::deserialize(value).map_err(|e| {
+            if e.path().to_string() == "." {
+                FormatError::Serde(Box::new(e.into_inner()))
+            } else {
+                super::path_tracking_error(e)
+            }
+        })
     }
 
     /// Serialize a typed value to bytes.
     pub fn serialize<T: Serialize>(&self, value: &T) -> Result<Vec<u8>, FormatError> {
-        let serialize_fn = self.serialize_fn.as_ref().ok_or_else(|| {
-            FormatError::Other(Box::new(std::io::Error::new(
-                std::io::ErrorKind::Unsupported,
-                format!(
-                    "Custom format '{}' does not support serialization",
-                    self.name
-                ),
-            )))
-        })?;
+        let serialize_fn = self
+            .serialize_fn
+            .as_ref()
+            .ok_or_else(|| self.unsupported("serialization"))?;
 
         let json_value =
             serde_json::to_value(value).map_err(|e| FormatError::Serde(Box::new(e)))?;
@@ -134,4 +270,48 @@ impl CustomFormat {
             .iter()
             .any(|e| e.eq_ignore_ascii_case(&ext_lower))
     }
+
+    fn unsupported(&self, op: &str) -> FormatError {
+        FormatError::Other(Box::new(std::io::Error::new(
+            std::io::ErrorKind::Unsupported,
+            format!("Custom format '{}' does not support {}", self.name, op),
+        )))
+    }
+}
+
+impl super::Format for CustomFormat {
+    /// Bridges through `serde_json::to_value` since `serialize_fn` is
+    /// generic over `T: Serialize`, not object-safe.
+    fn serialize(&self, value: &dyn erased_serde::Serialize) -> Result<Vec<u8>, FormatError> {
+        let serialize_fn = self
+            .serialize_fn
+            .as_ref()
+            .ok_or_else(|| self.unsupported("serialization"))?;
+        let json_value =
+            serde_json::to_value(value).map_err(|e| FormatError::Serde(Box::new(e)))?;
+        serialize_fn(&json_value)
+    }
+
+    fn deserialize(&self, bytes: &[u8]) -> Result<serde_json::Value, FormatError> {
+        let deserialize_fn = self
+            .deserialize_fn
+            .as_ref()
+            .ok_or_else(|| self.unsupported("deserialization"))?;
+        deserialize_fn(bytes)
+    }
+
+    fn stream_deserialize(
+        &self,
+        reader: Box<dyn Read>,
+    ) -> Result<Box<dyn Iterator<Item = Result<serde_json::Value, FormatError>>>, FormatError> {
+        self.stream_deserialize_values(reader)
+    }
+
+    fn stream_serialize(
+        &self,
+        values: Box<dyn Iterator<Item = Result<serde_json::Value, FormatError>>>,
+        writer: &mut dyn Write,
+    ) -> Result<(), FormatError> {
+        self.stream_serialize_values(values, writer)
+    }
 }
@@ -0,0 +1,70 @@
+//! Syntactic "does this look like format X" signals shared between
+//! `plaintext`'s structured-text fallback and
+//! [`super::FormatRegistry::detect_format`].
+//!
+//! These are deliberately cheap, line-oriented checks (leading `{`/`[`/`---`,
+//! `key: value` lines, `key=value` lines) rather than a real parse; a real
+//! parse is what `detect_format` uses alongside these signals to turn "looks
+//! like" into "parses as, and looks like".
+
+fn has_leading(s: &str, prefixes: &[&str]) -> bool {
+    let trimmed = s.trim_start();
+    prefixes.iter().any(|p| trimmed.starts_with(p))
+}
+
+fn has_key_colon_line(s: &str) -> bool {
+    s.lines().any(|line| {
+        let line = line.trim_start();
+        line.starts_with("- ") || line.contains(": ") || line.ends_with(':')
+    })
+}
+
+fn has_key_equals_line(s: &str) -> bool {
+    s.lines().any(|line| match line.trim().split_once('=') {
+        Some((left, right)) => !left.trim().is_empty() && !right.trim().is_empty(),
+        None => false,
+    })
+}
+
+#[cfg(feature = "json")]
+pub(crate) fn json_signal(s: &str) -> bool {
+    has_leading(s, &["{", "["])
+}
+
+#[cfg(feature = "yaml")]
+pub(crate) fn yaml_signal(s: &str) -> bool {
+    has_leading(s, &["---"]) || has_key_colon_line(s)
+}
+
+#[cfg(feature = "toml")]
+pub(crate) fn toml_signal(s: &str) -> bool {
+    has_key_equals_line(s)
+}
+
+#[cfg(feature = "xml")]
+pub(crate) fn xml_signal(s: &str) -> bool {
+    has_leading(s, &["<?xml", "<"])
+}
+
+/// Bytes a content-sniffing signal inspects, rather than the whole payload —
+/// cheap even for a multi-megabyte input, and plenty to spot a format's own
+/// magic/leading bytes.
+pub(crate) const SNIFF_PREFIX_LEN: usize = 512;
+
+pub(crate) fn sniff_prefix(bytes: &[u8]) -> &[u8] {
+    &bytes[..bytes.len().min(SNIFF_PREFIX_LEN)]
+}
+
+/// Whether `bytes` starts with a CBOR major type byte for one of the
+/// self-describing top-level shapes (map or array) that a round-tripped
+/// document typically uses, or the CBOR "self-describe" tag (0xd9d9f7)
+/// sometimes prepended to identify the bytes as CBOR.
+#[cfg(feature = "cbor")]
+pub(crate) fn cbor_signal(bytes: &[u8]) -> bool {
+    let prefix = sniff_prefix(bytes);
+    match prefix {
+        [0xd9, 0xd9, 0xf7, ..] => true,
+        [first, ..] => matches!(first, 0x80..=0x9f | 0xa0..=0xbf),
+        [] => false,
+    }
+}
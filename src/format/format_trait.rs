@@ -0,0 +1,143 @@
+//! Trait-object dispatch for format handlers.
+//!
+//! Builtin and custom formats both implement [`Format`], so [`super::FormatRegistry`]
+//! can store either kind under the same [`super::FormatKind`] key, look one up,
+//! and stream through it uniformly, rather than special-casing "is this
+//! custom or builtin" at every call site.
+
+use std::io::{Read, Write};
+
+use super::{FormatError, FormatKind};
+
+/// A format handler bridging serialize/deserialize/stream_deserialize
+/// through `serde_json::Value`, the same document model [`super::CustomFormat`]
+/// already bridges custom formats through.
+pub trait Format: Send + Sync {
+    /// Serialize an already type-erased value. Takes `&dyn erased_serde::Serialize`
+    /// rather than a generic `T: Serialize` so the trait stays object-safe.
+    fn serialize(&self, value: &dyn erased_serde::Serialize) -> Result<Vec<u8>, FormatError>;
+
+    /// Deserialize into the generic `serde_json::Value` document model; the
+    /// caller converts to a concrete type with `serde_json::from_value`.
+    fn deserialize(&self, bytes: &[u8]) -> Result<serde_json::Value, FormatError>;
+
+    /// Stream-deserialize a reader into a sequence of values, one per
+    /// record. The default falls back to reading the whole reader and
+    /// yielding a single item from [`Self::deserialize`]; formats with a
+    /// genuine incremental decoder override this.
+    fn stream_deserialize(
+        &self,
+        mut reader: Box<dyn Read>,
+    ) -> Result<Box<dyn Iterator<Item = Result<serde_json::Value, FormatError>>>, FormatError> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes)?;
+        let value = self.deserialize(&bytes)?;
+        Ok(Box::new(std::iter::once(Ok(value))))
+    }
+
+    /// Stream-serialize a sequence of values, one per record, writing
+    /// incrementally rather than collecting the whole sequence first. The
+    /// default collects `values` into a single JSON array and writes it with
+    /// one [`Self::serialize`] call; formats with a genuine incremental
+    /// encoder override this.
+    fn stream_serialize(
+        &self,
+        values: Box<dyn Iterator<Item = Result<serde_json::Value, FormatError>>>,
+        writer: &mut dyn Write,
+    ) -> Result<(), FormatError> {
+        let collected: Vec<serde_json::Value> = values.collect::<Result<_, _>>()?;
+        let bytes = self.serialize(&serde_json::Value::Array(collected))?;
+        writer.write_all(&bytes)?;
+        Ok(())
+    }
+}
+
+/// `Format` handler for one of the macro-generated builtin formats. Bridges
+/// through the same top-level `deserialize`/`serialize` functions the rest
+/// of this module uses, and through each module's native stream decoder
+/// where one exists; falls back to the trait's default buffered
+/// implementation for formats without one (TOML, INI, RON, JSON5, XML,
+/// Markdown).
+pub(crate) struct BuiltinHandler(pub FormatKind);
+
+impl Format for BuiltinHandler {
+    fn serialize(&self, value: &dyn erased_serde::Serialize) -> Result<Vec<u8>, FormatError> {
+        let json_value =
+            serde_json::to_value(value).map_err(|e| FormatError::Serde(Box::new(e)))?;
+        super::serialize(self.0, &json_value)
+    }
+
+    fn deserialize(&self, bytes: &[u8]) -> Result<serde_json::Value, FormatError> {
+        super::deserialize(self.0, bytes)
+    }
+
+    fn stream_deserialize(
+        &self,
+        reader: Box<dyn Read>,
+    ) -> Result<Box<dyn Iterator<Item = Result<serde_json::Value, FormatError>>>, FormatError> {
+        match self.0 {
+            #[cfg(feature = "json")]
+            FormatKind::Json => Ok(Box::new(super::deserialize_json_stream::<
+                serde_json::Value,
+                _,
+            >(reader))),
+            #[cfg(feature = "csv")]
+            FormatKind::Csv => Ok(Box::new(
+                super::deserialize_csv_stream::<serde_json::Value, _>(reader),
+            )),
+            #[cfg(feature = "yaml")]
+            FormatKind::Yaml => Ok(Box::new(super::deserialize_yaml_stream::<
+                serde_json::Value,
+                _,
+            >(reader))),
+            #[cfg(feature = "plaintext")]
+            FormatKind::Plaintext => Ok(Box::new(super::deserialize_plaintext_stream::<
+                serde_json::Value,
+                _,
+            >(reader))),
+            #[cfg(feature = "preserves")]
+            FormatKind::Preserves => Ok(Box::new(super::deserialize_preserves_stream::<
+                serde_json::Value,
+                _,
+            >(reader))),
+            #[cfg(feature = "ndjson")]
+            FormatKind::Ndjson => Ok(Box::new(super::deserialize_ndjson_stream::<
+                serde_json::Value,
+                _,
+            >(reader))),
+            #[cfg(feature = "cbor")]
+            FormatKind::Cbor => Ok(Box::new(super::deserialize_cbor_stream::<
+                serde_json::Value,
+                _,
+            >(reader))),
+            _ => {
+                let mut reader = reader;
+                let mut bytes = Vec::new();
+                reader.read_to_end(&mut bytes)?;
+                let value = self.deserialize(&bytes)?;
+                Ok(Box::new(std::iter::once(Ok(value))))
+            }
+        }
+    }
+
+    fn stream_serialize(
+        &self,
+        values: Box<dyn Iterator<Item = Result<serde_json::Value, FormatError>>>,
+        writer: &mut dyn Write,
+    ) -> Result<(), FormatError> {
+        match self.0 {
+            #[cfg(feature = "ndjson")]
+            FormatKind::Ndjson => super::ndjson::stream_serialize(values, writer),
+            #[cfg(feature = "plaintext")]
+            FormatKind::Plaintext => super::plaintext::stream_serialize(values, writer),
+            #[cfg(feature = "csv")]
+            FormatKind::Csv => super::csv::stream_serialize(values, writer),
+            _ => {
+                let collected: Vec<serde_json::Value> = values.collect::<Result<_, _>>()?;
+                let bytes = self.serialize(&serde_json::Value::Array(collected))?;
+                writer.write_all(&bytes)?;
+                Ok(())
+            }
+        }
+    }
+}
@@ -12,3 +12,11 @@ pub(crate) fn serialize<T: Serialize>(value: &T) -> Result<Vec<u8>, FormatError>
         .map(|s| s.into_bytes())
         .map_err(|e| FormatError::Serde(Box::new(e)))
 }
+
+/// `serde_ini` has no compact/pretty or key-order knobs, so `options` is ignored.
+pub(crate) fn serialize_with_options<T: Serialize>(
+    value: &T,
+    _options: &super::OutputOptions,
+) -> Result<Vec<u8>, FormatError> {
+    serialize(value)
+}
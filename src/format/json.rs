@@ -1,24 +1,229 @@
 //! JSON format implementation.
 
-use serde::{Serialize, de::DeserializeOwned};
+use serde::de::{Deserializer as _, MapAccess, SeqAccess, Visitor, value::MapAccessDeserializer};
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use std::fmt;
 use std::io::Read;
+use std::marker::PhantomData;
 
 use super::FormatError;
 
 pub(crate) fn deserialize<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, FormatError> {
-    serde_json::from_slice(bytes).map_err(|e| FormatError::Serde(Box::new(e)))
+    let mut de = serde_json::Deserializer::from_slice(bytes);
+    serde_path_to_error::deserialize(&mut de).map_err(|e| {
+        if e.inner().classify() == serde_json::error::Category::Data {
+            // A value was actually handed to `T`'s `Deserialize` impl and
+            // rejected (wrong type, out-of-range, ...), so the path
+            // `serde_path_to_error` recorded while descending to it is
+            // meaningful. A bare syntax/truncation failure still gets a path
+            // (whichever field/index was being parsed when the document
+            // ran out), but that path isn't the useful part of the story.
+            super::path_tracking_error(e)
+        } else {
+            let inner = e.into_inner();
+            let offset = super::line_col_to_offset(bytes, inner.line(), inner.column());
+            FormatError::SerdeSpanned {
+                span: (offset, 1),
+                input: bytes.into(),
+                source: Box::new(inner),
+            }
+        }
+    })
+}
+
+/// Deserialize JSONC: JSON with `//`/`/* */` comments and trailing commas
+/// stripped before handing the cleaned text to `serde_json`.
+pub(crate) fn deserialize_jsonc<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, FormatError> {
+    let cleaned = super::strip_jsonc_comments(&String::from_utf8_lossy(bytes));
+    serde_json::from_str(&cleaned).map_err(|e| FormatError::Serde(Box::new(e)))
 }
 
 pub(crate) fn serialize<T: Serialize>(value: &T) -> Result<Vec<u8>, FormatError> {
     serde_json::to_vec_pretty(value).map_err(|e| FormatError::Serde(Box::new(e)))
 }
 
+pub(crate) fn serialize_with_options<T: Serialize>(
+    value: &T,
+    options: &super::OutputOptions,
+) -> Result<Vec<u8>, FormatError> {
+    use super::{KeyOrder, OutputStyle};
+
+    // Routes through `serde_json::Value` (needed for `sort_keys`/the compact
+    // vs. pretty choice below), so unless `KeyOrder::Sorted` is requested,
+    // whether this preserves `value`'s field order depends on whether the
+    // crate's `preserve_order` feature (which enables `serde_json`'s own)
+    // is enabled.
+    let mut json_value =
+        serde_json::to_value(value).map_err(|e| FormatError::Serde(Box::new(e)))?;
+    if options.key_order == KeyOrder::Sorted {
+        sort_keys(&mut json_value);
+    }
+
+    match options.style {
+        OutputStyle::Compact => {
+            serde_json::to_vec(&json_value).map_err(|e| FormatError::Serde(Box::new(e)))
+        }
+        OutputStyle::Pretty => {
+            let mut buf = Vec::new();
+            let formatter =
+                serde_json::ser::PrettyFormatter::with_indent(options.indent.as_bytes());
+            let mut ser = serde_json::Serializer::with_formatter(&mut buf, formatter);
+            json_value
+                .serialize(&mut ser)
+                .map_err(|e| FormatError::Serde(Box::new(e)))?;
+            Ok(buf)
+        }
+    }
+}
+
+/// Recursively sort object keys alphabetically. `serde_json::Map`'s own
+/// iteration order depends on whether the `preserve_order` cargo feature is
+/// enabled, so sorting explicitly here makes `KeyOrder::Sorted` deterministic
+/// either way.
+fn sort_keys(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Object(map) => {
+            let mut entries: Vec<(String, serde_json::Value)> =
+                std::mem::take(map).into_iter().collect();
+            for (_, v) in entries.iter_mut() {
+                sort_keys(v);
+            }
+            entries.sort_by(|a, b| a.0.cmp(&b.0));
+            map.extend(entries);
+        }
+        serde_json::Value::Array(arr) => {
+            for v in arr.iter_mut() {
+                sort_keys(v);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// A `Visitor` that streams elements of a top-level JSON value to `callback`
+/// as they're parsed, rather than collecting them into an intermediate
+/// `serde_json::Value` tree first: `visit_seq` hands each array element
+/// straight to `callback`, and `visit_map` treats a lone top-level object as
+/// a one-element sequence, matching the "accept an object or an array of
+/// objects" behavior the non-streaming [`deserialize`] path already has.
+struct ArrayOrSingleVisitor<'a, T, F> {
+    callback: &'a mut F,
+    _marker: PhantomData<T>,
+}
+
+impl<'de, T, F> Visitor<'de> for ArrayOrSingleVisitor<'_, T, F>
+where
+    T: Deserialize<'de>,
+    F: FnMut(T),
+{
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a JSON array or a single JSON value")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        while let Some(item) = seq.next_element::<T>()? {
+            (self.callback)(item);
+        }
+        Ok(())
+    }
+
+    fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let value = T::deserialize(MapAccessDeserializer::new(map))?;
+        (self.callback)(value);
+        Ok(())
+    }
+}
+
+/// Stream a top-level JSON array's elements directly off `reader` via
+/// [`ArrayOrSingleVisitor`], skipping the `serde_json::Value` tree
+/// [`deserialize`] would otherwise build for the whole array. The results
+/// are still collected into one `Vec` before this returns, since making the
+/// `Visitor` callback itself lazily resumable across an external iterator's
+/// `.next()` calls would need cooperative suspension (e.g. a background
+/// thread) that the non-`Send` bounds on this module's streaming functions
+/// don't allow for; what's saved is the intermediate `Value` representation
+/// (typically much larger than `T` for nested objects), not the need to
+/// hold every element at once.
+fn stream_array_elements<T, R>(reader: R) -> Result<Vec<T>, FormatError>
+where
+    T: DeserializeOwned,
+    R: Read,
+{
+    let mut items = Vec::new();
+    let mut callback = |item: T| items.push(item);
+    let visitor = ArrayOrSingleVisitor {
+        callback: &mut callback,
+        _marker: PhantomData,
+    };
+    let mut de = serde_json::Deserializer::from_reader(reader);
+    de.deserialize_any(visitor)
+        .map_err(|e| FormatError::Serde(Box::new(e)))?;
+    Ok(items)
+}
+
+/// Peek the first non-whitespace byte of `reader` without consuming it, to
+/// tell a top-level JSON array apart from one or more whitespace-separated
+/// top-level values.
+fn peek_first_significant_byte<R: std::io::BufRead>(reader: &mut R) -> Option<u8> {
+    loop {
+        let buf = reader.fill_buf().ok()?;
+        if buf.is_empty() {
+            return None;
+        }
+        if let Some(&b) = buf.iter().find(|b| !b.is_ascii_whitespace()) {
+            return Some(b);
+        }
+        let len = buf.len();
+        reader.consume(len);
+    }
+}
+
+enum Either<L, R> {
+    Left(L),
+    Right(R),
+}
+
+impl<L, R> Iterator for Either<L, R>
+where
+    L: Iterator,
+    R: Iterator<Item = L::Item>,
+{
+    type Item = L::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            Either::Left(l) => l.next(),
+            Either::Right(r) => r.next(),
+        }
+    }
+}
+
 pub(crate) fn stream_deserialize<T, R>(reader: R) -> impl Iterator<Item = Result<T, FormatError>>
 where
     T: DeserializeOwned,
     R: Read,
 {
-    serde_json::Deserializer::from_reader(reader)
-        .into_iter::<T>()
-        .map(|res| res.map_err(|e| FormatError::Serde(Box::new(e))))
+    let mut reader = std::io::BufReader::new(reader);
+
+    if peek_first_significant_byte(&mut reader) == Some(b'[') {
+        let items = match stream_array_elements::<T, _>(reader) {
+            Ok(items) => items.into_iter().map(Ok).collect::<Vec<_>>(),
+            Err(e) => vec![Err(e)],
+        };
+        Either::Left(items.into_iter())
+    } else {
+        Either::Right(
+            serde_json::Deserializer::from_reader(reader)
+                .into_iter::<T>()
+                .map(|res| res.map_err(|e| FormatError::Serde(Box::new(e)))),
+        )
+    }
 }
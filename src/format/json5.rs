@@ -0,0 +1,27 @@
+//! JSON5 format implementation: an ECMAScript 5.1-compatible JSON superset
+//! (unquoted keys, trailing commas, comments, single-quoted strings) commonly
+//! used for hand-edited config files.
+
+use serde::{Serialize, de::DeserializeOwned};
+
+use super::FormatError;
+
+pub(crate) fn deserialize<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, FormatError> {
+    let s = String::from_utf8_lossy(bytes);
+    json5::from_str(&s).map_err(|e| FormatError::Serde(Box::new(e)))
+}
+
+pub(crate) fn serialize<T: Serialize>(value: &T) -> Result<Vec<u8>, FormatError> {
+    json5::to_string(value)
+        .map(|s| s.into_bytes())
+        .map_err(|e| FormatError::Serde(Box::new(e)))
+}
+
+/// The `json5` crate has no compact/pretty or key-order knobs, so `options`
+/// is ignored.
+pub(crate) fn serialize_with_options<T: Serialize>(
+    value: &T,
+    _options: &super::OutputOptions,
+) -> Result<Vec<u8>, FormatError> {
+    serialize(value)
+}
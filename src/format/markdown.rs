@@ -2,42 +2,110 @@ use serde::{Serialize, de::DeserializeOwned};
 
 use super::FormatError;
 
-fn extract_code_block(content: &str, lang: &str) -> Option<String> {
-    let fence_start = format!("```{}", lang);
-    let fence_end = "```";
+/// Scan every fenced code block in `content`, returning its language tag
+/// (the text right after the opening ` ``` `, up to the end of that line)
+/// paired with the block's body. Advances past each closing fence in turn so
+/// later blocks are found too, unlike a single `find` for one tag.
+fn extract_all_code_blocks(content: &str) -> Vec<(String, String)> {
+    let mut blocks = Vec::new();
+    let mut rest = content;
 
-    let start_idx = content.find(&fence_start)?;
-    let content_start = start_idx + fence_start.len();
-    let remaining = &content[content_start..];
+    while let Some(start_idx) = rest.find("```") {
+        let after_fence = &rest[start_idx + 3..];
+        let Some(newline_idx) = after_fence.find('\n') else {
+            break;
+        };
+        let lang = after_fence[..newline_idx].trim().to_string();
+        let body = &after_fence[newline_idx + 1..];
+        let Some(end_idx) = body.find("```") else {
+            break;
+        };
+        blocks.push((lang, body[..end_idx].trim_end().to_string()));
+        rest = &body[end_idx + 3..];
+    }
 
-    let content_start = if remaining.starts_with('\n') {
-        content_start + 1
-    } else {
-        content_start
-    };
+    blocks
+}
 
-    let remaining = &content[content_start..];
-    let end_idx = remaining.find(fence_end)?;
+/// Extract a leading YAML frontmatter section delimited by `---` lines (the
+/// Jekyll/Hugo convention), if `content` starts with one.
+#[cfg(feature = "yaml")]
+fn extract_frontmatter(content: &str) -> Option<&str> {
+    let rest = content.strip_prefix("---\n")?;
+    let end = rest.find("\n---")?;
+    Some(rest[..end].trim_end())
+}
 
-    Some(remaining[..end_idx].trim_end().to_string())
+/// Merge `incoming` into `base`: object keys from `incoming` override
+/// same-named keys already in `base` (and are added if new), arrays
+/// concatenate, and any other pairing (mismatched shapes, scalars) is simply
+/// replaced by `incoming` - later fenced blocks are meant to extend or
+/// override earlier ones, not fail outright on a shape mismatch.
+fn merge_values(base: serde_json::Value, incoming: serde_json::Value) -> serde_json::Value {
+    match (base, incoming) {
+        (serde_json::Value::Object(mut a), serde_json::Value::Object(b)) => {
+            a.extend(b);
+            serde_json::Value::Object(a)
+        }
+        (serde_json::Value::Array(mut a), serde_json::Value::Array(b)) => {
+            a.extend(b);
+            serde_json::Value::Array(a)
+        }
+        (_, incoming) => incoming,
+    }
 }
 
 pub(crate) fn deserialize<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, FormatError> {
     let content = String::from_utf8_lossy(bytes);
+    let mut merged: Option<serde_json::Value> = None;
 
-    #[cfg(feature = "json")]
-    if let Some(json_content) = extract_code_block(&content, "json") {
-        return serde_json::from_str(&json_content).map_err(|e| FormatError::Serde(Box::new(e)));
+    #[cfg(feature = "yaml")]
+    if let Some(frontmatter) = extract_frontmatter(&content) {
+        let value: serde_json::Value =
+            serde_yaml::from_str(frontmatter).map_err(|e| FormatError::Serde(Box::new(e)))?;
+        merged = Some(value);
     }
 
-    #[cfg(feature = "yaml")]
-    if let Some(yaml_content) = extract_code_block(&content, "yaml") {
-        return serde_yaml::from_str(&yaml_content).map_err(|e| FormatError::Serde(Box::new(e)));
+    for (lang, block) in extract_all_code_blocks(&content) {
+        let parsed: Option<serde_json::Value> = match lang.as_str() {
+            #[cfg(feature = "json")]
+            "json" => {
+                Some(serde_json::from_str(&block).map_err(|e| FormatError::Serde(Box::new(e)))?)
+            }
+            #[cfg(feature = "yaml")]
+            "yaml" => {
+                Some(serde_yaml::from_str(&block).map_err(|e| FormatError::Serde(Box::new(e)))?)
+            }
+            // Fences in any other (or unrecognized) language aren't data
+            // blocks as far as this format is concerned - a ` ```rust ` or
+            // ` ```bash ` example embedded in the same document is left
+            // alone.
+            _ => None,
+        };
+
+        if let Some(parsed) = parsed {
+            merged = Some(match merged.take() {
+                Some(base) => merge_values(base, parsed),
+                None => parsed,
+            });
+        }
     }
 
-    let deserializer =
-        serde::de::value::StringDeserializer::<serde::de::value::Error>::new(content.into_owned());
-    T::deserialize(deserializer).map_err(|e| FormatError::Serde(Box::new(e)))
+    match merged {
+        Some(value) => serde_path_to_error::deserialize(value).map_err(|e| {
+            if e.path().to_string() == "." {
+                FormatError::Serde(Box::new(e.into_inner()))
+            } else {
+                super::path_tracking_error(e)
+            }
+        }),
+        None => {
+            let deserializer = serde::de::value::StringDeserializer::<serde::de::value::Error>::new(
+                content.into_owned(),
+            );
+            T::deserialize(deserializer).map_err(|e| FormatError::Serde(Box::new(e)))
+        }
+    }
 }
 
 pub(crate) fn serialize<T: Serialize>(value: &T) -> Result<Vec<u8>, FormatError> {
@@ -56,3 +124,79 @@ pub(crate) fn serialize<T: Serialize>(value: &T) -> Result<Vec<u8>, FormatError>
         ))))
     }
 }
+
+/// Split a top-level JSON object into its scalar fields (destined for
+/// frontmatter) and its object/array-valued fields (destined for the payload
+/// block); anything that isn't a top-level object (a bare scalar or array
+/// value) has no scalar fields to pull out, so it becomes the whole payload.
+#[cfg(all(feature = "json", feature = "yaml"))]
+fn split_frontmatter_and_payload(
+    value: serde_json::Value,
+) -> (serde_json::Map<String, serde_json::Value>, serde_json::Value) {
+    match value {
+        serde_json::Value::Object(map) => {
+            let mut frontmatter = serde_json::Map::new();
+            let mut payload = serde_json::Map::new();
+            for (key, val) in map {
+                match val {
+                    serde_json::Value::Object(_) | serde_json::Value::Array(_) => {
+                        payload.insert(key, val);
+                    }
+                    scalar => {
+                        frontmatter.insert(key, scalar);
+                    }
+                }
+            }
+            (frontmatter, serde_json::Value::Object(payload))
+        }
+        other => (serde_json::Map::new(), other),
+    }
+}
+
+/// Emit `value` as YAML frontmatter (its scalar top-level fields) followed by
+/// a single ` ```json ` block (its object/array-valued fields), rather than
+/// one monolithic block containing the whole value.
+#[cfg(all(feature = "json", feature = "yaml"))]
+fn serialize_structured<T: Serialize>(value: &T) -> Result<Vec<u8>, FormatError> {
+    let json_value = serde_json::to_value(value).map_err(|e| FormatError::Serde(Box::new(e)))?;
+    let (frontmatter, payload) = split_frontmatter_and_payload(json_value);
+
+    let mut out = String::new();
+
+    if !frontmatter.is_empty() {
+        let frontmatter_yaml = serde_yaml::to_string(&serde_json::Value::Object(frontmatter))
+            .map_err(|e| FormatError::Serde(Box::new(e)))?;
+        out.push_str("---\n");
+        out.push_str(frontmatter_yaml.trim_end());
+        out.push_str("\n---\n");
+    }
+
+    let payload_is_empty = matches!(&payload, serde_json::Value::Object(map) if map.is_empty());
+    if !payload_is_empty {
+        if !out.is_empty() {
+            out.push('\n');
+        }
+        let payload_json =
+            serde_json::to_string_pretty(&payload).map_err(|e| FormatError::Serde(Box::new(e)))?;
+        out.push_str(&format!("```json\n{}\n```", payload_json));
+    }
+
+    Ok(out.into_bytes())
+}
+
+/// The embedded JSON/YAML is always pretty-printed regardless of `options`,
+/// so only `options.markdown_frontmatter` (split into frontmatter plus a
+/// payload block vs. one monolithic block) is honored here.
+pub(crate) fn serialize_with_options<T: Serialize>(
+    value: &T,
+    options: &super::OutputOptions,
+) -> Result<Vec<u8>, FormatError> {
+    #[cfg(all(feature = "json", feature = "yaml"))]
+    if options.markdown_frontmatter {
+        return serialize_structured(value);
+    }
+    #[cfg(not(all(feature = "json", feature = "yaml")))]
+    let _ = options;
+
+    serialize(value)
+}
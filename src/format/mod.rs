@@ -6,24 +6,52 @@
 //! - `FormatRegistry`: Registry managing formats by kind
 //! - `CustomFormat`: Support for user-defined custom formats
 
-use std::io::Read;
+use std::collections::HashMap;
+use std::io::{Read, Write};
+use std::sync::Arc;
 
 use paste::paste;
 
 mod custom;
 pub use custom::CustomFormat;
 
+mod detect;
+
+mod format_trait;
+use format_trait::BuiltinHandler;
+pub use format_trait::Format;
+
+mod options;
+pub use options::{KeyOrder, OutputOptions, OutputStyle};
+
+#[cfg(feature = "preserve_order")]
+mod ordered_value;
+#[cfg(feature = "preserve_order")]
+pub use ordered_value::OrderedValue;
+
 // Per-format implementations
+#[cfg(feature = "cbor")]
+mod cbor;
 #[cfg(feature = "csv")]
 mod csv;
+#[cfg(feature = "csv")]
+pub use csv::CsvOptions;
 #[cfg(feature = "ini")]
 mod ini;
 #[cfg(feature = "json")]
 mod json;
+#[cfg(feature = "json5")]
+mod json5;
 #[cfg(feature = "markdown")]
 mod markdown;
+#[cfg(feature = "ndjson")]
+mod ndjson;
 #[cfg(feature = "plaintext")]
 mod plaintext;
+#[cfg(feature = "preserves")]
+mod preserves;
+#[cfg(feature = "ron")]
+mod ron;
 #[cfg(feature = "toml")]
 mod toml;
 #[cfg(feature = "xml")]
@@ -39,13 +67,21 @@ pub enum FormatKind {
     Json,
     Yaml,
     Toml,
+    Ron,
+    Json5,
     Csv,
     Xml,
     Ini,
     Markdown,
+    Preserves,
     /// Custom format with a unique name
     Custom(&'static str),
     Plaintext,
+    /// Newline-delimited JSON (JSON Lines): one compact JSON value per line.
+    Ndjson,
+    /// CBOR (Concise Binary Object Representation): a compact binary
+    /// encoding, useful where JSON/YAML's text overhead is unwelcome.
+    Cbor,
 }
 
 impl Copy for FormatKind {}
@@ -61,11 +97,16 @@ macro_rules! format_spec {
             (Structured, Json,      "json",      json,      "json",      ["json"],            ["json"])
             (Structured, Yaml,      "yaml",      yaml,      "yaml",      ["yaml", "yml"],    ["yaml", "yml"])
             (Structured, Toml,      "toml",      toml,      "toml",      ["toml"],            ["toml"])
+            (Structured, Ron,       "ron",       ron,       "ron",       ["ron"],             ["ron"])
+            (Structured, Json5,     "json5",     json5,     "json5",     ["json5"],           ["json5"])
             (Structured, Ini,       "ini",       ini,       "ini",       ["ini"],             ["ini"])
+            (Structured, Preserves, "preserves", preserves, "preserves", ["pr", "prs", "pre"], ["preserves", "pr", "prs", "pre"])
             (Other,      Csv,       "csv",       csv,       "csv",       ["csv"],             ["csv"])
             (Other,      Xml,       "xml",       xml,       "xml",       ["xml"],             ["xml"])
             (Other,      Markdown,  "markdown",  markdown,  "markdown",  ["md", "markdown"], ["markdown", "md"])
             (Other,      Plaintext, "plaintext", plaintext, "plaintext", ["txt", "text"],    ["plaintext", "text", "txt"])
+            (Other,      Ndjson,    "ndjson",    ndjson,    "ndjson",    ["ndjson", "jsonl"], ["ndjson", "jsonl"])
+            (Other,      Cbor,      "cbor",      cbor,      "cbor",      ["cbor"],            ["cbor"])
         }
     };
 
@@ -74,11 +115,16 @@ macro_rules! format_spec {
             (Structured, Json,      "json",      json,      "json",      ["json"],            ["json"])
             (Structured, Yaml,      "yaml",      yaml,      "yaml",      ["yaml", "yml"],    ["yaml", "yml"])
             (Structured, Toml,      "toml",      toml,      "toml",      ["toml"],            ["toml"])
+            (Structured, Ron,       "ron",       ron,       "ron",       ["ron"],             ["ron"])
+            (Structured, Json5,     "json5",     json5,     "json5",     ["json5"],           ["json5"])
             (Structured, Ini,       "ini",       ini,       "ini",       ["ini"],             ["ini"])
+            (Structured, Preserves, "preserves", preserves, "preserves", ["pr", "prs", "pre"], ["preserves", "pr", "prs", "pre"])
             (Other,      Csv,       "csv",       csv,       "csv",       ["csv"],             ["csv"])
             (Other,      Xml,       "xml",       xml,       "xml",       ["xml"],             ["xml"])
             (Other,      Markdown,  "markdown",  markdown,  "markdown",  ["md", "markdown"], ["markdown", "md"])
             (Other,      Plaintext, "plaintext", plaintext, "plaintext", ["txt", "text"],    ["plaintext", "text", "txt"])
+            (Other,      Ndjson,    "ndjson",    ndjson,    "ndjson",    ["ndjson", "jsonl"], ["ndjson", "jsonl"])
+            (Other,      Cbor,      "cbor",      cbor,      "cbor",      ["cbor"],            ["cbor"])
         }
     };
 }
@@ -254,15 +300,167 @@ pub enum FormatError {
     #[error("Format '{0}' is not enabled. Enable the corresponding feature.")]
     NotEnabled(FormatKind),
 
+    /// The format has no incremental (record-at-a-time) representation, so
+    /// streaming decode/encode can't be done; callers should fall back to the
+    /// buffered, whole-document path instead.
+    #[error("Streaming is not supported for format '{0}'")]
+    StreamingUnsupported(FormatKind),
+
     #[error("I/O error: {0}")]
     Io(#[from] std::io::Error),
 
     #[error("Serde error: {0}")]
     Serde(Box<dyn std::error::Error + Send + Sync>),
 
+    /// A parse error for which the underlying library exposed a location
+    /// within the input (currently produced by the JSON, YAML, and TOML
+    /// whole-document decoders). Carries a clone of the offending input
+    /// bytes alongside the byte `span` so that, under the `miette` feature,
+    /// the report can render a labeled underline over the exact region
+    /// instead of a flat "invalid type at line N" string.
+    #[error("Serde error: {source}")]
+    SerdeSpanned {
+        source: Box<dyn std::error::Error + Send + Sync>,
+        /// Byte offset and length of the offending region in `input`.
+        span: (usize, usize),
+        input: std::sync::Arc<[u8]>,
+    },
+
+    /// A parse error that failed inside a nested field or array element
+    /// rather than at the document root, carrying the dotted/bracketed path
+    /// to the offending spot (e.g. `records[3].value`) instead of just the
+    /// underlying library's flat "invalid type at line N" message. Produced
+    /// by [`path_tracking_error`] wrapping a [`serde_path_to_error::Error`];
+    /// see its call sites in the JSON, YAML, and CSV modules, plus
+    /// [`CustomFormat::deserialize`].
+    #[error("{path}: {source}")]
+    SerdeAt {
+        path: String,
+        source: Box<dyn std::error::Error + Send + Sync>,
+    },
+
     /// Other format-specific error
     #[error("Format error: {0}")]
     Other(Box<dyn std::error::Error + Send + Sync>),
+
+    /// A payload that failed to resolve into a value of the shape the caller
+    /// wanted, annotated with which [`FormatKind`] was involved and a
+    /// [`PayloadErrorKind`] classification, following MeiliSearch's
+    /// `DocumentFormatError`. `context` is a bounded preview of the
+    /// offending payload (see [`truncate_context`]) rather than the full
+    /// input, so a malformed multi-megabyte payload doesn't get dumped whole
+    /// into the error message.
+    #[error("{kind} payload error ({classification}): {message} (near: {context})")]
+    MalformedPayload {
+        kind: FormatKind,
+        classification: PayloadErrorKind,
+        message: String,
+        context: String,
+    },
+}
+
+/// Classification carried by [`FormatError::MalformedPayload`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PayloadErrorKind {
+    /// The payload parsed fine as the underlying format, but didn't have the
+    /// shape this operation expected (e.g. CSV asked to serialize a bare
+    /// number instead of an object or array of objects).
+    DataShape,
+    /// The payload failed to parse as the underlying format at all: a
+    /// syntax error, truncated input, or I/O failure while reading it.
+    Syntax,
+}
+
+impl std::fmt::Display for PayloadErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PayloadErrorKind::DataShape => write!(f, "data shape"),
+            PayloadErrorKind::Syntax => write!(f, "syntax"),
+        }
+    }
+}
+
+/// Build a bounded preview of a payload for [`FormatError::MalformedPayload`]:
+/// short inputs are shown in full, longer ones keep ~50 leading and ~85
+/// trailing chars with the middle elided as `...`, so the error message
+/// stays readable even for a multi-megabyte input.
+pub(crate) fn truncate_context(bytes: &[u8]) -> String {
+    const LEAD: usize = 50;
+    const TRAIL: usize = 85;
+
+    let text = String::from_utf8_lossy(bytes);
+    let chars: Vec<char> = text.chars().collect();
+
+    if chars.len() <= LEAD + TRAIL {
+        return chars.into_iter().collect();
+    }
+
+    let lead: String = chars[..LEAD].iter().collect();
+    let trail: String = chars[chars.len() - TRAIL..].iter().collect();
+    format!("{lead}...{trail}")
+}
+
+impl FormatError {
+    /// The byte offset and length of the offending region in the original
+    /// input, when known. Only set for [`FormatError::SerdeSpanned`].
+    pub fn span(&self) -> Option<(usize, usize)> {
+        match self {
+            FormatError::SerdeSpanned { span, .. } => Some(*span),
+            _ => None,
+        }
+    }
+
+    /// The original input bytes that `span` refers to, when a span is
+    /// present.
+    pub fn source_bytes(&self) -> Option<&std::sync::Arc<[u8]>> {
+        match self {
+            FormatError::SerdeSpanned { input, .. } => Some(input),
+            _ => None,
+        }
+    }
+}
+
+/// Converts a [`serde_path_to_error::Error`] into a [`FormatError::SerdeAt`],
+/// joining the segments `serde_path_to_error` recorded while descending into
+/// the value (one per map key or sequence index visited before the failure)
+/// into the conventional dotted/bracketed form, e.g. `records[3].value`.
+///
+/// `serde_path_to_error` still reports a path for errors that happen before
+/// any segment is pushed (a malformed document that fails at the root), just
+/// an empty one that `Display`s as `"."`; callers with a more specific
+/// root-level error representation of their own (a byte span, an existing
+/// `MalformedPayload`, ...) should check for that case and use it instead of
+/// calling this function.
+pub(crate) fn path_tracking_error<E>(err: serde_path_to_error::Error<E>) -> FormatError
+where
+    E: std::error::Error + Send + Sync + 'static,
+{
+    FormatError::SerdeAt {
+        path: err.path().to_string(),
+        source: Box::new(err.into_inner()),
+    }
+}
+
+/// Best-effort conversion from a 1-based (line, column) location - as
+/// reported by `serde_json::Error` - to a byte offset into `bytes`. Columns
+/// are assumed to be byte-counted, so multi-byte UTF-8 content earlier on the
+/// same line may shift the computed offset slightly; this is meant for
+/// pointing a diagnostic at roughly the right place, not byte-exact slicing.
+pub(crate) fn line_col_to_offset(bytes: &[u8], line: usize, column: usize) -> usize {
+    let mut line_start = 0usize;
+    let mut current_line = 1usize;
+    if current_line < line {
+        for (i, &b) in bytes.iter().enumerate() {
+            if b == b'\n' {
+                current_line += 1;
+                if current_line == line {
+                    line_start = i + 1;
+                    break;
+                }
+            }
+        }
+    }
+    (line_start + column.saturating_sub(1)).min(bytes.len())
 }
 
 // Projection: body for top-level `deserialize` function.
@@ -301,15 +499,151 @@ macro_rules! impl_serialize_body {
     }};
 }
 
+// Projection: body for top-level `serialize_with_options` function.
+macro_rules! impl_serialize_with_options_body {
+    ($value:ident, $kind:ident, $options:ident
+        $(($cat:ident, $fmt_kind:ident, $feat:literal, $module:ident,
+           $display:literal, [$($ext:literal),*], [$($alias:literal),*]))*
+    ) => {{
+        match $kind {
+            $(
+                #[cfg(feature = $feat)]
+                FormatKind::$fmt_kind => $module::serialize_with_options($value, $options),
+            )*
+
+            #[allow(unreachable_patterns)]
+            _ => Err(FormatError::NotEnabled($kind)),
+        }
+    }};
+}
+
 pub fn deserialize<T: DeserializeOwned>(kind: FormatKind, bytes: &[u8]) -> Result<T, FormatError> {
     format_spec!(impl_deserialize_body(bytes, kind))
 }
 
+/// Strip `//` line comments, `/* */` block comments, and trailing commas from
+/// `input`, tracking string-literal context (quotes and backslash escapes) the
+/// same way `cli::sarge::split_repeatable_values` does, so a `//` or `,`
+/// inside a quoted value is left untouched.
+///
+/// This turns a hand-edited "JSONC" document into strict JSON text; it's a
+/// standalone text transform so custom formats can reuse it too.
+pub fn strip_jsonc_comments(input: &str) -> String {
+    strip_trailing_commas(&strip_comments(input))
+}
+
+fn strip_comments(input: &str) -> String {
+    let mut out = String::with_capacity(input.len());
+    let mut chars = input.chars().peekable();
+    let mut in_string = false;
+
+    while let Some(ch) = chars.next() {
+        if in_string {
+            out.push(ch);
+            match ch {
+                '\\' => {
+                    if let Some(next) = chars.next() {
+                        out.push(next);
+                    }
+                }
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+
+        match ch {
+            '"' => {
+                in_string = true;
+                out.push(ch);
+            }
+            '/' if matches!(chars.peek(), Some('/')) => {
+                chars.next();
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        out.push('\n');
+                        break;
+                    }
+                }
+            }
+            '/' if matches!(chars.peek(), Some('*')) => {
+                chars.next();
+                let mut prev = '\0';
+                for c in chars.by_ref() {
+                    if prev == '*' && c == '/' {
+                        break;
+                    }
+                    prev = c;
+                }
+            }
+            _ => out.push(ch),
+        }
+    }
+
+    out
+}
+
+fn strip_trailing_commas(input: &str) -> String {
+    let mut out = String::with_capacity(input.len());
+    let mut chars = input.chars().peekable();
+    let mut in_string = false;
+
+    while let Some(ch) = chars.next() {
+        if in_string {
+            out.push(ch);
+            match ch {
+                '\\' => {
+                    if let Some(next) = chars.next() {
+                        out.push(next);
+                    }
+                }
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+
+        match ch {
+            '"' => {
+                in_string = true;
+                out.push(ch);
+            }
+            ',' => {
+                let next_significant = chars.clone().find(|c| !c.is_whitespace());
+                if !matches!(next_significant, Some('}') | Some(']')) {
+                    out.push(',');
+                }
+            }
+            _ => out.push(ch),
+        }
+    }
+
+    out
+}
+
+/// Deserialize JSONC (JSON with `//`/`/* */` comments and trailing commas)
+/// from bytes. Strict `deserialize(FormatKind::Json, ..)` is left untouched
+/// for data interchange; this path exists for hand-edited config files.
+#[cfg(feature = "json")]
+pub fn deserialize_jsonc<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, FormatError> {
+    json::deserialize_jsonc(bytes)
+}
+
 /// Serialize to bytes using the specified format.
 pub fn serialize<T: Serialize>(kind: FormatKind, value: &T) -> Result<Vec<u8>, FormatError> {
     format_spec!(impl_serialize_body(value, kind))
 }
 
+/// Serialize to bytes using the specified format, honoring `options` where
+/// the underlying serializer supports the requested knob.
+pub fn serialize_with_options<T: Serialize>(
+    kind: FormatKind,
+    value: &T,
+    options: &OutputOptions,
+) -> Result<Vec<u8>, FormatError> {
+    format_spec!(impl_serialize_with_options_body(value, kind, options))
+}
+
 /// Deserialize from a reader using the specified format.
 pub fn deserialize_from_reader<T: DeserializeOwned>(
     kind: FormatKind,
@@ -365,7 +699,11 @@ macro_rules! define_stream_deserialize_fn_read_static {
 }
 
 define_stream_deserialize_fn_read!(
-    /// Stream JSON values from a reader as multiple top-level JSON documents.
+    /// Stream JSON values from a reader. A top-level `[...]` array streams
+    /// its elements directly off the reader via a `Visitor`, without
+    /// building an intermediate `serde_json::Value` tree for the whole
+    /// array; anything else is treated as one or more whitespace-separated
+    /// top-level documents (a lone object counts as a one-element stream).
     ["json"]
     json
 );
@@ -388,6 +726,41 @@ define_stream_deserialize_fn_read!(
     plaintext
 );
 
+define_stream_deserialize_fn_read!(
+    /// Stream NDJSON records from a reader, one JSON value per line.
+    ["ndjson"]
+    ndjson
+);
+
+define_stream_deserialize_fn_read_static!(
+    /// Stream Preserves values from a reader, one top-level value per
+    /// record, auto-detecting the text vs. binary syntax from the leading
+    /// byte.
+    ["preserves"]
+    preserves
+);
+
+define_stream_deserialize_fn_read!(
+    /// Stream concatenated top-level CBOR values from a reader, one per
+    /// record.
+    ["cbor"]
+    cbor
+);
+
+/// Confidence that a blob of bytes is actually the format it was
+/// successfully parsed as, as returned by [`FormatRegistry::detect_format`].
+/// Ordered so the most confident candidate sorts first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Confidence {
+    /// Parsed successfully into the format's generic value type, but the raw
+    /// text carries no syntactic tell specific to that format.
+    Medium,
+    /// Parsed successfully and the raw text also carries that format's own
+    /// syntactic tell (a leading `{`/`[`/`---`, a `key: value` line, a
+    /// `key=value` line).
+    High,
+}
+
 /// Format registry.
 #[derive(Default)]
 pub struct FormatRegistry {
@@ -395,6 +768,14 @@ pub struct FormatRegistry {
     formats: Vec<FormatKind>,
     /// Custom format handlers
     custom_formats: Vec<CustomFormat>,
+    /// `Format` handlers keyed by kind, consulted by `stream_deserialize_into`.
+    /// `register`/`register_custom` populate this automatically; `register_handler`
+    /// lets a caller override a kind's handler (including a builtin one) directly.
+    handlers: HashMap<FormatKind, Arc<dyn Format>>,
+    /// Whether `deserialize_document` lands in [`OrderedValue`] (insertion
+    /// order preserved) rather than `serde_json::Value`.
+    #[cfg(feature = "preserve_order")]
+    preserve_order: bool,
 }
 
 impl FormatRegistry {
@@ -403,6 +784,9 @@ impl FormatRegistry {
         Self {
             formats: Vec::new(),
             custom_formats: Vec::new(),
+            handlers: HashMap::new(),
+            #[cfg(feature = "preserve_order")]
+            preserve_order: false,
         }
     }
 
@@ -411,6 +795,9 @@ impl FormatRegistry {
         if !self.formats.contains(&kind) {
             self.formats.push(kind);
         }
+        self.handlers
+            .entry(kind)
+            .or_insert_with(|| Arc::new(BuiltinHandler(kind)));
     }
 
     /// Register a built-in format (builder pattern).
@@ -419,6 +806,21 @@ impl FormatRegistry {
         self
     }
 
+    /// Register a `Format` handler for `kind`, overriding whatever handler
+    /// (builtin or custom) was previously registered under it.
+    pub fn register_handler(&mut self, kind: FormatKind, handler: Arc<dyn Format>) {
+        if !self.formats.contains(&kind) {
+            self.formats.push(kind);
+        }
+        self.handlers.insert(kind, handler);
+    }
+
+    /// Register a `Format` handler for `kind` (builder pattern).
+    pub fn with_handler(mut self, kind: FormatKind, handler: Arc<dyn Format>) -> Self {
+        self.register_handler(kind, handler);
+        self
+    }
+
     /// Register a custom format handler.
     ///
     /// # Example
@@ -445,6 +847,7 @@ impl FormatRegistry {
         if !self.formats.contains(&kind) {
             self.formats.push(kind);
         }
+        self.handlers.insert(kind, Arc::new(format.clone()));
         self.custom_formats.push(format);
     }
 
@@ -509,6 +912,208 @@ impl FormatRegistry {
         Err(FormatError::NoFormatMatched)
     }
 
+    /// Rank the registered, available structured-text formats that can parse
+    /// `bytes`, most confident first.
+    ///
+    /// A format is included only if decoding it into its generic value type
+    /// (`serde_json::Value`, `serde_yaml::Value`, `toml::Value`) succeeds;
+    /// [`Confidence::High`] additionally requires the raw text to carry that
+    /// format's own syntactic tell (see the `detect` module), so e.g. a bare
+    /// number that also happens to parse as YAML doesn't outrank an object
+    /// with an actual leading `{`. XML has no such generic value type, so it
+    /// is included whenever `bytes` at least tokenizes to the end as
+    /// well-formed XML; CBOR decodes straight into `serde_json::Value` since
+    /// its deserializer is self-describing like JSON's.
+    ///
+    /// Formats with no generic value type and no cheap structural signal
+    /// (INI, Markdown, Preserves, and anything else format-specific) are
+    /// never returned here; resolve those via the ordinary `format_candidates`
+    /// trial order instead. Custom formats participate too: a registered
+    /// format is included whenever its `deserialize_fn` succeeds, ranked
+    /// `Confidence::High` if it also has a `sniff_fn` (see
+    /// [`CustomFormat::with_sniff`]) that returns `true`.
+    pub fn detect_format(&self, bytes: &[u8]) -> Vec<(FormatKind, Confidence)> {
+        let s = String::from_utf8_lossy(bytes);
+        let mut out = Vec::new();
+
+        #[cfg(feature = "json")]
+        if self.has_format(&FormatKind::Json)
+            && FormatKind::Json.is_available()
+            && serde_json::from_str::<serde_json::Value>(&s).is_ok()
+        {
+            let confidence = if detect::json_signal(&s) {
+                Confidence::High
+            } else {
+                Confidence::Medium
+            };
+            out.push((FormatKind::Json, confidence));
+        }
+
+        #[cfg(feature = "yaml")]
+        if self.has_format(&FormatKind::Yaml)
+            && FormatKind::Yaml.is_available()
+            && serde_yaml::from_str::<serde_yaml::Value>(&s).is_ok()
+        {
+            let confidence = if detect::yaml_signal(&s) {
+                Confidence::High
+            } else {
+                Confidence::Medium
+            };
+            out.push((FormatKind::Yaml, confidence));
+        }
+
+        #[cfg(feature = "toml")]
+        if self.has_format(&FormatKind::Toml)
+            && FormatKind::Toml.is_available()
+            && ::toml::from_str::<::toml::Value>(&s).is_ok()
+        {
+            let confidence = if detect::toml_signal(&s) {
+                Confidence::High
+            } else {
+                Confidence::Medium
+            };
+            out.push((FormatKind::Toml, confidence));
+        }
+
+        #[cfg(feature = "xml")]
+        if self.has_format(&FormatKind::Xml) && FormatKind::Xml.is_available() {
+            // quick_xml has no generic `Value` type to decode into, so
+            // "parses" here means "tokenizes to the end without an error"
+            // rather than a full typed deserialize.
+            let mut reader = quick_xml::Reader::from_str(&s);
+            let mut buf = Vec::new();
+            let well_formed = loop {
+                match reader.read_event_into(&mut buf) {
+                    Ok(quick_xml::events::Event::Eof) => break true,
+                    Ok(_) => buf.clear(),
+                    Err(_) => break false,
+                }
+            };
+            if well_formed {
+                let confidence = if detect::xml_signal(&s) {
+                    Confidence::High
+                } else {
+                    Confidence::Medium
+                };
+                out.push((FormatKind::Xml, confidence));
+            }
+        }
+
+        #[cfg(feature = "cbor")]
+        if self.has_format(&FormatKind::Cbor)
+            && FormatKind::Cbor.is_available()
+            && ciborium::de::from_reader::<serde_json::Value, _>(bytes).is_ok()
+        {
+            let confidence = if detect::cbor_signal(bytes) {
+                Confidence::High
+            } else {
+                Confidence::Medium
+            };
+            out.push((FormatKind::Cbor, confidence));
+        }
+
+        for custom in &self.custom_formats {
+            let Some(deserialize_fn) = custom.deserialize_fn.as_ref() else {
+                continue;
+            };
+            if deserialize_fn(bytes).is_ok() {
+                let confidence = match &custom.sniff_fn {
+                    Some(sniff) if sniff(bytes) => Confidence::High,
+                    _ => Confidence::Medium,
+                };
+                out.push((FormatKind::Custom(custom.name), confidence));
+            }
+        }
+
+        out.sort_by(|a, b| b.1.cmp(&a.1));
+        out
+    }
+
+    /// The single most-confident format `detect_format` finds for `bytes`,
+    /// or `None` if nothing registered parses it. A thin convenience over
+    /// `detect_format` for callers that just want "what format is this?"
+    /// without weighing it against a candidate list.
+    pub fn detect(&self, bytes: &[u8]) -> Option<FormatKind> {
+        self.detect_format(bytes).into_iter().next().map(|(k, _)| k)
+    }
+
+    /// Resolve a format the same way [`Self::resolve`] does, but when there's
+    /// no explicit format, let [`Self::detect_format`]'s confidence ranking of
+    /// `bytes` reorder `candidates`: the highest-confidence detected format
+    /// that's also present in `candidates` is tried first, instead of
+    /// candidates' own declaration order. Falls back to `resolve`'s original
+    /// order when sniffing finds no match among `candidates` (e.g. for
+    /// formats `detect_format` doesn't cover, like CSV or INI).
+    pub fn resolve_with_sniffing(
+        &self,
+        explicit: Option<&FormatKind>,
+        candidates: &[FormatKind],
+        bytes: &[u8],
+    ) -> Result<FormatKind, FormatError> {
+        if explicit.is_none() {
+            for (kind, _confidence) in self.detect_format(bytes) {
+                if candidates.contains(&kind) && self.has_format(&kind) && kind.is_available() {
+                    return Ok(kind);
+                }
+            }
+        }
+
+        self.resolve(explicit, candidates)
+    }
+
+    /// Set whether [`Self::deserialize_document`] should land in
+    /// [`OrderedValue`] (builder pattern). See [`OrderedValue`] for why this
+    /// is a dedicated document type rather than a flag on the generic
+    /// [`Self::deserialize_value`].
+    #[cfg(feature = "preserve_order")]
+    pub fn with_preserve_order(mut self, yes: bool) -> Self {
+        self.preserve_order = yes;
+        self
+    }
+
+    /// Whether [`Self::deserialize_document`] currently preserves source
+    /// field order.
+    #[cfg(feature = "preserve_order")]
+    pub fn preserve_order(&self) -> bool {
+        self.preserve_order
+    }
+
+    /// Deserialize a schema-less document.
+    ///
+    /// When [`Self::preserve_order`] is set, this lands in [`OrderedValue`],
+    /// whose object variant is always backed by an insertion-ordered map, so
+    /// a read-then-write through this and [`Self::serialize_document`]
+    /// preserves the source document's field order. Otherwise this goes
+    /// through `serde_json::Value` (ordered only if `serde_json` itself was
+    /// compiled with its own `preserve_order` feature; alphabetical
+    /// otherwise) and converts the result into an [`OrderedValue`] shell.
+    #[cfg(feature = "preserve_order")]
+    pub fn deserialize_document(
+        &self,
+        explicit: Option<&FormatKind>,
+        candidates: &[FormatKind],
+        bytes: &[u8],
+    ) -> Result<OrderedValue, FormatError> {
+        if self.preserve_order {
+            self.deserialize_value::<OrderedValue>(explicit, candidates, bytes)
+        } else {
+            self.deserialize_value::<serde_json::Value>(explicit, candidates, bytes)
+                .map(OrderedValue::from)
+        }
+    }
+
+    /// Serialize an [`OrderedValue`] document, preserving whatever field
+    /// order it already carries.
+    #[cfg(feature = "preserve_order")]
+    pub fn serialize_document(
+        &self,
+        explicit: Option<&FormatKind>,
+        candidates: &[FormatKind],
+        value: &OrderedValue,
+    ) -> Result<Vec<u8>, FormatError> {
+        self.serialize_value(explicit, candidates, value)
+    }
+
     /// Get all registered format kinds.
     pub fn formats(&self) -> &[FormatKind] {
         &self.formats
@@ -521,15 +1126,33 @@ impl FormatRegistry {
 
     /// Deserialize using this registry.
     ///
-    /// Automatically handles both built-in and custom formats.
+    /// Automatically handles both built-in and custom formats. When called
+    /// with `explicit: None` and an empty `candidates` (the "I got bytes from
+    /// a pipe and have no idea what format they are" case), and
+    /// [`Self::resolve_with_sniffing`]'s structural signals don't land on a
+    /// format either, falls back to [`Self::deserialize_value_by_trial`]:
+    /// actually trial-deserializing `bytes` as `T` against every registered
+    /// format in [`DEFAULT_FORMAT_ORDER`], returning the first that succeeds.
     pub fn deserialize_value<T: DeserializeOwned>(
         &self,
         explicit: Option<&FormatKind>,
         candidates: &[FormatKind],
         bytes: &[u8],
     ) -> Result<T, FormatError> {
-        let kind = self.resolve(explicit, candidates)?;
+        match self.resolve_with_sniffing(explicit, candidates, bytes) {
+            Ok(kind) => self.deserialize_value_as(kind, bytes),
+            Err(FormatError::NoFormatMatched) if explicit.is_none() && candidates.is_empty() => {
+                self.deserialize_value_by_trial(bytes)
+            }
+            Err(e) => Err(e),
+        }
+    }
 
+    fn deserialize_value_as<T: DeserializeOwned>(
+        &self,
+        kind: FormatKind,
+        bytes: &[u8],
+    ) -> Result<T, FormatError> {
         // Handle custom formats
         if let FormatKind::Custom(name) = &kind {
             let custom = self
@@ -542,6 +1165,34 @@ impl FormatRegistry {
         deserialize(kind, bytes)
     }
 
+    /// Trial-parse `bytes` as `T` against every registered, available format
+    /// in [`DEFAULT_FORMAT_ORDER`], then every registered custom format,
+    /// returning the first that deserializes without error. Unlike
+    /// [`Self::detect_format`] (which only covers the formats it can cheaply
+    /// probe: JSON, YAML, TOML, XML, CBOR, and custom formats), this covers every
+    /// format by attempting the real, typed deserialization `T` asked for —
+    /// a strictly stronger signal, at the cost of running every candidate
+    /// until one fits.
+    fn deserialize_value_by_trial<T: DeserializeOwned>(&self, bytes: &[u8]) -> Result<T, FormatError> {
+        for kind in DEFAULT_FORMAT_ORDER
+            .iter()
+            .copied()
+            .filter(|k| self.has_format(k) && k.is_available())
+        {
+            if let Ok(value) = self.deserialize_value_as(kind, bytes) {
+                return Ok(value);
+            }
+        }
+
+        for custom in &self.custom_formats {
+            if let Ok(value) = custom.deserialize(bytes) {
+                return Ok(value);
+            }
+        }
+
+        Err(FormatError::NoFormatMatched)
+    }
+
     /// Serialize using this registry.
     ///
     /// Automatically handles both built-in and custom formats.
@@ -565,11 +1216,43 @@ impl FormatRegistry {
         serialize(kind, value)
     }
 
+    /// Serialize using this registry, honoring `OutputOptions` (pretty vs.
+    /// compact, indent, key order, and CSV's delimiter/quote/header) where
+    /// the resolved format supports them.
+    ///
+    /// Custom formats ignore `options`, since their serialize function has no
+    /// hook for formatting hints.
+    pub fn serialize_value_with_options<T: Serialize>(
+        &self,
+        explicit: Option<&FormatKind>,
+        candidates: &[FormatKind],
+        value: &T,
+        options: &OutputOptions,
+    ) -> Result<Vec<u8>, FormatError> {
+        let kind = self.resolve(explicit, candidates)?;
+
+        // Handle custom formats
+        if let FormatKind::Custom(name) = &kind {
+            let custom = self
+                .get_custom(name)
+                .ok_or_else(|| FormatError::UnknownFormat(kind))?;
+            return custom.serialize(value);
+        }
+
+        // Handle built-in formats
+        serialize_with_options(kind, value, options)
+    }
+
     /// Stream-deserialize values into `T` using this registry.
     ///
-    /// For built-in JSON/CSV formats, this uses native streaming decoders.
-    /// For custom formats, if a streaming handler is provided it will be used.
-    /// Otherwise, falls back to non-streaming deserialization as a single item.
+    /// Looks up the [`Format`] handler registered for the resolved kind and
+    /// calls [`Format::stream_deserialize`] on it, so builtin formats with a
+    /// native incremental decoder (JSON, CSV, YAML, plaintext, Preserves,
+    /// NDJSON, CBOR) stream record-by-record, custom formats stream through
+    /// whatever decoder they registered via `with_stream_deserialize`, and
+    /// anything else falls back to the trait's default single-item buffered
+    /// implementation. `register_handler` lets a caller swap in its own
+    /// handler for any kind, builtin or custom, ahead of this lookup.
     pub fn stream_deserialize_into<T>(
         &self,
         explicit: Option<&FormatKind>,
@@ -580,84 +1263,51 @@ impl FormatRegistry {
         T: DeserializeOwned + 'static,
     {
         let kind = self.resolve(explicit, candidates)?;
+        let handler = self
+            .handlers
+            .get(&kind)
+            .ok_or_else(|| FormatError::UnknownFormat(kind))?;
+
+        let iter = handler.stream_deserialize(reader)?.map(|res| {
+            res.and_then(|value| {
+                serde_json::from_value::<T>(value).map_err(|e| FormatError::Serde(Box::new(e)))
+            })
+        });
+        Ok(Box::new(iter))
+    }
 
-        if let FormatKind::Json = kind {
-            #[cfg(feature = "json")]
-            {
-                let iter = crate::format::deserialize_json_stream::<T, _>(reader);
-                return Ok(Box::new(iter));
-            }
-            #[cfg(not(feature = "json"))]
-            {
-                return Err(FormatError::NotEnabled(kind));
-            }
-        }
-
-        if let FormatKind::Csv = kind {
-            #[cfg(feature = "csv")]
-            {
-                let iter = crate::format::deserialize_csv_stream::<T, _>(reader);
-                return Ok(Box::new(iter));
-            }
-            #[cfg(not(feature = "csv"))]
-            {
-                return Err(FormatError::NotEnabled(kind));
-            }
-        }
-
-        if let FormatKind::Yaml = kind {
-            #[cfg(feature = "yaml")]
-            {
-                let iter = crate::format::deserialize_yaml_stream::<T, _>(reader);
-                return Ok(Box::new(iter));
-            }
-            #[cfg(not(feature = "yaml"))]
-            {
-                return Err(FormatError::NotEnabled(kind));
-            }
-        }
-
-        if let FormatKind::Plaintext = kind {
-            #[cfg(feature = "plaintext")]
-            {
-                let iter = crate::format::deserialize_plaintext_stream::<T, _>(reader);
-                return Ok(Box::new(iter));
-            }
-            #[cfg(not(feature = "plaintext"))]
-            {
-                return Err(FormatError::NotEnabled(kind));
-            }
-        }
-
-        if let FormatKind::Custom(name) = kind {
-            let custom = self
-                .get_custom(name)
-                .ok_or_else(|| FormatError::UnknownFormat(kind))?;
-
-            if custom.stream_deserialize_fn.is_some() {
-                let iter = custom.stream_deserialize_values(reader)?.map(|res| {
-                    res.and_then(|value| {
-                        serde_json::from_value::<T>(value)
-                            .map_err(|e| FormatError::Serde(Box::new(e)))
-                    })
-                });
-                return Ok(Box::new(iter));
-            } else {
-                // Fallback: non-streaming, single item
-                let mut r = reader;
-                let mut bytes = Vec::new();
-                r.read_to_end(&mut bytes)?;
-                let value = custom.deserialize::<T>(&bytes)?;
-                return Ok(Box::new(std::iter::once(Ok(value))));
-            }
-        }
-
-        // Other built-in formats: fallback to non-streaming, single item
-        let mut r = reader;
-        let mut bytes = Vec::new();
-        r.read_to_end(&mut bytes)?;
-        let value = deserialize::<T>(kind, &bytes)?;
-        Ok(Box::new(std::iter::once(Ok(value))))
+    /// Stream-serialize values from `iter` using this registry, writing each
+    /// record incrementally rather than collecting them into a `Vec` first.
+    ///
+    /// Looks up the [`Format`] handler registered for the resolved kind and
+    /// calls [`Format::stream_serialize`] on it, so builtin formats with a
+    /// native incremental encoder (NDJSON, plaintext, CSV) write one record
+    /// at a time, custom formats stream through whatever encoder they
+    /// registered via `CustomFormat::with_stream_serialize`, and anything
+    /// else falls back to the trait's default behavior of collecting every
+    /// record and making a single [`Self::serialize_value`]-equivalent call.
+    pub fn stream_serialize_from<T>(
+        &self,
+        explicit: Option<&FormatKind>,
+        candidates: &[FormatKind],
+        writer: &mut dyn Write,
+        iter: impl Iterator<Item = Result<T, FormatError>>,
+    ) -> Result<(), FormatError>
+    where
+        T: Serialize,
+    {
+        let kind = self.resolve(explicit, candidates)?;
+        let handler = self
+            .handlers
+            .get(&kind)
+            .ok_or_else(|| FormatError::UnknownFormat(kind))?;
+
+        let values = iter.map(|res| {
+            res.and_then(|value| {
+                serde_json::to_value(&value).map_err(|e| FormatError::Serde(Box::new(e)))
+            })
+        });
+        handler.stream_serialize(Box::new(values), writer)
     }
 }
 
@@ -683,11 +1333,16 @@ mod tests {
                 FormatKind::Json,
                 FormatKind::Yaml,
                 FormatKind::Toml,
+                FormatKind::Ron,
+                FormatKind::Json5,
                 FormatKind::Ini,
+                FormatKind::Preserves,
                 FormatKind::Csv,
                 FormatKind::Xml,
                 FormatKind::Markdown,
                 FormatKind::Plaintext,
+                FormatKind::Ndjson,
+                FormatKind::Cbor,
             ],
         );
     }
@@ -703,7 +1358,26 @@ mod tests {
 
 // Async format support
 #[cfg(feature = "async")]
+mod async_custom;
+#[cfg(feature = "async")]
 mod async_format;
+#[cfg(feature = "async")]
+mod async_stream;
+#[cfg(feature = "async")]
+mod framed_binary;
 
+#[cfg(feature = "async")]
+pub use async_custom::AsyncCustomFormat;
 #[cfg(feature = "async")]
 pub use async_format::*;
+#[cfg(feature = "async")]
+pub use async_stream::{
+    deserialize_stream_from_async_reader, serialize_stream_to_async_writer, AsyncStreamFormat,
+    CsvStreamFormat, JsonStreamFormat, NdjsonStreamFormat,
+};
+#[cfg(all(feature = "async", feature = "plaintext"))]
+pub use async_stream::PlaintextStreamFormat;
+#[cfg(feature = "async")]
+pub use framed_binary::{
+    serialize_framed_stream_to_async_writer, FramedBinaryStreamFormat, DEFAULT_MAX_FRAME_SIZE,
+};
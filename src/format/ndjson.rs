@@ -0,0 +1,94 @@
+//! NDJSON (newline-delimited JSON / JSON Lines) format implementation.
+//!
+//! Each line is an independent, compact JSON value. The whole-document
+//! `deserialize`/`serialize` pair treats the document as an array of lines
+//! (mirroring how `csv` treats rows), while `stream_deserialize` is the
+//! format's natural representation: one record read and yielded per line.
+
+use serde::{Serialize, de::DeserializeOwned};
+use std::io::{Read, Write};
+
+use super::FormatError;
+
+pub(crate) fn deserialize<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, FormatError> {
+    let text = String::from_utf8_lossy(bytes);
+
+    let values: Vec<serde_json::Value> = text
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| serde_json::from_str(line).map_err(|e| FormatError::Serde(Box::new(e))))
+        .collect::<Result<_, _>>()?;
+
+    serde_json::from_value(serde_json::Value::Array(values))
+        .map_err(|e| FormatError::Serde(Box::new(e)))
+}
+
+pub(crate) fn serialize<T: Serialize>(value: &T) -> Result<Vec<u8>, FormatError> {
+    let json_value = serde_json::to_value(value).map_err(|e| FormatError::Serde(Box::new(e)))?;
+
+    let mut out = Vec::new();
+    let mut write_line = |v: &serde_json::Value| -> Result<(), FormatError> {
+        serde_json::to_writer(&mut out, v).map_err(|e| FormatError::Serde(Box::new(e)))?;
+        out.push(b'\n');
+        Ok(())
+    };
+
+    match json_value {
+        serde_json::Value::Array(items) => {
+            for item in &items {
+                write_line(item)?;
+            }
+        }
+        other => write_line(&other)?,
+    }
+
+    Ok(out)
+}
+
+/// NDJSON requires exactly one compact value per line, so there's no pretty
+/// vs. compact knob to honor; `options` is ignored.
+pub(crate) fn serialize_with_options<T: Serialize>(
+    value: &T,
+    _options: &super::OutputOptions,
+) -> Result<Vec<u8>, FormatError> {
+    serialize(value)
+}
+
+pub(crate) fn stream_deserialize<T, R>(reader: R) -> impl Iterator<Item = Result<T, FormatError>>
+where
+    T: DeserializeOwned,
+    R: Read,
+{
+    use std::io::{BufRead, BufReader};
+
+    BufReader::new(reader)
+        .lines()
+        .enumerate()
+        .filter_map(|(idx, res)| {
+            let line_no = idx + 1;
+            match res {
+                Ok(line) if line.trim().is_empty() => None,
+                Ok(line) => Some(serde_json::from_str(&line).map_err(|e| {
+                    FormatError::Serde(Box::new(std::io::Error::other(format!(
+                        "line {line_no}: {e}"
+                    ))))
+                })),
+                Err(e) => Some(Err(FormatError::Io(e))),
+            }
+        })
+}
+
+/// Stream-serialize values one per line, writing incrementally rather than
+/// collecting into an array first. This is NDJSON's natural representation,
+/// matching [`stream_deserialize`]'s one-record-per-line reading.
+pub(crate) fn stream_serialize(
+    values: Box<dyn Iterator<Item = Result<serde_json::Value, FormatError>>>,
+    writer: &mut dyn Write,
+) -> Result<(), FormatError> {
+    for value in values {
+        let value = value?;
+        serde_json::to_writer(&mut *writer, &value).map_err(|e| FormatError::Serde(Box::new(e)))?;
+        writer.write_all(b"\n")?;
+    }
+    Ok(())
+}
@@ -0,0 +1,138 @@
+//! Formatting hints for `FormatRegistry::serialize_value_with_options`.
+
+/// How a serialized value's whitespace should be shaped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputStyle {
+    /// Minimal whitespace, one line where the format allows it.
+    Compact,
+    /// Human-readable, with newlines and indentation.
+    Pretty,
+}
+
+impl OutputStyle {
+    /// Parse a style from a string.
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "compact" => Some(OutputStyle::Compact),
+            "pretty" => Some(OutputStyle::Pretty),
+            _ => None,
+        }
+    }
+}
+
+/// Whether object/map keys should keep the order they appear in the source
+/// value, or be sorted alphabetically.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyOrder {
+    /// Preserve the order keys appeared in the source value.
+    Insertion,
+    /// Sort keys alphabetically.
+    Sorted,
+}
+
+impl KeyOrder {
+    /// Parse a key order from a string.
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "insertion" => Some(KeyOrder::Insertion),
+            "sorted" => Some(KeyOrder::Sorted),
+            _ => None,
+        }
+    }
+}
+
+/// Formatting hints threaded into `FormatRegistry::serialize_value_with_options`.
+///
+/// These are hints, not guarantees: a format whose underlying serializer has
+/// no notion of a given knob (CSV has no "pretty" mode, for instance) ignores
+/// it rather than erroring. See each format module for what it honors.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutputOptions {
+    /// Compact vs. pretty-printed output.
+    pub style: OutputStyle,
+    /// Indent unit used when `style` is `Pretty`.
+    pub indent: String,
+    /// Insertion vs. sorted map/object key order.
+    pub key_order: KeyOrder,
+    /// CSV field delimiter. Only honored by the CSV format.
+    pub csv_delimiter: u8,
+    /// CSV quote character. Only honored by the CSV format.
+    pub csv_quote: u8,
+    /// Whether CSV output should include a header row. Only honored by the
+    /// CSV format.
+    pub csv_header: bool,
+    /// Emit a structured Markdown document - YAML frontmatter for scalar
+    /// top-level fields plus a single ` ```json ` block for the remaining
+    /// object/array-valued fields - instead of one monolithic ` ```json `
+    /// block containing the whole value. Only honored by the Markdown
+    /// format, and only when both its `json` and `yaml` feature
+    /// dependencies are enabled; otherwise ignored like any other hint a
+    /// format can't act on.
+    pub markdown_frontmatter: bool,
+}
+
+impl Default for OutputOptions {
+    fn default() -> Self {
+        Self {
+            style: OutputStyle::Compact,
+            indent: "  ".to_string(),
+            key_order: KeyOrder::Insertion,
+            csv_delimiter: b',',
+            csv_quote: b'"',
+            csv_header: true,
+            markdown_frontmatter: false,
+        }
+    }
+}
+
+impl OutputOptions {
+    /// Options for compact, single-line-where-possible output.
+    pub fn compact() -> Self {
+        Self::default()
+    }
+
+    /// Options for human-readable, indented output.
+    pub fn pretty() -> Self {
+        Self {
+            style: OutputStyle::Pretty,
+            ..Self::default()
+        }
+    }
+
+    /// Set the indent unit (used only when `style` is `Pretty`).
+    pub fn with_indent(mut self, indent: impl Into<String>) -> Self {
+        self.indent = indent.into();
+        self
+    }
+
+    /// Set the key order.
+    pub fn with_key_order(mut self, key_order: KeyOrder) -> Self {
+        self.key_order = key_order;
+        self
+    }
+
+    /// Set the CSV field delimiter (used only by the CSV format).
+    pub fn with_csv_delimiter(mut self, delimiter: u8) -> Self {
+        self.csv_delimiter = delimiter;
+        self
+    }
+
+    /// Set the CSV quote character (used only by the CSV format).
+    pub fn with_csv_quote(mut self, quote: u8) -> Self {
+        self.csv_quote = quote;
+        self
+    }
+
+    /// Set whether CSV output includes a header row (used only by the CSV format).
+    pub fn with_csv_header(mut self, header: bool) -> Self {
+        self.csv_header = header;
+        self
+    }
+
+    /// Set whether Markdown output splits into frontmatter plus a payload
+    /// block (used only by the Markdown format).
+    pub fn with_markdown_frontmatter(mut self, frontmatter: bool) -> Self {
+        self.markdown_frontmatter = frontmatter;
+        self
+    }
+}
@@ -0,0 +1,49 @@
+//! An insertion-order-preserving generic document value, used by
+//! [`super::FormatRegistry::deserialize_document`]/`serialize_document` when
+//! [`super::FormatRegistry::with_preserve_order`] is set.
+//!
+//! `serde_json::Value`'s own map type flips between a sorted `BTreeMap` and
+//! an insertion-ordered `IndexMap` depending on whether the `serde_json`
+//! crate itself was compiled with its `preserve_order` feature - a
+//! process-wide, compile-time choice multiio can't flip per-registry.
+//! `OrderedValue` sidesteps that by always backing its object variant with an
+//! `indexmap::IndexMap`, so a read-then-write through it preserves the
+//! source document's field order no matter how `serde_json` was compiled,
+//! and regardless of which structured format (JSON, YAML, TOML, ...) decoded
+//! it.
+
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+
+/// A schema-less document value, shaped like `serde_json::Value` but backed
+/// by an insertion-ordered map so object key order survives a
+/// deserialize-then-serialize round trip.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum OrderedValue {
+    Null,
+    Bool(bool),
+    Number(serde_json::Number),
+    String(String),
+    Array(Vec<OrderedValue>),
+    Object(IndexMap<String, OrderedValue>),
+}
+
+impl From<serde_json::Value> for OrderedValue {
+    fn from(value: serde_json::Value) -> Self {
+        match value {
+            serde_json::Value::Null => OrderedValue::Null,
+            serde_json::Value::Bool(b) => OrderedValue::Bool(b),
+            serde_json::Value::Number(n) => OrderedValue::Number(n),
+            serde_json::Value::String(s) => OrderedValue::String(s),
+            serde_json::Value::Array(arr) => {
+                OrderedValue::Array(arr.into_iter().map(OrderedValue::from).collect())
+            }
+            serde_json::Value::Object(map) => OrderedValue::Object(
+                map.into_iter()
+                    .map(|(k, v)| (k, OrderedValue::from(v)))
+                    .collect(),
+            ),
+        }
+    }
+}
@@ -1,10 +1,10 @@
-use std::io::Read;
+use std::io::{Read, Write};
 
 use serde::{Serialize, de::DeserializeOwned};
 
 use super::{FormatError, FormatKind, STRUCTURED_TEXT_FORMATS};
 
-fn decode_from_string<T: DeserializeOwned>(s: String) -> Result<T, FormatError> {
+pub(crate) fn decode_from_string<T: DeserializeOwned>(s: String) -> Result<T, FormatError> {
     if let Some(v) = try_decode_structured::<T>(&s)? {
         return Ok(v);
     }
@@ -116,3 +116,33 @@ pub(crate) fn serialize<T: Serialize>(value: &T) -> Result<Vec<u8>, FormatError>
         ))))
     }
 }
+
+/// Plaintext's structured fallback is always pretty JSON, so `options` is ignored.
+pub(crate) fn serialize_with_options<T: Serialize>(
+    value: &T,
+    _options: &super::OutputOptions,
+) -> Result<Vec<u8>, FormatError> {
+    serialize(value)
+}
+
+/// Stream-serialize values one per line, writing incrementally rather than
+/// collecting into a pretty-printed document first. A record that's a plain
+/// string is written as-is (mirroring `stream_deserialize`'s plain-string
+/// fallback); anything else is written as one compact JSON value per line.
+pub(crate) fn stream_serialize(
+    values: Box<dyn Iterator<Item = Result<serde_json::Value, FormatError>>>,
+    writer: &mut dyn Write,
+) -> Result<(), FormatError> {
+    for value in values {
+        let value = value?;
+        match value {
+            serde_json::Value::String(s) => writer.write_all(s.as_bytes())?,
+            other => {
+                serde_json::to_writer(&mut *writer, &other)
+                    .map_err(|e| FormatError::Serde(Box::new(e)))?;
+            }
+        }
+        writer.write_all(b"\n")?;
+    }
+    Ok(())
+}
@@ -0,0 +1,79 @@
+//! Preserves format implementation: a self-describing serialization format
+//! with a canonical binary encoding and a human-readable text syntax that
+//! round-trip to the same values. Beyond JSON's types, Preserves adds
+//! records (a label plus ordered fields), symbols (distinct from strings),
+//! byte strings, sets, and out-of-band annotations.
+//!
+//! Every binary Preserves value starts with a tag byte at or above `0x80`,
+//! while the text syntax starts with a printable ASCII character such as
+//! `<`, `#`, a digit, or a quote. `deserialize`/`stream_deserialize` sniff
+//! the leading byte to pick the matching decoder; `serialize` always emits
+//! the canonical binary encoding, since that's the form meant for
+//! machine-to-machine interchange.
+
+use std::io::{self, Read};
+
+use serde::{Serialize, de::DeserializeOwned};
+
+use super::FormatError;
+
+pub(crate) fn deserialize<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, FormatError> {
+    if is_binary(bytes) {
+        preserves::value::from_bytes(bytes).map_err(|e| FormatError::Serde(Box::new(e)))
+    } else {
+        let text = std::str::from_utf8(bytes).map_err(|e| FormatError::Other(Box::new(e)))?;
+        preserves::value::from_str(text).map_err(|e| FormatError::Serde(Box::new(e)))
+    }
+}
+
+pub(crate) fn serialize<T: Serialize>(value: &T) -> Result<Vec<u8>, FormatError> {
+    preserves::value::to_bytes(value).map_err(|e| FormatError::Serde(Box::new(e)))
+}
+
+/// The canonical binary encoding has no notion of whitespace style, indent,
+/// or key order, so `options` is ignored.
+pub(crate) fn serialize_with_options<T: Serialize>(
+    value: &T,
+    _options: &super::OutputOptions,
+) -> Result<Vec<u8>, FormatError> {
+    serialize(value)
+}
+
+/// Sniff the first byte of a Preserves stream to decide which syntax a
+/// decoder should use.
+fn is_binary(bytes: &[u8]) -> bool {
+    matches!(bytes.first(), Some(b) if *b >= 0x80)
+}
+
+pub(crate) fn stream_deserialize<T, R>(
+    mut reader: R,
+) -> Box<dyn Iterator<Item = Result<T, FormatError>>>
+where
+    T: DeserializeOwned + 'static,
+    R: Read + 'static,
+{
+    let mut first = [0u8; 1];
+    let n = match reader.read(&mut first) {
+        Ok(n) => n,
+        Err(e) => return Box::new(std::iter::once(Err(FormatError::Io(e)))),
+    };
+    if n == 0 {
+        return Box::new(std::iter::empty());
+    }
+
+    let reader = io::Cursor::new(first).chain(reader);
+
+    if is_binary(&first) {
+        Box::new(
+            preserves::value::de::Deserializer::from_binary_reader(reader)
+                .into_iter::<T>()
+                .map(|res| res.map_err(|e| FormatError::Serde(Box::new(e)))),
+        )
+    } else {
+        Box::new(
+            preserves::value::de::Deserializer::from_text_reader(reader)
+                .into_iter::<T>()
+                .map(|res| res.map_err(|e| FormatError::Serde(Box::new(e)))),
+        )
+    }
+}
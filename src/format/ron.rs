@@ -0,0 +1,35 @@
+//! RON (Rusty Object Notation) format implementation.
+
+use serde::{Serialize, de::DeserializeOwned};
+
+use super::FormatError;
+
+pub(crate) fn deserialize<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, FormatError> {
+    ron::de::from_bytes(bytes).map_err(|e| FormatError::Serde(Box::new(e)))
+}
+
+pub(crate) fn serialize<T: Serialize>(value: &T) -> Result<Vec<u8>, FormatError> {
+    ron::to_string(value)
+        .map(|s| s.into_bytes())
+        .map_err(|e| FormatError::Serde(Box::new(e)))
+}
+
+/// `ron::to_string` already produces RON's compact form; `OutputStyle::Pretty`
+/// uses `ron::ser::to_string_pretty` with the library's default
+/// `PrettyConfig`. `options.indent`/`options.key_order` have no RON
+/// equivalent beyond that.
+pub(crate) fn serialize_with_options<T: Serialize>(
+    value: &T,
+    options: &super::OutputOptions,
+) -> Result<Vec<u8>, FormatError> {
+    use super::OutputStyle;
+
+    let rendered = match options.style {
+        OutputStyle::Compact => ron::to_string(value),
+        OutputStyle::Pretty => ron::ser::to_string_pretty(value, ron::ser::PrettyConfig::default()),
+    };
+
+    rendered
+        .map(|s| s.into_bytes())
+        .map_err(|e| FormatError::Serde(Box::new(e)))
+}
@@ -0,0 +1,40 @@
+use serde::{Serialize, de::DeserializeOwned};
+
+use super::FormatError;
+
+pub(crate) fn deserialize<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, FormatError> {
+    let s = String::from_utf8_lossy(bytes);
+    toml::from_str(&s).map_err(|e| match e.span() {
+        Some(range) => FormatError::SerdeSpanned {
+            span: (range.start, range.end.saturating_sub(range.start)),
+            input: bytes.into(),
+            source: Box::new(e),
+        },
+        None => FormatError::Serde(Box::new(e)),
+    })
+}
+
+pub(crate) fn serialize<T: Serialize>(value: &T) -> Result<Vec<u8>, FormatError> {
+    toml::to_string(value)
+        .map(|s| s.into_bytes())
+        .map_err(|e| FormatError::Serde(Box::new(e)))
+}
+
+/// `toml::Table` is backed by a `BTreeMap`, so it is always key-sorted
+/// regardless of `options.key_order`; `options.indent` has no TOML
+/// equivalent either. Only `options.style` (compact vs. pretty) is honored.
+pub(crate) fn serialize_with_options<T: Serialize>(
+    value: &T,
+    options: &super::OutputOptions,
+) -> Result<Vec<u8>, FormatError> {
+    use super::OutputStyle;
+
+    let rendered = match options.style {
+        OutputStyle::Compact => toml::to_string(value),
+        OutputStyle::Pretty => toml::to_string_pretty(value),
+    };
+
+    rendered
+        .map(|s| s.into_bytes())
+        .map_err(|e| FormatError::Serde(Box::new(e)))
+}
@@ -22,6 +22,8 @@ impl Format for XmlFormat {
     fn deserialize<T: DeserializeOwned>(&self, reader: &mut dyn Read) -> Result<T, FormatError> {
         let mut content = String::new();
         reader.read_to_string(&mut content)?;
+        // quick-xml's `DeError` doesn't expose a reliable byte span, unlike
+        // serde_json/serde_yaml/toml, so XML parse errors stay unspanned.
         quick_xml::de::from_str(&content).map_err(|e| FormatError::Serde(Box::new(e)))
     }
 
@@ -4,10 +4,39 @@ use std::io::Read;
 
 use serde::{Serialize, de::DeserializeOwned};
 
-use super::FormatError;
+use super::{FormatError, FormatKind, PayloadErrorKind, truncate_context};
 
 pub(crate) fn deserialize<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, FormatError> {
-    serde_yaml::from_slice(bytes).map_err(|e| FormatError::Serde(Box::new(e)))
+    let de = serde_yaml::Deserializer::from_slice(bytes);
+    serde_path_to_error::deserialize(de).map_err(|e| {
+        // `serde_yaml::Error` has no `classify()` to tell a syntax failure
+        // apart from a rejected value the way `serde_json::Error` does, so
+        // use the path shape instead: reaching a sequence index means a
+        // value was actually read off that element and handed to `T` for
+        // validation, which is the only case path-tracking adds anything
+        // over the existing span/data-shape handling below.
+        if e.path().to_string().contains('[') {
+            return super::path_tracking_error(e);
+        }
+        let e = e.into_inner();
+        match e.location() {
+            Some(loc) => FormatError::SerdeSpanned {
+                span: (loc.index(), 1),
+                input: bytes.into(),
+                source: Box::new(e),
+            },
+            // `serde_yaml` only omits a location for errors that aren't tied
+            // to a specific byte in the input (e.g. the document didn't
+            // match the target shape at all), so classify those as
+            // data-shape rather than syntax failures.
+            None => FormatError::MalformedPayload {
+                kind: FormatKind::Yaml,
+                classification: PayloadErrorKind::DataShape,
+                message: e.to_string(),
+                context: truncate_context(bytes),
+            },
+        }
+    })
 }
 
 pub(crate) fn stream_deserialize<T, R>(reader: R) -> impl Iterator<Item = Result<T, FormatError>>
@@ -24,3 +53,55 @@ pub(crate) fn serialize<T: Serialize>(value: &T) -> Result<Vec<u8>, FormatError>
         .map(|s| s.into_bytes())
         .map_err(|e| FormatError::Serde(Box::new(e)))
 }
+
+/// YAML's block style is already human-readable and has no "compact" mode, so
+/// `options.style`/`options.indent` are ignored; only `key_order` is honored.
+/// `serde_yaml`'s emitter also has no public flow-vs-block style knob, so
+/// there is no `options` field for it to honor here either.
+pub(crate) fn serialize_with_options<T: Serialize>(
+    value: &T,
+    options: &super::OutputOptions,
+) -> Result<Vec<u8>, FormatError> {
+    use super::KeyOrder;
+
+    if options.key_order != KeyOrder::Sorted {
+        return serialize(value);
+    }
+
+    let mut yaml_value =
+        serde_yaml::to_value(value).map_err(|e| FormatError::Serde(Box::new(e)))?;
+    sort_keys(&mut yaml_value);
+    serde_yaml::to_string(&yaml_value)
+        .map(|s| s.into_bytes())
+        .map_err(|e| FormatError::Serde(Box::new(e)))
+}
+
+/// Recursively sort mapping keys alphabetically by their string rendering.
+fn sort_keys(value: &mut serde_yaml::Value) {
+    match value {
+        serde_yaml::Value::Mapping(map) => {
+            let mut entries: Vec<(serde_yaml::Value, serde_yaml::Value)> =
+                std::mem::take(map).into_iter().collect();
+            for (_, v) in entries.iter_mut() {
+                sort_keys(v);
+            }
+            entries.sort_by(|a, b| {
+                yaml_key_as_string(&a.0).cmp(&yaml_key_as_string(&b.0))
+            });
+            map.extend(entries);
+        }
+        serde_yaml::Value::Sequence(seq) => {
+            for v in seq.iter_mut() {
+                sort_keys(v);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn yaml_key_as_string(key: &serde_yaml::Value) -> String {
+    match key {
+        serde_yaml::Value::String(s) => s.clone(),
+        other => serde_yaml::to_string(other).unwrap_or_default(),
+    }
+}
@@ -0,0 +1,127 @@
+//! Async counterparts to [`crate::io::compression`]'s decompressing input and
+//! compressing output wrappers.
+//!
+//! Gzip/zstd/bzip2 stay lazy: `async-compression`'s decoders/encoders wrap the
+//! inner `AsyncRead`/`AsyncWrite` directly, so `read_records_async` still
+//! streams one record at a time instead of buffering the whole input. Zip
+//! needs random access to the archive's central directory, so it's read fully
+//! into memory and parsed with the sync `zip` crate, same as the sync engine.
+
+use std::io::Cursor;
+use std::sync::Arc;
+
+use async_compression::tokio::bufread::{BzDecoder, GzipDecoder, ZstdDecoder};
+use async_compression::tokio::write::{BzEncoder, GzipEncoder, ZstdEncoder};
+use async_trait::async_trait;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, BufReader};
+
+use super::compression::Compression;
+use super::{AsyncInputProvider, AsyncOutputTarget};
+
+/// Wraps an `AsyncInputProvider`, transparently decompressing the underlying
+/// stream according to `compression`.
+#[derive(Debug)]
+pub struct AsyncCompressedInput {
+    inner: Arc<dyn AsyncInputProvider>,
+    compression: Compression,
+}
+
+impl AsyncCompressedInput {
+    pub fn new(inner: Arc<dyn AsyncInputProvider>, compression: Compression) -> Self {
+        Self { inner, compression }
+    }
+}
+
+#[async_trait]
+impl AsyncInputProvider for AsyncCompressedInput {
+    fn id(&self) -> &str {
+        self.inner.id()
+    }
+
+    fn watch_path(&self) -> Option<&std::path::Path> {
+        self.inner.watch_path()
+    }
+
+    async fn open(&self) -> std::io::Result<Box<dyn AsyncRead + Unpin + Send>> {
+        let reader = self.inner.open().await?;
+        match &self.compression {
+            Compression::Gzip(_) => Ok(Box::new(GzipDecoder::new(BufReader::new(reader)))),
+            Compression::Zstd(_) => Ok(Box::new(ZstdDecoder::new(BufReader::new(reader)))),
+            Compression::Bzip2(_) => Ok(Box::new(BzDecoder::new(BufReader::new(reader)))),
+            Compression::Zip { entry, .. } => {
+                let mut reader = reader;
+                let mut archive_bytes = Vec::new();
+                reader.read_to_end(&mut archive_bytes).await?;
+
+                let mut archive = zip::ZipArchive::new(Cursor::new(archive_bytes))
+                    .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+                let mut entry_file = match entry {
+                    Some(name) => archive
+                        .by_name(name)
+                        .map_err(|e| std::io::Error::new(std::io::ErrorKind::NotFound, e))?,
+                    None => archive
+                        .by_index(0)
+                        .map_err(|e| std::io::Error::new(std::io::ErrorKind::NotFound, e))?,
+                };
+
+                let mut contents = Vec::new();
+                std::io::Read::read_to_end(&mut entry_file, &mut contents)?;
+                Ok(Box::new(Cursor::new(contents)))
+            }
+        }
+    }
+}
+
+/// Wraps an `AsyncOutputTarget`, transparently compressing everything written
+/// through it according to `compression`.
+#[derive(Debug)]
+pub struct AsyncCompressedOutput {
+    inner: Arc<dyn AsyncOutputTarget>,
+    compression: Compression,
+}
+
+impl AsyncCompressedOutput {
+    pub fn new(inner: Arc<dyn AsyncOutputTarget>, compression: Compression) -> Self {
+        Self { inner, compression }
+    }
+
+    fn wrap(
+        &self,
+        writer: Box<dyn AsyncWrite + Unpin + Send>,
+    ) -> std::io::Result<Box<dyn AsyncWrite + Unpin + Send>> {
+        match &self.compression {
+            Compression::Gzip(_) => Ok(Box::new(GzipEncoder::new(writer))),
+            Compression::Zstd(_) => Ok(Box::new(ZstdEncoder::new(writer))),
+            Compression::Bzip2(_) => Ok(Box::new(BzEncoder::new(writer))),
+            Compression::Zip { .. } => Err(std::io::Error::new(
+                std::io::ErrorKind::Unsupported,
+                "zip output isn't supported on the async engine; use the sync engine's \
+                 CompressedOutput, which can finish the archive's central directory \
+                 synchronously on drop",
+            )),
+        }
+    }
+}
+
+#[async_trait]
+impl AsyncOutputTarget for AsyncCompressedOutput {
+    fn id(&self) -> &str {
+        self.inner.id()
+    }
+
+    async fn open_overwrite(&self) -> std::io::Result<Box<dyn AsyncWrite + Unpin + Send>> {
+        self.wrap(self.inner.open_overwrite().await?)
+    }
+
+    async fn open_append(&self) -> std::io::Result<Box<dyn AsyncWrite + Unpin + Send>> {
+        self.wrap(self.inner.open_append().await?)
+    }
+
+    async fn open_overwrite_at(&self, path: &std::path::Path) -> std::io::Result<Box<dyn AsyncWrite + Unpin + Send>> {
+        self.wrap(self.inner.open_overwrite_at(path).await?)
+    }
+
+    fn file_path(&self) -> Option<&std::path::Path> {
+        self.inner.file_path()
+    }
+}
@@ -1,4 +1,5 @@
 use std::fmt::Debug;
+use std::path::Path;
 
 use async_trait::async_trait;
 use tokio::io::AsyncRead;
@@ -10,4 +11,14 @@ pub trait AsyncInputProvider: Send + Sync + Debug {
 
     /// Open and return a new async readable stream.
     async fn open(&self) -> std::io::Result<Box<dyn AsyncRead + Unpin + Send>>;
+
+    /// The filesystem path this provider reads from, if any.
+    ///
+    /// `AsyncIoEngine::run_watched` polls this to decide which inputs can
+    /// trigger a rerun; providers backed by something other than a plain
+    /// file (stdin, inline content, a socket, a process, ...) have nothing
+    /// meaningful to watch and keep the default `None`.
+    fn watch_path(&self) -> Option<&Path> {
+        None
+    }
 }
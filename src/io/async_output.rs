@@ -1,6 +1,7 @@
 //! Async output target trait definition.
 
 use std::fmt::Debug;
+use std::path::Path;
 
 use async_trait::async_trait;
 use tokio::io::AsyncWrite;
@@ -16,4 +17,23 @@ pub trait AsyncOutputTarget: Send + Sync + Debug {
 
     /// Open the target for appending to existing content.
     async fn open_append(&self) -> std::io::Result<Box<dyn AsyncWrite + Unpin + Send>>;
+
+    /// Open a writer at an arbitrary `path` rather than this target's own,
+    /// applying whatever transform this target would normally wrap around
+    /// its content (compression, an external filter command, ...).
+    ///
+    /// See [`crate::io::OutputTarget::open_overwrite_at`] for why this
+    /// exists: `FileExistsPolicy::AtomicOverwrite`'s write-temp-then-rename
+    /// dance needs a wrapping target to keep transforming bytes written to
+    /// the temp path, not just to its own `file_path()`.
+    async fn open_overwrite_at(&self, path: &Path) -> std::io::Result<Box<dyn AsyncWrite + Unpin + Send>> {
+        Ok(Box::new(tokio::fs::File::create(path).await?))
+    }
+
+    /// Returns the filesystem path backing this target, if any.
+    ///
+    /// See [`crate::io::OutputTarget::file_path`] for how the engine uses this.
+    fn file_path(&self) -> Option<&Path> {
+        None
+    }
 }
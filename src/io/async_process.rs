@@ -0,0 +1,246 @@
+//! Async counterpart of the sync `ProcessInput`/`ProcessOutput` adapters:
+//! external-command adapters built on `tokio::process::Command` instead of
+//! `std::process::Command`.
+//!
+//! Unlike the sync reader/writer, these don't need a manual `Drop` impl to
+//! reap the child: `tokio::process::Child` registers itself with Tokio's
+//! runtime-wide orphan queue, which waits it out in the background even if
+//! it's dropped without ever being awaited.
+
+use std::future::Future;
+use std::io;
+use std::pin::Pin;
+use std::process::{ExitStatus, Stdio};
+use std::task::{Context, Poll};
+
+use async_trait::async_trait;
+use tokio::io::{AsyncRead, AsyncWrite, AsyncWriteExt, ReadBuf};
+use tokio::process::{Child, ChildStdin, ChildStdout, Command};
+
+use super::{AsyncInputProvider, AsyncOutputTarget};
+
+/// Async input provider that runs an external command and reads its stdout.
+#[derive(Debug, Clone)]
+pub struct AsyncProcessInput {
+    id: String,
+    command: String,
+    args: Vec<String>,
+    stdin_payload: Option<Vec<u8>>,
+}
+
+impl AsyncProcessInput {
+    /// Create a new async process input that runs `command` with no arguments.
+    pub fn new(command: impl Into<String>) -> Self {
+        let command = command.into();
+        Self {
+            id: command.clone(),
+            command,
+            args: Vec::new(),
+            stdin_payload: None,
+        }
+    }
+
+    /// Set the command's arguments.
+    pub fn with_args(mut self, args: Vec<String>) -> Self {
+        self.args = args;
+        self
+    }
+
+    /// Feed `payload` to the command's stdin before reading its stdout.
+    pub fn with_stdin(mut self, payload: impl Into<Vec<u8>>) -> Self {
+        self.stdin_payload = Some(payload.into());
+        self
+    }
+}
+
+#[async_trait]
+impl AsyncInputProvider for AsyncProcessInput {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    async fn open(&self) -> io::Result<Box<dyn AsyncRead + Unpin + Send>> {
+        let mut cmd = Command::new(&self.command);
+        cmd.args(&self.args);
+        cmd.stdout(Stdio::piped());
+        cmd.stderr(Stdio::null());
+        cmd.stdin(if self.stdin_payload.is_some() {
+            Stdio::piped()
+        } else {
+            Stdio::null()
+        });
+
+        let mut child = cmd.spawn()?;
+
+        if let Some(payload) = self.stdin_payload.clone() {
+            let mut stdin = child
+                .stdin
+                .take()
+                .expect("stdin was configured as piped above");
+            tokio::spawn(async move {
+                let _ = stdin.write_all(&payload).await;
+            });
+        }
+
+        let stdout = child
+            .stdout
+            .take()
+            .expect("stdout was configured as piped above");
+
+        Ok(Box::new(AsyncProcessReader::new(child, stdout)))
+    }
+}
+
+type WaitFuture = Pin<Box<dyn Future<Output = io::Result<ExitStatus>> + Send>>;
+
+/// Reader that wraps a child process's stdout and, on EOF, waits for the
+/// child so a nonzero exit status surfaces as an `io::Error`.
+pub(crate) struct AsyncProcessReader {
+    child: Option<Child>,
+    stdout: ChildStdout,
+    wait: Option<WaitFuture>,
+}
+
+impl AsyncProcessReader {
+    /// Wrap an already-spawned `child`'s stdout, reaping `child` and checking
+    /// its exit status once `stdout` reports EOF. Shared with
+    /// [`super::async_transform::AsyncTransformInput`], which spawns the
+    /// child itself in order to feed its stdin from an upstream reader first.
+    pub(crate) fn new(child: Child, stdout: ChildStdout) -> Self {
+        Self {
+            child: Some(child),
+            stdout,
+            wait: None,
+        }
+    }
+}
+
+impl AsyncRead for AsyncProcessReader {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        loop {
+            if let Some(wait) = this.wait.as_mut() {
+                return match wait.as_mut().poll(cx) {
+                    Poll::Ready(Ok(status)) if !status.success() => {
+                        this.wait = None;
+                        Poll::Ready(Err(io::Error::other(format!(
+                            "command exited with {status}"
+                        ))))
+                    }
+                    Poll::Ready(Ok(_)) => {
+                        this.wait = None;
+                        Poll::Ready(Ok(()))
+                    }
+                    Poll::Ready(Err(e)) => {
+                        this.wait = None;
+                        Poll::Ready(Err(e))
+                    }
+                    Poll::Pending => Poll::Pending,
+                };
+            }
+
+            let before = buf.filled().len();
+            match Pin::new(&mut this.stdout).poll_read(cx, buf) {
+                Poll::Ready(Ok(())) if buf.filled().len() == before => {
+                    match this.child.take() {
+                        Some(mut child) => {
+                            this.wait = Some(Box::pin(async move { child.wait().await }));
+                            continue;
+                        }
+                        None => return Poll::Ready(Ok(())),
+                    }
+                }
+                other => return other,
+            }
+        }
+    }
+}
+
+/// Async output target that runs an external command and writes to its stdin.
+#[derive(Debug, Clone)]
+pub struct AsyncProcessOutput {
+    id: String,
+    command: String,
+    args: Vec<String>,
+}
+
+impl AsyncProcessOutput {
+    /// Create a new async process output that runs `command` with no arguments.
+    pub fn new(command: impl Into<String>) -> Self {
+        let command = command.into();
+        Self {
+            id: command.clone(),
+            command,
+            args: Vec::new(),
+        }
+    }
+
+    /// Set the command's arguments.
+    pub fn with_args(mut self, args: Vec<String>) -> Self {
+        self.args = args;
+        self
+    }
+
+    async fn spawn(&self) -> io::Result<Child> {
+        Command::new(&self.command)
+            .args(&self.args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+    }
+}
+
+#[async_trait]
+impl AsyncOutputTarget for AsyncProcessOutput {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    // A subprocess has no concept of truncate-vs-append; it just reads
+    // whatever arrives on stdin.
+    async fn open_overwrite(&self) -> io::Result<Box<dyn AsyncWrite + Unpin + Send>> {
+        let mut child = self.spawn().await?;
+        let stdin = child
+            .stdin
+            .take()
+            .expect("stdin was configured as piped above");
+        Ok(Box::new(AsyncProcessWriter {
+            _child: child,
+            stdin,
+        }))
+    }
+
+    async fn open_append(&self) -> io::Result<Box<dyn AsyncWrite + Unpin + Send>> {
+        self.open_overwrite().await
+    }
+}
+
+/// Writer that wraps a child process's stdin. The child is kept alive
+/// alongside the writer and reaped by Tokio's orphan queue once dropped.
+struct AsyncProcessWriter {
+    _child: Child,
+    stdin: ChildStdin,
+}
+
+impl AsyncWrite for AsyncProcessWriter {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.get_mut().stdin).poll_write(cx, buf)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.get_mut().stdin).poll_flush(cx)
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.get_mut().stdin).poll_shutdown(cx)
+    }
+}
@@ -0,0 +1,77 @@
+//! Async counterpart of [`super::SchemeRegistry`], mapping `scheme://rest`
+//! tokens to `AsyncInputProvider`/`AsyncOutputTarget` factories for
+//! `MultiioAsyncBuilder`.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+use super::{AsyncInputProvider, AsyncOutputTarget};
+
+type AsyncInputFactory =
+    Arc<dyn Fn(&str) -> std::io::Result<Arc<dyn AsyncInputProvider>> + Send + Sync>;
+type AsyncOutputFactory =
+    Arc<dyn Fn(&str) -> std::io::Result<Arc<dyn AsyncOutputTarget>> + Send + Sync>;
+
+/// Registry of scheme name -> async provider/target factory, consulted by
+/// `MultiioAsyncBuilder` for any CLI token of the form `scheme://rest`.
+#[derive(Clone, Default)]
+pub struct AsyncSchemeRegistry {
+    inputs: HashMap<String, AsyncInputFactory>,
+    outputs: HashMap<String, AsyncOutputFactory>,
+}
+
+impl fmt::Debug for AsyncSchemeRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AsyncSchemeRegistry")
+            .field("input_schemes", &self.inputs.keys().collect::<Vec<_>>())
+            .field("output_schemes", &self.outputs.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+impl AsyncSchemeRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a factory for `scheme://rest` input tokens. `factory` is
+    /// called with the part of the token after `scheme://`.
+    pub fn register_input_scheme<F>(&mut self, scheme: impl Into<String>, factory: F)
+    where
+        F: Fn(&str) -> std::io::Result<Arc<dyn AsyncInputProvider>> + Send + Sync + 'static,
+    {
+        self.inputs.insert(scheme.into(), Arc::new(factory));
+    }
+
+    /// Register a factory for `scheme://rest` output tokens. `factory` is
+    /// called with the part of the token after `scheme://`.
+    pub fn register_output_scheme<F>(&mut self, scheme: impl Into<String>, factory: F)
+    where
+        F: Fn(&str) -> std::io::Result<Arc<dyn AsyncOutputTarget>> + Send + Sync + 'static,
+    {
+        self.outputs.insert(scheme.into(), Arc::new(factory));
+    }
+
+    /// If `raw` is `scheme://rest` for a registered input scheme, runs its
+    /// factory and returns the result. Returns `None` (not an error) when no
+    /// scheme matches, so callers can fall through to other resolution.
+    pub fn resolve_input(
+        &self,
+        raw: &str,
+    ) -> Option<std::io::Result<Arc<dyn AsyncInputProvider>>> {
+        let (scheme, rest) = raw.split_once("://")?;
+        self.inputs.get(scheme).map(|factory| factory(rest))
+    }
+
+    /// If `raw` is `scheme://rest` for a registered output scheme, runs its
+    /// factory and returns the result. Returns `None` (not an error) when no
+    /// scheme matches, so callers can fall through to other resolution.
+    pub fn resolve_output(
+        &self,
+        raw: &str,
+    ) -> Option<std::io::Result<Arc<dyn AsyncOutputTarget>>> {
+        let (scheme, rest) = raw.split_once("://")?;
+        self.outputs.get(scheme).map(|factory| factory(rest))
+    }
+}
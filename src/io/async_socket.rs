@@ -0,0 +1,284 @@
+//! Async counterpart of the sync `SocketInput`/`SocketOutput` providers,
+//! built on `tokio::net::{TcpStream, UnixStream}`.
+//!
+//! The write side can't simply delegate to `AsyncWriteExt::write_all` and
+//! append a trailing newline afterwards the way the sync `NdjsonWriter` does:
+//! `poll_write` is handed partial buffers under backpressure and has no
+//! "this was the last chunk of the logical write" signal from the caller.
+//! Instead `NdjsonAsyncWriter` stages the whole record (plus newline) in an
+//! internal buffer on the first `poll_write` of a new record and only reports
+//! it as written once every staged byte has actually reached the inner
+//! stream, so a record is never left half-flushed if the caller drops the
+//! writer without an explicit `flush()`/`shutdown()`.
+
+use std::io;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use async_trait::async_trait;
+use tokio::io::{AsyncRead, AsyncWrite, BufReader};
+use tokio::net::TcpStream;
+#[cfg(unix)]
+use tokio::net::UnixStream;
+
+#[cfg(unix)]
+use std::path::{Path, PathBuf};
+
+use super::{AsyncInputProvider, AsyncOutputTarget};
+
+/// How records are delimited on an async socket connection.
+pub trait AsyncFraming: Send + Sync + std::fmt::Debug {
+    /// Wrap a raw connection reader with this framing's read-side behavior.
+    fn frame_reader(
+        &self,
+        reader: Box<dyn AsyncRead + Unpin + Send>,
+    ) -> Box<dyn AsyncRead + Unpin + Send>;
+
+    /// Wrap a raw connection writer with this framing's write-side behavior.
+    /// Each logical record write is expected to reach the inner stream
+    /// atomically, followed by a trailing newline.
+    fn frame_writer(
+        &self,
+        writer: Box<dyn AsyncWrite + Unpin + Send>,
+    ) -> Box<dyn AsyncWrite + Unpin + Send>;
+}
+
+/// Newline-delimited JSON framing for async connections.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AsyncNdjsonFraming;
+
+impl AsyncFraming for AsyncNdjsonFraming {
+    fn frame_reader(
+        &self,
+        reader: Box<dyn AsyncRead + Unpin + Send>,
+    ) -> Box<dyn AsyncRead + Unpin + Send> {
+        Box::new(BufReader::new(reader))
+    }
+
+    fn frame_writer(
+        &self,
+        writer: Box<dyn AsyncWrite + Unpin + Send>,
+    ) -> Box<dyn AsyncWrite + Unpin + Send> {
+        Box::new(NdjsonAsyncWriter {
+            inner: writer,
+            pending: Vec::new(),
+            pending_offset: 0,
+        })
+    }
+}
+
+struct NdjsonAsyncWriter {
+    inner: Box<dyn AsyncWrite + Unpin + Send>,
+    /// Record bytes plus trailing newline not yet fully written to `inner`.
+    pending: Vec<u8>,
+    pending_offset: usize,
+}
+
+impl NdjsonAsyncWriter {
+    /// Drain `self.pending[self.pending_offset..]` into `self.inner`.
+    fn drain_pending(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        while self.pending_offset < self.pending.len() {
+            match Pin::new(&mut self.inner).poll_write(cx, &self.pending[self.pending_offset..]) {
+                Poll::Ready(Ok(0)) => {
+                    return Poll::Ready(Err(io::Error::new(
+                        io::ErrorKind::WriteZero,
+                        "failed to write whole ndjson record",
+                    )));
+                }
+                Poll::Ready(Ok(n)) => self.pending_offset += n,
+                Poll::Ready(Err(e)) => return Poll::Ready(Err(e)),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+        self.pending.clear();
+        self.pending_offset = 0;
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl AsyncWrite for NdjsonAsyncWriter {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+
+        // Only start staging a new record once the previous one has been
+        // fully drained: `poll_write` is required to return `Ready` exactly
+        // once its bytes are accounted for, so a caller won't pass a new
+        // buffer while a prior call is still pending.
+        if this.pending_offset >= this.pending.len() && !buf.is_empty() {
+            this.pending.clear();
+            this.pending.extend_from_slice(buf);
+            if !buf.ends_with(b"\n") {
+                this.pending.push(b'\n');
+            }
+            this.pending_offset = 0;
+        }
+
+        match this.drain_pending(cx) {
+            Poll::Ready(Ok(())) => Poll::Ready(Ok(buf.len())),
+            Poll::Ready(Err(e)) => Poll::Ready(Err(e)),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        match this.drain_pending(cx) {
+            Poll::Ready(Ok(())) => Pin::new(&mut this.inner).poll_flush(cx),
+            other => other,
+        }
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        match this.drain_pending(cx) {
+            Poll::Ready(Ok(())) => Pin::new(&mut this.inner).poll_shutdown(cx),
+            other => other,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Endpoint {
+    Tcp(String),
+    #[cfg(unix)]
+    Unix(PathBuf),
+}
+
+impl Endpoint {
+    fn id(&self) -> String {
+        match self {
+            Endpoint::Tcp(addr) => format!("tcp://{addr}"),
+            #[cfg(unix)]
+            Endpoint::Unix(path) => format!("unix:{}", path.display()),
+        }
+    }
+
+    async fn connect_read(&self) -> io::Result<Box<dyn AsyncRead + Unpin + Send>> {
+        match self {
+            Endpoint::Tcp(addr) => Ok(Box::new(TcpStream::connect(addr).await?)),
+            #[cfg(unix)]
+            Endpoint::Unix(path) => Ok(Box::new(UnixStream::connect(path).await?)),
+        }
+    }
+
+    async fn connect_write(&self) -> io::Result<Box<dyn AsyncWrite + Unpin + Send>> {
+        match self {
+            Endpoint::Tcp(addr) => Ok(Box::new(TcpStream::connect(addr).await?)),
+            #[cfg(unix)]
+            Endpoint::Unix(path) => Ok(Box::new(UnixStream::connect(path).await?)),
+        }
+    }
+}
+
+/// Async input provider that reads an NDJSON-framed record stream from a TCP
+/// or Unix domain socket connection.
+#[derive(Debug, Clone)]
+pub struct AsyncSocketInput {
+    id: String,
+    endpoint: Endpoint,
+    framing: Arc<dyn AsyncFraming>,
+}
+
+impl AsyncSocketInput {
+    /// Connect to `host:port` over TCP on every `open()`.
+    pub fn tcp(addr: impl Into<String>) -> Self {
+        let endpoint = Endpoint::Tcp(addr.into());
+        Self {
+            id: endpoint.id(),
+            endpoint,
+            framing: Arc::new(AsyncNdjsonFraming),
+        }
+    }
+
+    /// Connect to a Unix domain socket at `path` on every `open()`.
+    #[cfg(unix)]
+    pub fn unix(path: impl AsRef<Path>) -> Self {
+        let endpoint = Endpoint::Unix(path.as_ref().to_path_buf());
+        Self {
+            id: endpoint.id(),
+            endpoint,
+            framing: Arc::new(AsyncNdjsonFraming),
+        }
+    }
+
+    /// Use a different framing strategy than the default NDJSON framing.
+    pub fn with_framing(mut self, framing: Arc<dyn AsyncFraming>) -> Self {
+        self.framing = framing;
+        self
+    }
+}
+
+#[async_trait]
+impl AsyncInputProvider for AsyncSocketInput {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    async fn open(&self) -> io::Result<Box<dyn AsyncRead + Unpin + Send>> {
+        let reader = self.endpoint.connect_read().await?;
+        Ok(self.framing.frame_reader(reader))
+    }
+}
+
+/// Async output target that writes an NDJSON-framed record stream to a TCP
+/// or Unix domain socket connection.
+#[derive(Debug, Clone)]
+pub struct AsyncSocketOutput {
+    id: String,
+    endpoint: Endpoint,
+    framing: Arc<dyn AsyncFraming>,
+}
+
+impl AsyncSocketOutput {
+    /// Connect to `host:port` over TCP on every `open_overwrite()`/
+    /// `open_append()`.
+    pub fn tcp(addr: impl Into<String>) -> Self {
+        let endpoint = Endpoint::Tcp(addr.into());
+        Self {
+            id: endpoint.id(),
+            endpoint,
+            framing: Arc::new(AsyncNdjsonFraming),
+        }
+    }
+
+    /// Connect to a Unix domain socket at `path` on every `open_overwrite()`/
+    /// `open_append()`.
+    #[cfg(unix)]
+    pub fn unix(path: impl AsRef<Path>) -> Self {
+        let endpoint = Endpoint::Unix(path.as_ref().to_path_buf());
+        Self {
+            id: endpoint.id(),
+            endpoint,
+            framing: Arc::new(AsyncNdjsonFraming),
+        }
+    }
+
+    /// Use a different framing strategy than the default NDJSON framing.
+    pub fn with_framing(mut self, framing: Arc<dyn AsyncFraming>) -> Self {
+        self.framing = framing;
+        self
+    }
+}
+
+#[async_trait]
+impl AsyncOutputTarget for AsyncSocketOutput {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    // A socket connection has no concept of truncate-vs-append; every open
+    // is a fresh connection and the framing layer delimits records on it.
+    async fn open_overwrite(&self) -> io::Result<Box<dyn AsyncWrite + Unpin + Send>> {
+        let writer = self.endpoint.connect_write().await?;
+        Ok(self.framing.frame_writer(writer))
+    }
+
+    async fn open_append(&self) -> io::Result<Box<dyn AsyncWrite + Unpin + Send>> {
+        self.open_overwrite().await
+    }
+}
@@ -0,0 +1,315 @@
+//! SQL-backed async input/output providers, behind the optional `db`
+//! feature: bridge a relational database and the engine's byte-stream
+//! abstractions so rows flow through the same NDJSON encode/decode path as
+//! every other async provider/target.
+//!
+//! `SqlInput` runs its query lazily on `open()`: a background task drives the
+//! row stream and forwards each row, serialized as one compact JSON object
+//! per line, over a channel that's exposed to the caller as an `AsyncRead`.
+//! No result set is buffered up front - the engine only pulls as much as its
+//! NDJSON decoder consumes.
+//!
+//! `SqlOutput` does the reverse: bytes written through it are split into
+//! NDJSON lines, parsed back into rows, and flushed to the target table in
+//! batches of `SQL_BATCH_SIZE`. `FileExistsPolicy::Overwrite` truncates the
+//! table before the first batch; every other policy appends, mirroring how
+//! `FileOutput` treats `Overwrite` as truncate and anything else as
+//! append-or-fail at the file level.
+
+use std::future::Future;
+use std::io;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use futures::stream::StreamExt;
+use sqlx::any::{AnyPoolOptions, AnyRow};
+use sqlx::{Column, Row};
+use tokio::io::{AsyncRead, AsyncWrite};
+use tokio::sync::mpsc::{self, UnboundedSender};
+use tokio::task::JoinHandle;
+use tokio_stream::wrappers::ReceiverStream;
+use tokio_util::io::StreamReader;
+
+use crate::config::FileExistsPolicy;
+
+use super::{AsyncInputProvider, AsyncOutputTarget};
+
+/// Rows buffered before `SqlOutput` flushes an `INSERT` batch.
+const SQL_BATCH_SIZE: usize = 500;
+
+/// Async input provider that runs a SQL query and streams each row as one
+/// compact JSON object per NDJSON line.
+#[derive(Debug, Clone)]
+pub struct SqlInput {
+    id: String,
+    connection_string: String,
+    query: String,
+}
+
+impl SqlInput {
+    /// Create a SQL input that runs `query` against `connection_string` on
+    /// every `open()`.
+    pub fn new(connection_string: impl Into<String>, query: impl Into<String>) -> Self {
+        let connection_string = connection_string.into();
+        Self {
+            id: connection_string.clone(),
+            connection_string,
+            query: query.into(),
+        }
+    }
+}
+
+#[async_trait]
+impl AsyncInputProvider for SqlInput {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    async fn open(&self) -> io::Result<Box<dyn AsyncRead + Unpin + Send>> {
+        let pool = AnyPoolOptions::new()
+            .connect(&self.connection_string)
+            .await
+            .map_err(io::Error::other)?;
+
+        // Bounded, so the query task waits on the reader instead of running
+        // ahead of it.
+        let (tx, rx) = mpsc::channel::<io::Result<Bytes>>(64);
+        let query = self.query.clone();
+
+        tokio::spawn(async move {
+            let mut rows = sqlx::query(&query).fetch(&pool);
+            while let Some(row) = rows.next().await {
+                let chunk = row.map_err(io::Error::other).and_then(|row| {
+                    let mut line = serde_json::to_vec(&row_to_json(&row))
+                        .map_err(io::Error::other)?;
+                    line.push(b'\n');
+                    Ok(Bytes::from(line))
+                });
+                let is_err = chunk.is_err();
+                if tx.send(chunk).await.is_err() || is_err {
+                    break;
+                }
+            }
+        });
+
+        Ok(Box::new(StreamReader::new(ReceiverStream::new(rx))))
+    }
+}
+
+/// Best-effort conversion of a dynamically-typed row into a JSON object,
+/// trying each candidate column type in turn since the `Any` driver doesn't
+/// expose the database's own type info uniformly across backends.
+fn row_to_json(row: &AnyRow) -> serde_json::Value {
+    let mut map = serde_json::Map::new();
+    for (idx, column) in row.columns().iter().enumerate() {
+        let value = if let Ok(v) = row.try_get::<i64, _>(idx) {
+            serde_json::Value::from(v)
+        } else if let Ok(v) = row.try_get::<f64, _>(idx) {
+            serde_json::Value::from(v)
+        } else if let Ok(v) = row.try_get::<bool, _>(idx) {
+            serde_json::Value::from(v)
+        } else if let Ok(v) = row.try_get::<String, _>(idx) {
+            serde_json::Value::from(v)
+        } else {
+            serde_json::Value::Null
+        };
+        map.insert(column.name().to_string(), value);
+    }
+    serde_json::Value::Object(map)
+}
+
+/// Async output target that batch-inserts NDJSON records into a table.
+#[derive(Debug, Clone)]
+pub struct SqlOutput {
+    id: String,
+    connection_string: String,
+    table: String,
+}
+
+impl SqlOutput {
+    /// Create a SQL output that inserts into `table` over
+    /// `connection_string`.
+    pub fn new(connection_string: impl Into<String>, table: impl Into<String>) -> Self {
+        let connection_string = connection_string.into();
+        Self {
+            id: connection_string.clone(),
+            connection_string,
+            table: table.into(),
+        }
+    }
+
+    async fn open(&self, policy: FileExistsPolicy) -> io::Result<Box<dyn AsyncWrite + Unpin + Send>> {
+        let pool = AnyPoolOptions::new()
+            .connect(&self.connection_string)
+            .await
+            .map_err(io::Error::other)?;
+
+        if matches!(policy, FileExistsPolicy::Overwrite) {
+            sqlx::query(&format!("DELETE FROM {}", self.table))
+                .execute(&pool)
+                .await
+                .map_err(io::Error::other)?;
+        }
+
+        let (tx, mut rx) = mpsc::unbounded_channel::<serde_json::Value>();
+        let table = self.table.clone();
+        let worker: JoinHandle<io::Result<()>> = tokio::spawn(async move {
+            let mut batch = Vec::with_capacity(SQL_BATCH_SIZE);
+            while let Some(value) = rx.recv().await {
+                batch.push(value);
+                if batch.len() >= SQL_BATCH_SIZE {
+                    insert_batch(&pool, &table, &mut batch).await?;
+                }
+            }
+            insert_batch(&pool, &table, &mut batch).await
+        });
+
+        Ok(Box::new(SqlWriter {
+            tx: Some(tx),
+            line_buf: Vec::new(),
+            worker: Some(worker),
+        }))
+    }
+}
+
+#[async_trait]
+impl AsyncOutputTarget for SqlOutput {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    async fn open_overwrite(&self) -> io::Result<Box<dyn AsyncWrite + Unpin + Send>> {
+        self.open(FileExistsPolicy::Overwrite).await
+    }
+
+    async fn open_append(&self) -> io::Result<Box<dyn AsyncWrite + Unpin + Send>> {
+        self.open(FileExistsPolicy::Append).await
+    }
+}
+
+async fn insert_batch(
+    pool: &sqlx::AnyPool,
+    table: &str,
+    batch: &mut Vec<serde_json::Value>,
+) -> io::Result<()> {
+    for value in batch.drain(..) {
+        let obj = value
+            .as_object()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "expected a JSON object row"))?;
+
+        let columns: Vec<&str> = obj.keys().map(String::as_str).collect();
+        let placeholders: Vec<String> = (1..=columns.len()).map(|i| format!("${i}")).collect();
+        let sql = format!(
+            "INSERT INTO {table} ({}) VALUES ({})",
+            columns.join(", "),
+            placeholders.join(", ")
+        );
+
+        let mut query = sqlx::query(&sql);
+        for key in &columns {
+            query = bind_json_value(query, &obj[*key]);
+        }
+        query.execute(pool).await.map_err(io::Error::other)?;
+    }
+
+    Ok(())
+}
+
+fn bind_json_value<'q>(
+    query: sqlx::query::Query<'q, sqlx::Any, sqlx::any::AnyArguments<'q>>,
+    value: &'q serde_json::Value,
+) -> sqlx::query::Query<'q, sqlx::Any, sqlx::any::AnyArguments<'q>> {
+    match value {
+        serde_json::Value::Null => query.bind(None::<String>),
+        serde_json::Value::Bool(b) => query.bind(*b),
+        serde_json::Value::Number(n) => match n.as_i64() {
+            Some(i) => query.bind(i),
+            None => query.bind(n.as_f64().unwrap_or_default()),
+        },
+        serde_json::Value::String(s) => query.bind(s.as_str()),
+        other => query.bind(other.to_string()),
+    }
+}
+
+/// Write half of `SqlOutput`: buffers incoming bytes, forwards each complete
+/// NDJSON line to the background batch-insert task, and on shutdown flushes
+/// any trailing partial line before waiting for the task to drain its batch.
+struct SqlWriter {
+    tx: Option<UnboundedSender<serde_json::Value>>,
+    line_buf: Vec<u8>,
+    worker: Option<JoinHandle<io::Result<()>>>,
+}
+
+impl SqlWriter {
+    fn drain_complete_lines(&mut self) -> io::Result<()> {
+        let Some(tx) = self.tx.as_ref() else {
+            return Ok(());
+        };
+        while let Some(pos) = self.line_buf.iter().position(|&b| b == b'\n') {
+            let line: Vec<u8> = self.line_buf.drain(..=pos).collect();
+            let trimmed = &line[..line.len() - 1];
+            if trimmed.iter().all(|b| b.is_ascii_whitespace()) {
+                continue;
+            }
+            let value: serde_json::Value =
+                serde_json::from_slice(trimmed).map_err(io::Error::other)?;
+            let _ = tx.send(value);
+        }
+        Ok(())
+    }
+}
+
+impl AsyncWrite for SqlWriter {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+        this.line_buf.extend_from_slice(buf);
+        this.drain_complete_lines()?;
+        Poll::Ready(Ok(buf.len()))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+
+        if !this.line_buf.is_empty() {
+            this.drain_complete_lines()?;
+            if !this.line_buf.is_empty() {
+                if let Some(tx) = this.tx.as_ref() {
+                    if let Ok(value) = serde_json::from_slice(&this.line_buf) {
+                        let _ = tx.send(value);
+                    }
+                }
+                this.line_buf.clear();
+            }
+        }
+        // Dropping the sender lets the worker's `recv()` loop end once it
+        // has drained every record already queued.
+        this.tx.take();
+
+        let worker = match this.worker.as_mut() {
+            Some(worker) => worker,
+            None => return Poll::Ready(Ok(())),
+        };
+
+        match Pin::new(worker).poll(cx) {
+            Poll::Ready(Ok(result)) => {
+                this.worker = None;
+                Poll::Ready(result)
+            }
+            Poll::Ready(Err(e)) => {
+                this.worker = None;
+                Poll::Ready(Err(io::Error::other(e)))
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
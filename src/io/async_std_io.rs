@@ -62,6 +62,10 @@ impl AsyncInputProvider for AsyncFileInput {
         let file = tokio::fs::File::open(&self.path).await?;
         Ok(Box::new(BufReader::new(file)))
     }
+
+    fn watch_path(&self) -> Option<&std::path::Path> {
+        Some(&self.path)
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -182,4 +186,8 @@ impl AsyncOutputTarget for AsyncFileOutput {
             .await?;
         Ok(Box::new(file))
     }
+
+    fn file_path(&self) -> Option<&std::path::Path> {
+        Some(&self.path)
+    }
 }
@@ -0,0 +1,229 @@
+//! Pipes an `AsyncInputProvider`/`AsyncOutputTarget`'s bytes through an
+//! external command, so a filter like `gzip -d`, `zstd`, or a sanitizing
+//! script can sit between a source/destination and the format layer without
+//! either side knowing about it.
+//!
+//! This mirrors [`super::async_compression::AsyncCompressedInput`]/
+//! `AsyncCompressedOutput` (wrap the inner provider/target, transform what
+//! flows through it) but shells out to an arbitrary command instead of a
+//! built-in codec.
+
+use std::future::Future;
+use std::io;
+use std::pin::Pin;
+use std::process::Stdio;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use async_trait::async_trait;
+use tokio::io::{AsyncRead, AsyncWrite, AsyncWriteExt};
+use tokio::process::{Child, ChildStdin, Command};
+
+use super::async_process::AsyncProcessReader;
+use super::{AsyncInputProvider, AsyncOutputTarget};
+
+/// Wraps an `AsyncInputProvider`, feeding its bytes into `command`'s stdin on
+/// a background task and exposing the command's stdout as the downstream
+/// reader.
+#[derive(Debug)]
+pub struct AsyncTransformInput {
+    inner: Arc<dyn AsyncInputProvider>,
+    command: String,
+    args: Vec<String>,
+}
+
+impl AsyncTransformInput {
+    pub fn new(inner: Arc<dyn AsyncInputProvider>, command: impl Into<String>, args: Vec<String>) -> Self {
+        Self {
+            inner,
+            command: command.into(),
+            args,
+        }
+    }
+}
+
+#[async_trait]
+impl AsyncInputProvider for AsyncTransformInput {
+    fn id(&self) -> &str {
+        self.inner.id()
+    }
+
+    fn watch_path(&self) -> Option<&std::path::Path> {
+        self.inner.watch_path()
+    }
+
+    async fn open(&self) -> io::Result<Box<dyn AsyncRead + Unpin + Send>> {
+        let mut upstream = self.inner.open().await?;
+
+        let mut child = Command::new(&self.command)
+            .args(&self.args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()?;
+
+        let mut stdin = child
+            .stdin
+            .take()
+            .expect("stdin was configured as piped above");
+        tokio::spawn(async move {
+            let _ = tokio::io::copy(&mut upstream, &mut stdin).await;
+            let _ = stdin.shutdown().await;
+        });
+
+        let stdout = child
+            .stdout
+            .take()
+            .expect("stdout was configured as piped above");
+
+        Ok(Box::new(AsyncProcessReader::new(child, stdout)))
+    }
+}
+
+/// Wraps an `AsyncOutputTarget`, routing everything written through it into
+/// `command`'s stdin and copying the command's stdout to the real target.
+#[derive(Debug)]
+pub struct AsyncTransformOutput {
+    inner: Arc<dyn AsyncOutputTarget>,
+    command: String,
+    args: Vec<String>,
+}
+
+impl AsyncTransformOutput {
+    pub fn new(inner: Arc<dyn AsyncOutputTarget>, command: impl Into<String>, args: Vec<String>) -> Self {
+        Self {
+            inner,
+            command: command.into(),
+            args,
+        }
+    }
+
+    async fn spawn(
+        &self,
+        real_writer: Box<dyn AsyncWrite + Unpin + Send>,
+    ) -> io::Result<Box<dyn AsyncWrite + Unpin + Send>> {
+        let mut child = Command::new(&self.command)
+            .args(&self.args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()?;
+
+        let stdin = child
+            .stdin
+            .take()
+            .expect("stdin was configured as piped above");
+        let mut stdout = child
+            .stdout
+            .take()
+            .expect("stdout was configured as piped above");
+
+        let mut real_writer = real_writer;
+        let copy_task = tokio::spawn(async move {
+            tokio::io::copy(&mut stdout, &mut real_writer).await?;
+            real_writer.shutdown().await
+        });
+
+        Ok(Box::new(AsyncTransformWriter {
+            child: Some(child),
+            stdin: Some(stdin),
+            copy_task: Some(copy_task),
+            finish: None,
+        }))
+    }
+}
+
+#[async_trait]
+impl AsyncOutputTarget for AsyncTransformOutput {
+    fn id(&self) -> &str {
+        self.inner.id()
+    }
+
+    async fn open_overwrite(&self) -> io::Result<Box<dyn AsyncWrite + Unpin + Send>> {
+        self.spawn(self.inner.open_overwrite().await?).await
+    }
+
+    async fn open_append(&self) -> io::Result<Box<dyn AsyncWrite + Unpin + Send>> {
+        self.spawn(self.inner.open_append().await?).await
+    }
+
+    async fn open_overwrite_at(&self, path: &std::path::Path) -> io::Result<Box<dyn AsyncWrite + Unpin + Send>> {
+        self.spawn(self.inner.open_overwrite_at(path).await?).await
+    }
+
+    fn file_path(&self) -> Option<&std::path::Path> {
+        self.inner.file_path()
+    }
+}
+
+type ShutdownFuture = Pin<Box<dyn Future<Output = io::Result<()>> + Send>>;
+
+/// Writer that feeds a child's stdin and, on shutdown, waits for both the
+/// child to exit and the stdout-copy task to finish before reporting the
+/// write as complete, so a nonzero exit status or a failed copy to the real
+/// target surfaces as an error instead of being silently dropped.
+struct AsyncTransformWriter {
+    child: Option<Child>,
+    stdin: Option<ChildStdin>,
+    copy_task: Option<tokio::task::JoinHandle<io::Result<()>>>,
+    finish: Option<ShutdownFuture>,
+}
+
+impl AsyncWrite for AsyncTransformWriter {
+    fn poll_write(self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &[u8]) -> Poll<io::Result<usize>> {
+        let stdin = self
+            .get_mut()
+            .stdin
+            .as_mut()
+            .expect("write after shutdown");
+        Pin::new(stdin).poll_write(cx, buf)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let stdin = self
+            .get_mut()
+            .stdin
+            .as_mut()
+            .expect("flush after shutdown");
+        Pin::new(stdin).poll_flush(cx)
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        loop {
+            if let Some(finish) = this.finish.as_mut() {
+                return finish.as_mut().poll(cx);
+            }
+
+            if let Some(mut stdin) = this.stdin.take() {
+                match Pin::new(&mut stdin).poll_shutdown(cx) {
+                    Poll::Ready(Ok(())) => {}
+                    Poll::Ready(Err(e)) => return Poll::Ready(Err(e)),
+                    Poll::Pending => {
+                        this.stdin = Some(stdin);
+                        return Poll::Pending;
+                    }
+                }
+            }
+
+            let mut child = this
+                .child
+                .take()
+                .expect("poll_shutdown called again after completing");
+            let copy_task = this
+                .copy_task
+                .take()
+                .expect("poll_shutdown called again after completing");
+            this.finish = Some(Box::pin(async move {
+                let status = child.wait().await?;
+                copy_task
+                    .await
+                    .map_err(|e| io::Error::other(format!("transform copy task panicked: {e}")))??;
+                if !status.success() {
+                    return Err(io::Error::other(format!("command exited with {status}")));
+                }
+                Ok(())
+            }));
+        }
+    }
+}
@@ -0,0 +1,256 @@
+//! Transparent compression codecs for input/output specs.
+//!
+//! `InputSpec::with_compression`/`OutputSpec::with_compression` (and their
+//! async counterparts) wrap the spec's provider/target in a decorator that
+//! decompresses on read or compresses on write, so format (de)serialization
+//! never sees compressed bytes. `Compression::detect` inspects a spec's
+//! `raw` for a trailing `.gz`/`.zst`/`.zip`/`.bz2` extension (and, for zip, a
+//! `#entry` suffix naming the archive member) to pick a codec automatically,
+//! returning the name format-candidate resolution should use in its place.
+//!
+//! `CompressedInput` wraps the opened reader in a streaming decoder (rather
+//! than decompressing eagerly into a buffer), so `IoEngine::read_stream`
+//! stays incremental over compressed sources too - the one exception is the
+//! zip codec, which must read the whole archive to locate its central
+//! directory before a member's bytes can be extracted.
+//! `CompressedOutput::file_path` delegates to the wrapped target, so
+//! `FileExistsPolicy` (including `AtomicOverwrite`) checks existence against
+//! the real on-disk compressed path, not a virtual uncompressed one.
+//! `CompressedOutput::open_overwrite_at` delegates to the wrapped target too
+//! (then compresses), so `AtomicOverwrite`'s temp-file write goes through the
+//! same codec as a normal write - the temp file is compressed bytes, not a
+//! raw copy of the plaintext later renamed into place.
+
+use std::io::{Cursor, Read, Write};
+use std::sync::Arc;
+
+use super::{InputProvider, OutputTarget};
+
+/// Compression codec applied transparently around an input/output spec's
+/// provider/target. Each variant carries the codec's own compression level so
+/// callers can tune the size/speed trade-off; `detect` picks each codec's own
+/// "balanced default" level.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Compression {
+    Gzip(u32),
+    Zstd(i32),
+    Bzip2(u32),
+    /// `entry` is the archive member to read from or write to. `None` reads
+    /// the archive's first entry; writing requires a named entry.
+    Zip { entry: Option<String>, level: i64 },
+}
+
+impl Compression {
+    /// Gzip at the given `flate2` compression level (0-9).
+    pub fn gzip(level: u32) -> Self {
+        Compression::Gzip(level)
+    }
+
+    /// Zstandard at the given `zstd` compression level (typically 1-22).
+    pub fn zstd(level: i32) -> Self {
+        Compression::Zstd(level)
+    }
+
+    /// Bzip2 at the given `bzip2` compression level (0-9).
+    pub fn bzip2(level: u32) -> Self {
+        Compression::Bzip2(level)
+    }
+
+    /// A single named entry within a zip archive, at the given `zip`
+    /// compression level. `entry` selects which archive member to read from
+    /// or write to; pass `None` to read an archive's first entry (writing
+    /// always requires a name).
+    pub fn zip(entry: impl Into<Option<String>>, level: i64) -> Self {
+        Compression::Zip {
+            entry: entry.into(),
+            level,
+        }
+    }
+
+    /// Detects a compression codec from `raw`'s trailing extension, splitting
+    /// off a zip `#entry` suffix first if present (e.g.
+    /// `"archive.zip#data.csv"`). Returns the codec at its default level
+    /// alongside the name format-candidate resolution should use instead of
+    /// `raw`: the archive member for zip, or `raw` with the compression
+    /// suffix stripped for everything else.
+    pub fn detect(raw: &str) -> Option<(Compression, String)> {
+        let (base, entry) = match raw.split_once('#') {
+            Some((b, e)) => (b, Some(e.to_string())),
+            None => (raw, None),
+        };
+        let lower = base.to_ascii_lowercase();
+
+        if let Some(stripped) = lower.strip_suffix(".gz") {
+            return Some((Compression::gzip(6), base[..stripped.len()].to_string()));
+        }
+        if let Some(stripped) = lower.strip_suffix(".zst") {
+            return Some((Compression::zstd(3), base[..stripped.len()].to_string()));
+        }
+        if let Some(stripped) = lower.strip_suffix(".bz2") {
+            return Some((Compression::bzip2(6), base[..stripped.len()].to_string()));
+        }
+        if lower.ends_with(".zip") {
+            let format_hint = entry.clone().unwrap_or_else(|| base.to_string());
+            return Some((Compression::zip(entry, 6), format_hint));
+        }
+
+        None
+    }
+}
+
+/// Wraps an `InputProvider`, transparently decompressing the underlying
+/// stream according to `compression`.
+#[derive(Debug)]
+pub struct CompressedInput {
+    inner: Arc<dyn InputProvider>,
+    compression: Compression,
+}
+
+impl CompressedInput {
+    pub fn new(inner: Arc<dyn InputProvider>, compression: Compression) -> Self {
+        Self { inner, compression }
+    }
+}
+
+impl InputProvider for CompressedInput {
+    fn id(&self) -> &str {
+        self.inner.id()
+    }
+
+    fn open(&self) -> std::io::Result<Box<dyn Read + Send>> {
+        let reader = self.inner.open()?;
+        match &self.compression {
+            Compression::Gzip(_) => Ok(Box::new(flate2::read::GzDecoder::new(reader))),
+            Compression::Zstd(_) => Ok(Box::new(zstd::stream::read::Decoder::new(reader)?)),
+            Compression::Bzip2(_) => Ok(Box::new(bzip2::read::BzDecoder::new(reader))),
+            Compression::Zip { entry, .. } => {
+                let mut reader = reader;
+                let mut archive_bytes = Vec::new();
+                reader.read_to_end(&mut archive_bytes)?;
+
+                let mut archive = zip::ZipArchive::new(Cursor::new(archive_bytes))
+                    .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+                let mut entry_file = match entry {
+                    Some(name) => archive
+                        .by_name(name)
+                        .map_err(|e| std::io::Error::new(std::io::ErrorKind::NotFound, e))?,
+                    None => archive
+                        .by_index(0)
+                        .map_err(|e| std::io::Error::new(std::io::ErrorKind::NotFound, e))?,
+                };
+
+                let mut contents = Vec::new();
+                entry_file.read_to_end(&mut contents)?;
+                Ok(Box::new(Cursor::new(contents)))
+            }
+        }
+    }
+}
+
+/// Wraps an `OutputTarget`, transparently compressing everything written
+/// through it according to `compression`.
+#[derive(Debug)]
+pub struct CompressedOutput {
+    inner: Arc<dyn OutputTarget>,
+    compression: Compression,
+}
+
+impl CompressedOutput {
+    pub fn new(inner: Arc<dyn OutputTarget>, compression: Compression) -> Self {
+        Self { inner, compression }
+    }
+
+    fn wrap(&self, writer: Box<dyn Write + Send>) -> std::io::Result<Box<dyn Write + Send>> {
+        match &self.compression {
+            Compression::Gzip(level) => Ok(Box::new(flate2::write::GzEncoder::new(
+                writer,
+                flate2::Compression::new(*level),
+            ))),
+            Compression::Zstd(level) => {
+                Ok(Box::new(zstd::stream::write::Encoder::new(writer, *level)?.auto_finish()))
+            }
+            Compression::Bzip2(level) => Ok(Box::new(bzip2::write::BzEncoder::new(
+                writer,
+                bzip2::Compression::new(*level),
+            ))),
+            Compression::Zip { entry, level } => {
+                let name = entry.clone().ok_or_else(|| {
+                    std::io::Error::new(
+                        std::io::ErrorKind::InvalidInput,
+                        "zip output requires a named entry, e.g. 'archive.zip#data.csv'",
+                    )
+                })?;
+                Ok(Box::new(ZipEntryWriter::new(writer, name, *level)?))
+            }
+        }
+    }
+}
+
+impl OutputTarget for CompressedOutput {
+    fn id(&self) -> &str {
+        self.inner.id()
+    }
+
+    fn open_overwrite(&self) -> std::io::Result<Box<dyn Write + Send>> {
+        self.wrap(self.inner.open_overwrite()?)
+    }
+
+    fn open_append(&self) -> std::io::Result<Box<dyn Write + Send>> {
+        self.wrap(self.inner.open_append()?)
+    }
+
+    fn open_overwrite_at(&self, path: &std::path::Path) -> std::io::Result<Box<dyn Write + Send>> {
+        self.wrap(self.inner.open_overwrite_at(path)?)
+    }
+
+    fn file_path(&self) -> Option<&std::path::Path> {
+        self.inner.file_path()
+    }
+}
+
+/// Writes a single named entry into a zip archive. Unlike the gzip/zstd/bzip2
+/// encoders, `zip::ZipWriter` doesn't finish the central directory on drop, so
+/// this wrapper does it explicitly once the engine is done writing and drops
+/// the `Box<dyn Write>` it was handed.
+struct ZipEntryWriter<W: Write + Send> {
+    writer: Option<zip::ZipWriter<W>>,
+}
+
+impl<W: Write + Send> ZipEntryWriter<W> {
+    fn new(writer: W, name: String, level: i64) -> std::io::Result<Self> {
+        let mut zip_writer = zip::ZipWriter::new(writer);
+        let options = zip::write::FileOptions::default()
+            .compression_method(zip::CompressionMethod::Deflated)
+            .compression_level(Some(level));
+        zip_writer
+            .start_file(name, options)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e))?;
+        Ok(Self {
+            writer: Some(zip_writer),
+        })
+    }
+}
+
+impl<W: Write + Send> Write for ZipEntryWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.writer
+            .as_mut()
+            .expect("ZipEntryWriter used after finish")
+            .write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.writer
+            .as_mut()
+            .expect("ZipEntryWriter used after finish")
+            .flush()
+    }
+}
+
+impl<W: Write + Send> Drop for ZipEntryWriter<W> {
+    fn drop(&mut self) {
+        if let Some(mut zip_writer) = self.writer.take() {
+            let _ = zip_writer.finish();
+        }
+    }
+}
@@ -0,0 +1,253 @@
+//! HTTP(S) input/output providers, behind the optional `http` feature:
+//! bridge a remote endpoint and the engine's byte-stream abstractions so a
+//! GET response or POST/PUT body flows through the same `Read`/`Write`
+//! machinery as a file.
+//!
+//! `HttpInput::open` performs a blocking GET and returns the response body
+//! as a streaming `Read`; a non-2xx status surfaces as an `io::Error`.
+//! `HttpOutput::open_overwrite` sends the written bytes as a single
+//! POST or PUT request as soon as the engine's one `write_all` call delivers
+//! them, so the request only ever carries a complete payload. Most HTTP
+//! servers have no notion of appending to an existing resource, so
+//! `open_append` fails with `io::ErrorKind::Unsupported` rather than
+//! guessing at a `Content-Range` convention a given server may not honor.
+
+use std::io::{self, Read, Write};
+use std::time::Duration;
+
+use ureq::Agent;
+
+use super::{InputProvider, OutputTarget};
+
+/// HTTP method `HttpOutput::open_overwrite` sends the payload with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HttpWriteMethod {
+    Post,
+    Put,
+}
+
+#[derive(Debug, Clone, Default)]
+struct HttpAuth {
+    user: String,
+    password: String,
+}
+
+/// Base64-encodes `bytes` for a `Basic` `Authorization` header,
+/// avoiding a dependency on a whole base64 crate for one header value.
+fn base64_encode(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let b0 = chunk[0];
+        let b1 = chunk.get(1).copied();
+        let b2 = chunk.get(2).copied();
+
+        out.push(ALPHABET[(b0 >> 2) as usize] as char);
+        out.push(ALPHABET[((b0 & 0x03) << 4 | b1.unwrap_or(0) >> 4) as usize] as char);
+        out.push(match b1 {
+            Some(b1) => ALPHABET[((b1 & 0x0f) << 2 | b2.unwrap_or(0) >> 6) as usize] as char,
+            None => '=',
+        });
+        out.push(match b2 {
+            Some(b2) => ALPHABET[(b2 & 0x3f) as usize] as char,
+            None => '=',
+        });
+    }
+    out
+}
+
+fn build_agent(timeout: Option<Duration>) -> Agent {
+    let mut builder = ureq::AgentBuilder::new();
+    if let Some(timeout) = timeout {
+        builder = builder.timeout(timeout);
+    }
+    builder.build()
+}
+
+/// Performs a lightweight `HEAD` request to read the response's
+/// `Content-Type` header (stripped of any `; charset=...` parameter and
+/// lowercased), without downloading the body. Used by
+/// `MultiioBuilder::resolve_single_input` to infer a format when a `http(s)://`
+/// URL's own path has no recognized extension. Any failure (network error,
+/// non-2xx status, missing header) is treated as "unknown" rather than
+/// surfaced, since format inference here is best-effort.
+pub(crate) fn probe_content_type(url: &str, timeout: Option<Duration>) -> Option<String> {
+    let response = build_agent(timeout).head(url).call().ok()?;
+    let content_type = response.header("Content-Type")?;
+    Some(
+        content_type
+            .split(';')
+            .next()
+            .unwrap_or(content_type)
+            .trim()
+            .to_ascii_lowercase(),
+    )
+}
+
+fn basic_auth_header(auth: &HttpAuth) -> String {
+    format!(
+        "Basic {}",
+        base64_encode(format!("{}:{}", auth.user, auth.password).as_bytes())
+    )
+}
+
+/// Input provider that performs a GET request and streams the response body.
+#[derive(Debug, Clone)]
+pub struct HttpInput {
+    url: String,
+    headers: Vec<(String, String)>,
+    auth: Option<HttpAuth>,
+    timeout: Option<Duration>,
+}
+
+impl HttpInput {
+    /// Create an HTTP input that GETs `url` on every `open()`.
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            headers: Vec::new(),
+            auth: None,
+            timeout: None,
+        }
+    }
+
+    /// Add a request header.
+    pub fn with_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Send HTTP basic auth credentials with every request.
+    pub fn with_basic_auth(mut self, user: impl Into<String>, password: impl Into<String>) -> Self {
+        self.auth = Some(HttpAuth {
+            user: user.into(),
+            password: password.into(),
+        });
+        self
+    }
+
+    /// Set the request timeout (connect + read combined).
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+}
+
+impl InputProvider for HttpInput {
+    fn id(&self) -> &str {
+        &self.url
+    }
+
+    fn open(&self) -> io::Result<Box<dyn Read + Send>> {
+        let mut request = build_agent(self.timeout).get(&self.url);
+        for (name, value) in &self.headers {
+            request = request.set(name, value);
+        }
+        if let Some(auth) = &self.auth {
+            request = request.set("Authorization", &basic_auth_header(auth));
+        }
+
+        let response = request.call().map_err(io::Error::other)?;
+        Ok(response.into_reader())
+    }
+}
+
+/// Output target that sends written bytes as an HTTP POST/PUT request body.
+#[derive(Debug, Clone)]
+pub struct HttpOutput {
+    url: String,
+    headers: Vec<(String, String)>,
+    auth: Option<HttpAuth>,
+    timeout: Option<Duration>,
+    method: HttpWriteMethod,
+}
+
+impl HttpOutput {
+    /// Create an HTTP output that POSTs to `url` on `open_overwrite`.
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            headers: Vec::new(),
+            auth: None,
+            timeout: None,
+            method: HttpWriteMethod::Post,
+        }
+    }
+
+    /// Add a request header.
+    pub fn with_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Send HTTP basic auth credentials with every request.
+    pub fn with_basic_auth(mut self, user: impl Into<String>, password: impl Into<String>) -> Self {
+        self.auth = Some(HttpAuth {
+            user: user.into(),
+            password: password.into(),
+        });
+        self
+    }
+
+    /// Set the request timeout (connect + read combined).
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Use PUT instead of the default POST for `open_overwrite`.
+    pub fn with_method(mut self, method: HttpWriteMethod) -> Self {
+        self.method = method;
+        self
+    }
+}
+
+impl OutputTarget for HttpOutput {
+    fn id(&self) -> &str {
+        &self.url
+    }
+
+    fn open_overwrite(&self) -> io::Result<Box<dyn Write + Send>> {
+        Ok(Box::new(HttpWriter {
+            target: self.clone(),
+        }))
+    }
+
+    fn open_append(&self) -> io::Result<Box<dyn Write + Send>> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "HTTP output does not support appending to an existing resource",
+        ))
+    }
+}
+
+/// Writer that sends its entire payload as one request the first (and only)
+/// time `write()` is called with it, rather than buffering incrementally:
+/// the engine always delivers a document's full serialized bytes in a single
+/// `write_all` call, so there is nothing to accumulate across calls.
+struct HttpWriter {
+    target: HttpOutput,
+}
+
+impl Write for HttpWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let agent = build_agent(self.target.timeout);
+        let mut request = match self.target.method {
+            HttpWriteMethod::Post => agent.post(&self.target.url),
+            HttpWriteMethod::Put => agent.put(&self.target.url),
+        };
+        for (name, value) in &self.target.headers {
+            request = request.set(name, value);
+        }
+        if let Some(auth) = &self.target.auth {
+            request = request.set("Authorization", &basic_auth_header(auth));
+        }
+
+        request.send_bytes(buf).map_err(io::Error::other)?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
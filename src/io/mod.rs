@@ -5,32 +5,107 @@
 //! - `OutputTarget`: Trait for output destinations
 //! - Standard implementations for files, stdin/stdout
 //! - In-memory implementations for testing
+//! - `ProcessInput`/`ProcessOutput`: external-command adapters that bridge a
+//!   subprocess's stdio
+//! - `SocketInput`/`SocketOutput`: NDJSON-framed adapters over a TCP or Unix
+//!   domain socket connection
+//! - `Compression`/`CompressedInput`/`CompressedOutput`: transparent
+//!   gzip/zstd/zip/bzip2 wrapping for any provider/target
+//! - `SecretKey`/`EncryptedInput`/`EncryptedOutput` (feature `encryption`):
+//!   transparent AES-256-GCM wrapping for any provider/target, composable
+//!   with `CompressedInput`/`CompressedOutput`
+//! - `AsyncResourcePool`/`PooledInput`: async input backed by a caller-owned
+//!   connection pool instead of a fresh handle per `open()`
+//! - `SchemeRegistry`/`AsyncSchemeRegistry`: maps a `scheme://` CLI token
+//!   prefix to a caller-supplied provider/target factory
+//! - `SqlInput`/`SqlOutput` (feature `db`): stream a query's rows in as
+//!   NDJSON, batch-insert NDJSON records out to a table
+//! - `AsyncTransformInput`/`AsyncTransformOutput`: pipe a provider's/target's
+//!   bytes through an external command (decompress, sanitize, transcode, ...)
+//! - `HttpInput`/`HttpOutput` (feature `http`): blocking GET input and
+//!   POST/PUT output against a remote URL
+//! - `SftpInput`/`SftpOutput` (feature `ssh`): stream a remote file over an
+//!   SFTP session pooled by `user@host:port`
 
 mod input;
 mod memory;
 mod output;
+mod process;
+mod scheme;
+mod socket;
 mod std_io;
 
+#[cfg(feature = "compression")]
+mod compression;
+#[cfg(feature = "encryption")]
+mod encryption;
+#[cfg(feature = "http")]
+mod http;
+#[cfg(feature = "ssh")]
+mod sftp;
+
 pub use input::InputProvider;
 #[cfg(feature = "async")]
 pub use memory::AsyncInMemorySource;
 pub use memory::{InMemorySink, InMemorySource};
 pub use output::OutputTarget;
+pub use process::{ProcessInput, ProcessOutput};
+pub use scheme::SchemeRegistry;
+pub use socket::{Framing, NdjsonFraming, SocketInput, SocketOutput};
 pub use std_io::{FileInput, FileOutput, StderrOutput, StdinInput, StdoutOutput};
 
+#[cfg(feature = "compression")]
+pub use compression::{CompressedInput, CompressedOutput, Compression};
+#[cfg(feature = "encryption")]
+pub use encryption::{EncryptedInput, EncryptedOutput, SecretKey};
+#[cfg(feature = "http")]
+pub use http::{HttpInput, HttpOutput, HttpWriteMethod};
+#[cfg(feature = "http")]
+pub(crate) use http::probe_content_type;
+#[cfg(feature = "ssh")]
+pub use sftp::{SftpInput, SftpOutput, SshAuth};
+
 // Async I/O support
 #[cfg(feature = "async")]
 mod async_input;
 #[cfg(feature = "async")]
 mod async_output;
 #[cfg(feature = "async")]
+mod async_pool;
+#[cfg(feature = "async")]
+mod async_process;
+#[cfg(feature = "async")]
+mod async_scheme;
+#[cfg(feature = "async")]
+mod async_socket;
+#[cfg(feature = "async")]
 mod async_std_io;
+#[cfg(all(feature = "async", feature = "compression"))]
+mod async_compression;
+#[cfg(feature = "async")]
+mod async_transform;
+#[cfg(all(feature = "async", feature = "db"))]
+mod async_sql;
 
 #[cfg(feature = "async")]
 pub use async_input::AsyncInputProvider;
+#[cfg(all(feature = "async", feature = "compression"))]
+pub use async_compression::{AsyncCompressedInput, AsyncCompressedOutput};
 #[cfg(feature = "async")]
 pub use async_output::AsyncOutputTarget;
 #[cfg(feature = "async")]
+pub use async_scheme::AsyncSchemeRegistry;
+#[cfg(feature = "async")]
+pub use async_pool::{AsyncResourcePool, PooledInput};
+#[cfg(feature = "async")]
+pub use async_process::{AsyncProcessInput, AsyncProcessOutput};
+#[cfg(feature = "async")]
+pub use async_transform::{AsyncTransformInput, AsyncTransformOutput};
+#[cfg(feature = "async")]
+pub use async_socket::{AsyncFraming, AsyncNdjsonFraming, AsyncSocketInput, AsyncSocketOutput};
+#[cfg(all(feature = "async", feature = "db"))]
+pub use async_sql::{SqlInput, SqlOutput};
+#[cfg(feature = "async")]
 pub use async_std_io::{
     AsyncFileInput, AsyncFileOutput, AsyncStderrOutput, AsyncStdinInput, AsyncStdoutOutput,
 };
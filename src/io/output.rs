@@ -2,6 +2,7 @@
 
 use std::fmt::Debug;
 use std::io::Write;
+use std::path::Path;
 
 /// Trait for synchronous output targets.
 ///
@@ -19,4 +20,31 @@ pub trait OutputTarget: Send + Sync + Debug {
 
     /// Open the target for appending to existing content.
     fn open_append(&self) -> std::io::Result<Box<dyn Write + Send>>;
+
+    /// Open a writer at an arbitrary `path` rather than this target's own,
+    /// applying whatever transform this target would normally wrap around
+    /// its content (compression, encryption, ...).
+    ///
+    /// This exists for `FileExistsPolicy::AtomicOverwrite`'s write-temp-then-
+    /// rename dance: the temp file lives at a different path from
+    /// `file_path()`, but a wrapping target (`CompressedOutput`,
+    /// `EncryptedOutput`) still needs to transform the bytes written to it.
+    /// The default just creates a plain file at `path`, which is correct for
+    /// targets with no wrapping of their own (including `FileOutput`, whose
+    /// `open_overwrite` does the same thing against its own path).
+    fn open_overwrite_at(&self, path: &Path) -> std::io::Result<Box<dyn Write + Send>> {
+        Ok(Box::new(std::fs::File::create(path)?))
+    }
+
+    /// Returns the filesystem path backing this target, if any.
+    ///
+    /// File-backed targets override this so the engine can check for
+    /// existence (`FileExistsPolicy::Error`) and perform a write-temp-then-
+    /// rename dance (`FileExistsPolicy::AtomicOverwrite`). Targets with no
+    /// real file behind them (stdout/stderr, in-memory buffers, processes,
+    /// sockets) keep the default of `None`, for which those policies fall
+    /// back to a plain overwrite.
+    fn file_path(&self) -> Option<&Path> {
+        None
+    }
 }
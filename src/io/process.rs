@@ -0,0 +1,245 @@
+//! External-command adapters: treat a subprocess's stdio as an input source
+//! or output target.
+//!
+//! `ProcessInput` spawns a command and exposes its stdout as the readable
+//! stream returned by `open()`, optionally feeding a fixed payload to its
+//! stdin first. `ProcessOutput` spawns a command and returns a writer wired
+//! to its stdin, so records are piped through an external filter (decompress,
+//! strip metadata, transcode, …) before landing at their real destination.
+
+use std::io::{self, Read, Write};
+use std::process::{Child, Command, Stdio};
+
+use super::{InputProvider, OutputTarget};
+
+/// Input provider that runs an external command and reads its stdout.
+#[derive(Debug, Clone)]
+pub struct ProcessInput {
+    id: String,
+    command: String,
+    args: Vec<String>,
+    stdin_payload: Option<Vec<u8>>,
+}
+
+impl ProcessInput {
+    /// Create a new process input that runs `command` with no arguments.
+    pub fn new(command: impl Into<String>) -> Self {
+        let command = command.into();
+        Self {
+            id: command.clone(),
+            command,
+            args: Vec::new(),
+            stdin_payload: None,
+        }
+    }
+
+    /// Set the command's arguments.
+    pub fn with_args(mut self, args: Vec<String>) -> Self {
+        self.args = args;
+        self
+    }
+
+    /// Feed `payload` to the command's stdin before reading its stdout.
+    pub fn with_stdin(mut self, payload: impl Into<Vec<u8>>) -> Self {
+        self.stdin_payload = Some(payload.into());
+        self
+    }
+}
+
+impl InputProvider for ProcessInput {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn open(&self) -> io::Result<Box<dyn Read + Send>> {
+        let mut cmd = Command::new(&self.command);
+        cmd.args(&self.args);
+        cmd.stdout(Stdio::piped());
+        cmd.stderr(Stdio::null());
+        cmd.stdin(if self.stdin_payload.is_some() {
+            Stdio::piped()
+        } else {
+            Stdio::null()
+        });
+
+        let mut child = cmd.spawn()?;
+
+        if let Some(payload) = self.stdin_payload.clone() {
+            let mut stdin = child
+                .stdin
+                .take()
+                .expect("stdin was configured as piped above");
+            std::thread::spawn(move || {
+                let _ = stdin.write_all(&payload);
+            });
+        }
+
+        let stdout = child
+            .stdout
+            .take()
+            .expect("stdout was configured as piped above");
+
+        Ok(Box::new(ProcessReader {
+            child,
+            stdout,
+            reaped: false,
+        }))
+    }
+}
+
+/// Reader that wraps a child process's stdout and reaps the child on EOF (or
+/// on drop, if the caller stops reading early), surfacing a nonzero exit
+/// status as an `io::Error` on the read that observes EOF.
+struct ProcessReader {
+    child: Child,
+    stdout: std::process::ChildStdout,
+    reaped: bool,
+}
+
+impl Read for ProcessReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.stdout.read(buf)?;
+        if n == 0 && !self.reaped {
+            self.reaped = true;
+            let status = self.child.wait()?;
+            if !status.success() {
+                return Err(io::Error::other(format!(
+                    "command exited with {status}"
+                )));
+            }
+        }
+        Ok(n)
+    }
+}
+
+impl Drop for ProcessReader {
+    fn drop(&mut self) {
+        // The caller may stop reading before EOF; kill and reap so the child
+        // is never left behind as a zombie/orphan.
+        if !self.reaped {
+            let _ = self.child.kill();
+            let _ = self.child.wait();
+        }
+    }
+}
+
+/// Output target that runs an external command and writes to its stdin.
+#[derive(Debug, Clone)]
+pub struct ProcessOutput {
+    id: String,
+    command: String,
+    args: Vec<String>,
+}
+
+impl ProcessOutput {
+    /// Create a new process output that runs `command` with no arguments.
+    pub fn new(command: impl Into<String>) -> Self {
+        let command = command.into();
+        Self {
+            id: command.clone(),
+            command,
+            args: Vec::new(),
+        }
+    }
+
+    /// Set the command's arguments.
+    pub fn with_args(mut self, args: Vec<String>) -> Self {
+        self.args = args;
+        self
+    }
+
+    fn spawn(&self) -> io::Result<Child> {
+        Command::new(&self.command)
+            .args(&self.args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+    }
+}
+
+impl OutputTarget for ProcessOutput {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    // `open_append`/`open_overwrite` are identical: a subprocess has no
+    // concept of truncate-vs-append, it just reads whatever arrives on stdin.
+    fn open_overwrite(&self) -> io::Result<Box<dyn Write + Send>> {
+        let mut child = self.spawn()?;
+        let stdin = child
+            .stdin
+            .take()
+            .expect("stdin was configured as piped above");
+        Ok(Box::new(ProcessWriter {
+            child,
+            stdin: Some(stdin),
+            finished: false,
+        }))
+    }
+
+    fn open_append(&self) -> io::Result<Box<dyn Write + Send>> {
+        self.open_overwrite()
+    }
+}
+
+/// Writer that wraps a child process's stdin and reaps the child once
+/// finished, surfacing a nonzero exit status as an error.
+struct ProcessWriter {
+    child: Child,
+    stdin: Option<std::process::ChildStdin>,
+    finished: bool,
+}
+
+impl ProcessWriter {
+    /// Closes stdin (signaling EOF to the child) and waits for it to exit,
+    /// surfacing a nonzero exit status as an error. Idempotent: later calls
+    /// after the first are a no-op `Ok(())`.
+    ///
+    /// `stdin` must be dropped *before* `wait()`, otherwise a well-behaved
+    /// filter that reads its input to completion before producing output
+    /// would never see EOF, and `wait()` would hang forever.
+    fn finish(&mut self) -> io::Result<()> {
+        if self.finished {
+            return Ok(());
+        }
+        self.finished = true;
+        self.stdin.take();
+        let status = self.child.wait()?;
+        if !status.success() {
+            return Err(io::Error::other(format!("command exited with {status}")));
+        }
+        Ok(())
+    }
+}
+
+impl Write for ProcessWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.stdin
+            .as_mut()
+            .expect("write after finish")
+            .write(buf)
+    }
+
+    /// Flushes pending bytes, then - since the plain `Write` trait gives
+    /// callers no other hook to observe completion - finishes the child
+    /// (close stdin, wait, check status) and surfaces a nonzero exit as an
+    /// error. Safe to call more than once; only the first call after the
+    /// last successful write does the finishing work.
+    fn flush(&mut self) -> io::Result<()> {
+        if let Some(stdin) = self.stdin.as_mut() {
+            stdin.flush()?;
+        }
+        self.finish()
+    }
+}
+
+impl Drop for ProcessWriter {
+    fn drop(&mut self) {
+        // Best-effort safety net for a writer that was never explicitly
+        // flushed: still close stdin and reap the child so it's never left
+        // behind as a zombie/orphan, but there's no way to report an error
+        // from here.
+        let _ = self.finish();
+    }
+}
@@ -0,0 +1,78 @@
+//! Pluggable resolution of `scheme://rest` CLI tokens to arbitrary providers.
+//!
+//! `SchemeRegistry` maps a scheme name (the part of a token before `://`) to a
+//! factory that builds an `InputProvider`/`OutputTarget` from the remainder of
+//! the token. `MultiioBuilder::register_input_scheme`/`register_output_scheme`
+//! populate it, and `resolve_single_input`/`resolve_single_output` consult it
+//! for any token containing `://` that no builtin scheme claims first
+//! (`tcp://`, plus `http(s)://` and `ssh://` when the `http`/`ssh` features
+//! are enabled), so callers can make `s3://bucket/key`, `db://...`, etc.
+//! resolve to their own backend without touching CLI parsing.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+use super::{InputProvider, OutputTarget};
+
+type InputFactory =
+    Arc<dyn Fn(&str) -> std::io::Result<Arc<dyn InputProvider>> + Send + Sync>;
+type OutputFactory =
+    Arc<dyn Fn(&str) -> std::io::Result<Arc<dyn OutputTarget>> + Send + Sync>;
+
+/// Registry of scheme name -> provider/target factory, consulted by
+/// `MultiioBuilder` for any CLI token of the form `scheme://rest`.
+#[derive(Clone, Default)]
+pub struct SchemeRegistry {
+    inputs: HashMap<String, InputFactory>,
+    outputs: HashMap<String, OutputFactory>,
+}
+
+impl fmt::Debug for SchemeRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SchemeRegistry")
+            .field("input_schemes", &self.inputs.keys().collect::<Vec<_>>())
+            .field("output_schemes", &self.outputs.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+impl SchemeRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a factory for `scheme://rest` input tokens. `factory` is
+    /// called with the part of the token after `scheme://`.
+    pub fn register_input_scheme<F>(&mut self, scheme: impl Into<String>, factory: F)
+    where
+        F: Fn(&str) -> std::io::Result<Arc<dyn InputProvider>> + Send + Sync + 'static,
+    {
+        self.inputs.insert(scheme.into(), Arc::new(factory));
+    }
+
+    /// Register a factory for `scheme://rest` output tokens. `factory` is
+    /// called with the part of the token after `scheme://`.
+    pub fn register_output_scheme<F>(&mut self, scheme: impl Into<String>, factory: F)
+    where
+        F: Fn(&str) -> std::io::Result<Arc<dyn OutputTarget>> + Send + Sync + 'static,
+    {
+        self.outputs.insert(scheme.into(), Arc::new(factory));
+    }
+
+    /// If `raw` is `scheme://rest` for a registered input scheme, runs its
+    /// factory and returns the result. Returns `None` (not an error) when no
+    /// scheme matches, so callers can fall through to other resolution.
+    pub fn resolve_input(&self, raw: &str) -> Option<std::io::Result<Arc<dyn InputProvider>>> {
+        let (scheme, rest) = raw.split_once("://")?;
+        self.inputs.get(scheme).map(|factory| factory(rest))
+    }
+
+    /// If `raw` is `scheme://rest` for a registered output scheme, runs its
+    /// factory and returns the result. Returns `None` (not an error) when no
+    /// scheme matches, so callers can fall through to other resolution.
+    pub fn resolve_output(&self, raw: &str) -> Option<std::io::Result<Arc<dyn OutputTarget>>> {
+        let (scheme, rest) = raw.split_once("://")?;
+        self.outputs.get(scheme).map(|factory| factory(rest))
+    }
+}
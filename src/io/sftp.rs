@@ -0,0 +1,283 @@
+//! SSH/SFTP remote file provider/target, behind the optional `ssh` feature.
+//!
+//! `SftpInput`/`SftpOutput` parse a `ssh://user@host[:port]/path` target the
+//! same way [`super::HttpInput`]/[`super::HttpOutput`] parse a `http(s)://`
+//! one, and stream the remote file through the same `Box<dyn Read/Write +
+//! Send>` interface `FileInput`/`FileOutput` use, so no temporary local copy
+//! is ever staged. `open()` reuses an authenticated session from a process-
+//! wide pool keyed by `user@host:port` instead of re-handshaking on every
+//! call; a session that's no longer authenticated (e.g. the server dropped
+//! an idle connection) is transparently replaced.
+//!
+//! `open_overwrite` truncates the remote file (`Sftp::create`);
+//! `open_append` opens it with the SFTP protocol's own `APPEND` flag, which
+//! positions every write at the file's current end without requiring an
+//! explicit seek.
+
+use std::collections::HashMap;
+use std::io::{self, Read, Write};
+use std::net::TcpStream;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, OnceLock};
+
+use ssh2::{OpenFlags, OpenType, Session};
+
+use super::{InputProvider, OutputTarget};
+
+/// How `connect` authenticates a new SSH session. Defaults to the running
+/// user's `ssh-agent`; `with_private_key` switches to key-file auth.
+#[derive(Clone)]
+pub enum SshAuth {
+    Agent,
+    KeyFile {
+        private_key: PathBuf,
+        public_key: Option<PathBuf>,
+        passphrase: Option<String>,
+    },
+}
+
+impl std::fmt::Debug for SshAuth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SshAuth::Agent => f.write_str("SshAuth::Agent"),
+            SshAuth::KeyFile { private_key, public_key, .. } => f
+                .debug_struct("SshAuth::KeyFile")
+                .field("private_key", private_key)
+                .field("public_key", public_key)
+                .field("passphrase", &"<redacted>")
+                .finish(),
+        }
+    }
+}
+
+/// A parsed `ssh://user@host[:port]/path` target.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct SshUrl {
+    user: String,
+    host: String,
+    port: u16,
+    path: String,
+}
+
+impl SshUrl {
+    fn parse(raw: &str) -> Option<Self> {
+        let rest = raw.strip_prefix("ssh://")?;
+        let (authority, path) = rest.split_once('/')?;
+        let (user, host_port) = authority.split_once('@')?;
+        let (host, port) = match host_port.split_once(':') {
+            Some((host, port)) => (host.to_string(), port.parse().ok()?),
+            None => (host_port.to_string(), 22),
+        };
+        Some(Self {
+            user: user.to_string(),
+            host,
+            port,
+            path: format!("/{path}"),
+        })
+    }
+
+    fn pool_key(&self) -> String {
+        format!("{}@{}:{}", self.user, self.host, self.port)
+    }
+}
+
+type SessionPool = Mutex<HashMap<String, Arc<Mutex<Session>>>>;
+
+fn session_pool() -> &'static SessionPool {
+    static POOL: OnceLock<SessionPool> = OnceLock::new();
+    POOL.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Returns a pooled, authenticated session for `url`, handshaking a new one
+/// (and replacing any stale pooled entry) if none is cached yet.
+fn connect(url: &SshUrl, auth: &SshAuth) -> io::Result<Arc<Mutex<Session>>> {
+    let key = url.pool_key();
+
+    if let Some(session) = session_pool().lock().unwrap().get(&key) {
+        if session.lock().unwrap().authenticated() {
+            return Ok(session.clone());
+        }
+    }
+
+    let session = Arc::new(Mutex::new(open_session(url, auth)?));
+    session_pool().lock().unwrap().insert(key, session.clone());
+    Ok(session)
+}
+
+fn open_session(url: &SshUrl, auth: &SshAuth) -> io::Result<Session> {
+    let tcp = TcpStream::connect((url.host.as_str(), url.port))?;
+    let mut session = Session::new().map_err(io::Error::other)?;
+    session.set_tcp_stream(tcp);
+    session.handshake().map_err(io::Error::other)?;
+
+    match auth {
+        SshAuth::Agent => session
+            .userauth_agent(&url.user)
+            .map_err(io::Error::other)?,
+        SshAuth::KeyFile { private_key, public_key, passphrase } => session
+            .userauth_pubkey_file(
+                &url.user,
+                public_key.as_deref(),
+                private_key,
+                passphrase.as_deref(),
+            )
+            .map_err(io::Error::other)?,
+    }
+
+    if !session.authenticated() {
+        return Err(io::Error::new(
+            io::ErrorKind::PermissionDenied,
+            "SSH authentication failed",
+        ));
+    }
+    Ok(session)
+}
+
+/// Input provider that streams a remote file over SFTP.
+#[derive(Debug, Clone)]
+pub struct SftpInput {
+    url: SshUrl,
+    auth: SshAuth,
+}
+
+impl SftpInput {
+    /// Parse a `ssh://user@host[:port]/path` target, authenticating via the
+    /// running user's `ssh-agent` by default.
+    pub fn new(raw: &str) -> io::Result<Self> {
+        let url = SshUrl::parse(raw).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "expected ssh://user@host[:port]/path",
+            )
+        })?;
+        Ok(Self { url, auth: SshAuth::Agent })
+    }
+
+    /// Authenticate with a private key file instead of `ssh-agent`.
+    pub fn with_private_key(
+        mut self,
+        private_key: impl Into<PathBuf>,
+        passphrase: Option<String>,
+    ) -> Self {
+        self.auth = SshAuth::KeyFile {
+            private_key: private_key.into(),
+            public_key: None,
+            passphrase,
+        };
+        self
+    }
+}
+
+impl InputProvider for SftpInput {
+    fn id(&self) -> &str {
+        &self.url.path
+    }
+
+    fn open(&self) -> io::Result<Box<dyn Read + Send>> {
+        let session = connect(&self.url, &self.auth)?;
+        let file = {
+            let guard = session.lock().unwrap();
+            let sftp = guard.sftp().map_err(io::Error::other)?;
+            sftp.open(Path::new(&self.url.path))
+                .map_err(io::Error::other)?
+        };
+        Ok(Box::new(SftpReader { _session: session, file }))
+    }
+}
+
+struct SftpReader {
+    /// Kept alive for as long as `file` is in use; the pooled session is
+    /// also reachable through `session_pool`, so this clone mainly documents
+    /// the dependency rather than being the sole owner.
+    _session: Arc<Mutex<Session>>,
+    file: ssh2::File,
+}
+
+impl Read for SftpReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.file.read(buf)
+    }
+}
+
+/// Output target that writes a remote file over SFTP.
+#[derive(Debug, Clone)]
+pub struct SftpOutput {
+    url: SshUrl,
+    auth: SshAuth,
+}
+
+impl SftpOutput {
+    /// Parse a `ssh://user@host[:port]/path` target, authenticating via the
+    /// running user's `ssh-agent` by default.
+    pub fn new(raw: &str) -> io::Result<Self> {
+        let url = SshUrl::parse(raw).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "expected ssh://user@host[:port]/path",
+            )
+        })?;
+        Ok(Self { url, auth: SshAuth::Agent })
+    }
+
+    /// Authenticate with a private key file instead of `ssh-agent`.
+    pub fn with_private_key(
+        mut self,
+        private_key: impl Into<PathBuf>,
+        passphrase: Option<String>,
+    ) -> Self {
+        self.auth = SshAuth::KeyFile {
+            private_key: private_key.into(),
+            public_key: None,
+            passphrase,
+        };
+        self
+    }
+}
+
+impl OutputTarget for SftpOutput {
+    fn id(&self) -> &str {
+        &self.url.path
+    }
+
+    fn open_overwrite(&self) -> io::Result<Box<dyn Write + Send>> {
+        let session = connect(&self.url, &self.auth)?;
+        let file = {
+            let guard = session.lock().unwrap();
+            let sftp = guard.sftp().map_err(io::Error::other)?;
+            sftp.create(Path::new(&self.url.path))
+                .map_err(io::Error::other)?
+        };
+        Ok(Box::new(SftpWriter { _session: session, file }))
+    }
+
+    fn open_append(&self) -> io::Result<Box<dyn Write + Send>> {
+        let session = connect(&self.url, &self.auth)?;
+        let file = {
+            let guard = session.lock().unwrap();
+            let sftp = guard.sftp().map_err(io::Error::other)?;
+            sftp.open_mode(
+                Path::new(&self.url.path),
+                OpenFlags::WRITE | OpenFlags::APPEND | OpenFlags::CREATE,
+                0o644,
+                OpenType::File,
+            )
+            .map_err(io::Error::other)?
+        };
+        Ok(Box::new(SftpWriter { _session: session, file }))
+    }
+}
+
+struct SftpWriter {
+    _session: Arc<Mutex<Session>>,
+    file: ssh2::File,
+}
+
+impl Write for SftpWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.file.write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.file.flush()
+    }
+}
@@ -0,0 +1,211 @@
+//! Socket-based input/output providers: treat a TCP or Unix domain socket
+//! connection as a record stream, framed so one connection carries many
+//! records.
+//!
+//! `SocketInput`/`SocketOutput` connect fresh on every `open()`/
+//! `open_overwrite()` call, same as `FileInput`/`FileOutput`. Framing (how
+//! individual records are delimited on the wire) is pluggable via the
+//! `Framing` trait; `NdjsonFraming` (the default) delimits records with a
+//! trailing newline, which is also what `format::json`'s streaming decoder
+//! already tolerates between concatenated JSON values.
+
+use std::io::{self, BufReader, Read, Write};
+use std::net::TcpStream;
+use std::sync::Arc;
+
+#[cfg(unix)]
+use std::os::unix::net::UnixStream;
+#[cfg(unix)]
+use std::path::{Path, PathBuf};
+
+use super::{InputProvider, OutputTarget};
+
+/// How records are delimited on a socket connection.
+pub trait Framing: Send + Sync + std::fmt::Debug {
+    /// Wrap a raw connection reader with this framing's read-side behavior.
+    fn frame_reader(&self, reader: Box<dyn Read + Send>) -> Box<dyn Read + Send>;
+
+    /// Wrap a raw connection writer with this framing's write-side behavior.
+    /// Each `write_all` call on the returned writer is treated as one record.
+    fn frame_writer(&self, writer: Box<dyn Write + Send>) -> Box<dyn Write + Send>;
+}
+
+/// Newline-delimited JSON framing: reads go through a buffered reader so a
+/// line-oriented decoder never has to re-request the socket for a single
+/// byte, and each written record is followed by a `\n` if it doesn't already
+/// end with one.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NdjsonFraming;
+
+impl Framing for NdjsonFraming {
+    fn frame_reader(&self, reader: Box<dyn Read + Send>) -> Box<dyn Read + Send> {
+        Box::new(BufReader::new(reader))
+    }
+
+    fn frame_writer(&self, writer: Box<dyn Write + Send>) -> Box<dyn Write + Send> {
+        Box::new(NdjsonWriter { inner: writer })
+    }
+}
+
+/// Appends a trailing newline after every `write_all` call, so that a
+/// record-at-a-time writer produces one NDJSON line per record.
+struct NdjsonWriter {
+    inner: Box<dyn Write + Send>,
+}
+
+impl Write for NdjsonWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.inner.write(buf)
+    }
+
+    fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+        self.inner.write_all(buf)?;
+        if !buf.ends_with(b"\n") {
+            self.inner.write_all(b"\n")?;
+        }
+        Ok(())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Endpoint {
+    Tcp(String),
+    #[cfg(unix)]
+    Unix(PathBuf),
+}
+
+impl Endpoint {
+    fn id(&self) -> String {
+        match self {
+            Endpoint::Tcp(addr) => format!("tcp://{addr}"),
+            #[cfg(unix)]
+            Endpoint::Unix(path) => format!("unix:{}", path.display()),
+        }
+    }
+
+    fn connect_read(&self) -> io::Result<Box<dyn Read + Send>> {
+        match self {
+            Endpoint::Tcp(addr) => Ok(Box::new(TcpStream::connect(addr)?)),
+            #[cfg(unix)]
+            Endpoint::Unix(path) => Ok(Box::new(UnixStream::connect(path)?)),
+        }
+    }
+
+    fn connect_write(&self) -> io::Result<Box<dyn Write + Send>> {
+        match self {
+            Endpoint::Tcp(addr) => Ok(Box::new(TcpStream::connect(addr)?)),
+            #[cfg(unix)]
+            Endpoint::Unix(path) => Ok(Box::new(UnixStream::connect(path)?)),
+        }
+    }
+}
+
+/// Input provider that reads an NDJSON-framed record stream from a TCP or
+/// Unix domain socket connection.
+#[derive(Debug, Clone)]
+pub struct SocketInput {
+    id: String,
+    endpoint: Endpoint,
+    framing: Arc<dyn Framing>,
+}
+
+impl SocketInput {
+    /// Connect to `host:port` over TCP on every `open()`.
+    pub fn tcp(addr: impl Into<String>) -> Self {
+        let endpoint = Endpoint::Tcp(addr.into());
+        Self {
+            id: endpoint.id(),
+            endpoint,
+            framing: Arc::new(NdjsonFraming),
+        }
+    }
+
+    /// Connect to a Unix domain socket at `path` on every `open()`.
+    #[cfg(unix)]
+    pub fn unix(path: impl AsRef<Path>) -> Self {
+        let endpoint = Endpoint::Unix(path.as_ref().to_path_buf());
+        Self {
+            id: endpoint.id(),
+            endpoint,
+            framing: Arc::new(NdjsonFraming),
+        }
+    }
+
+    /// Use a different framing strategy than the default NDJSON framing.
+    pub fn with_framing(mut self, framing: Arc<dyn Framing>) -> Self {
+        self.framing = framing;
+        self
+    }
+}
+
+impl InputProvider for SocketInput {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn open(&self) -> io::Result<Box<dyn Read + Send>> {
+        let reader = self.endpoint.connect_read()?;
+        Ok(self.framing.frame_reader(reader))
+    }
+}
+
+/// Output target that writes an NDJSON-framed record stream to a TCP or Unix
+/// domain socket connection.
+#[derive(Debug, Clone)]
+pub struct SocketOutput {
+    id: String,
+    endpoint: Endpoint,
+    framing: Arc<dyn Framing>,
+}
+
+impl SocketOutput {
+    /// Connect to `host:port` over TCP on every `open_overwrite()`/
+    /// `open_append()`.
+    pub fn tcp(addr: impl Into<String>) -> Self {
+        let endpoint = Endpoint::Tcp(addr.into());
+        Self {
+            id: endpoint.id(),
+            endpoint,
+            framing: Arc::new(NdjsonFraming),
+        }
+    }
+
+    /// Connect to a Unix domain socket at `path` on every `open_overwrite()`/
+    /// `open_append()`.
+    #[cfg(unix)]
+    pub fn unix(path: impl AsRef<Path>) -> Self {
+        let endpoint = Endpoint::Unix(path.as_ref().to_path_buf());
+        Self {
+            id: endpoint.id(),
+            endpoint,
+            framing: Arc::new(NdjsonFraming),
+        }
+    }
+
+    /// Use a different framing strategy than the default NDJSON framing.
+    pub fn with_framing(mut self, framing: Arc<dyn Framing>) -> Self {
+        self.framing = framing;
+        self
+    }
+}
+
+impl OutputTarget for SocketOutput {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    // A socket connection has no concept of truncate-vs-append; every open
+    // is a fresh connection and the framing layer delimits records on it.
+    fn open_overwrite(&self) -> io::Result<Box<dyn Write + Send>> {
+        let writer = self.endpoint.connect_write()?;
+        Ok(self.framing.frame_writer(writer))
+    }
+
+    fn open_append(&self) -> io::Result<Box<dyn Write + Send>> {
+        self.open_overwrite()
+    }
+}
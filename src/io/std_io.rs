@@ -4,6 +4,9 @@ use std::fs::OpenOptions;
 use std::io::{self, Read, Write};
 use std::path::PathBuf;
 
+#[cfg(unix)]
+use std::os::unix::fs::OpenOptionsExt;
+
 use super::{InputProvider, OutputTarget};
 
 /// Input provider for reading from stdin.
@@ -139,19 +142,45 @@ impl OutputTarget for StderrOutput {
 pub struct FileOutput {
     id: String,
     path: PathBuf,
+    mode: Option<u32>,
 }
 
 impl FileOutput {
     /// Create a new file output target.
     pub fn new(path: PathBuf) -> Self {
         let id = path.to_string_lossy().into_owned();
-        Self { id, path }
+        Self { id, path, mode: None }
     }
 
     /// Get the file path.
     pub fn path(&self) -> &PathBuf {
         &self.path
     }
+
+    /// Set the Unix permission bits (e.g. `0o600`) a newly created file gets,
+    /// via `OpenOptions::mode`. No-op on non-Unix platforms, and has no
+    /// effect if the file already exists (the kernel only applies `mode` to
+    /// files it actually creates) - see
+    /// [`crate::config::OutputSpec::with_file_mode`] for a `set_permissions`
+    /// step that covers that case too.
+    pub fn with_mode(mut self, mode: u32) -> Self {
+        self.mode = Some(mode);
+        self
+    }
+
+    #[cfg(unix)]
+    fn open_options(&self) -> OpenOptions {
+        let mut options = OpenOptions::new();
+        if let Some(mode) = self.mode {
+            options.mode(mode);
+        }
+        options
+    }
+
+    #[cfg(not(unix))]
+    fn open_options(&self) -> OpenOptions {
+        OpenOptions::new()
+    }
 }
 
 impl OutputTarget for FileOutput {
@@ -160,7 +189,8 @@ impl OutputTarget for FileOutput {
     }
 
     fn open_overwrite(&self) -> io::Result<Box<dyn Write + Send>> {
-        let file = OpenOptions::new()
+        let file = self
+            .open_options()
             .create(true)
             .truncate(true)
             .write(true)
@@ -169,10 +199,15 @@ impl OutputTarget for FileOutput {
     }
 
     fn open_append(&self) -> io::Result<Box<dyn Write + Send>> {
-        let file = OpenOptions::new()
+        let file = self
+            .open_options()
             .create(true)
             .append(true)
             .open(&self.path)?;
         Ok(Box::new(file))
     }
+
+    fn file_path(&self) -> Option<&std::path::Path> {
+        Some(&self.path)
+    }
 }
@@ -8,6 +8,9 @@
 //! - **Multi-input/Multi-output**: Read from and write to multiple sources simultaneously
 //! - **Format abstraction**: Built-in support for JSON, YAML, CSV, XML, and plaintext
 //! - **Extensible formats**: Implement the `Format` trait for custom formats
+//! - **Extensible schemes**: Register `scheme://` CLI token handlers via
+//!   `MultiioBuilder::register_input_scheme`/`register_output_scheme` for
+//!   backends (S3, HTTP, a database, ...) the builtin resolvers don't cover
 //! - **Sync and Async**: Both synchronous and asynchronous I/O support
 //! - **Error handling**: Configurable error policies (FastFail or Accumulate)
 //! - **Pipeline configuration**: Define I/O workflows via YAML/JSON config files
@@ -44,9 +47,97 @@
 //! - `yaml` - YAML format support (enabled by default)
 //! - `csv` - CSV format support (enabled by default)
 //! - `xml` - XML format support
+//! - `ron` - RON (Rusty Object Notation) format support
+//! - `json5` - JSON5 format support
+//! - `preserve_order` - enables `serde_json`'s own `preserve_order` feature, so
+//!   `serde_json::Value`'s `Map` (and therefore `CustomFormat`'s
+//!   deserialize/serialize round trip, and any other code that passes a
+//!   schema-less `Value` through `serde_json`) keeps object keys in source
+//!   order instead of alphabetizing them; also enables
+//!   `FormatRegistry::deserialize_document`/`serialize_document`, which
+//!   round-trip through an insertion-ordered `OrderedValue` instead of
+//!   `serde_json::Value` for callers who want that guarantee independent of
+//!   which `preserve_order` feature `serde_json` itself was built with
 //! - `plaintext` - Plaintext format support (enabled by default)
+//! - `ndjson` - Newline-delimited JSON (JSON Lines) format support, with true
+//!   incremental streaming in both the sync and async engines
+//! - `cbor` - CBOR binary format support, via `ciborium`
 //! - `async` - Async I/O support with Tokio
 //! - `miette` - Pretty error reporting with miette
+//! - `compression` - Transparent gzip/zstd/zip/bzip2 decompression and compression for input/output specs
+//! - `db` - `SqlInput`/`SqlOutput` async providers that bridge a relational database (requires `async`)
+//! - `http` - `HttpInput`/`HttpOutput` providers that GET/POST/PUT a remote URL
+//!
+//! ## Pluggable format handlers
+//!
+//! `FormatRegistry::register_handler`/`with_handler` let a caller install a
+//! [`format::Format`] trait object under any `FormatKind`, builtin or custom,
+//! overriding whatever handler (including the library's own) was previously
+//! registered there. `stream_deserialize_into` and `stream_serialize_from`
+//! both dispatch through whichever handler is registered rather than
+//! special-casing each format by name.
+//!
+//! ## Malformed payload errors
+//!
+//! `FormatError::MalformedPayload` carries the `FormatKind` involved, a
+//! [`format::PayloadErrorKind`] (`DataShape` vs `Syntax`), and a bounded
+//! preview of the offending input rather than the whole thing, so a
+//! malformed multi-megabyte payload doesn't get dumped into the error
+//! message. The CSV and YAML decoders produce it for shape mismatches and
+//! (for YAML) parse failures the underlying library can't point a byte
+//! offset at; JSON/YAML/TOML failures that do have a location keep using
+//! the more precise `FormatError::SerdeSpanned`.
+//!
+//! ## Field-path-aware deserialization errors
+//!
+//! The JSON, YAML, and CSV decoders, plus `CustomFormat::deserialize`, parse
+//! through `serde_path_to_error` rather than calling `serde`'s deserialize
+//! directly. A failure nested inside a struct field or array element comes
+//! back as `FormatError::SerdeAt`, carrying the dotted/bracketed path to the
+//! offending spot (e.g. `records[3].value`) instead of just the underlying
+//! library's flat message; a failure at the document root (nothing to point
+//! at yet) falls back to whatever root-level representation that format
+//! already used (`SerdeSpanned`, `MalformedPayload`, ...).
+//!
+//! ## Glob and directory expansion (async builder)
+//!
+//! `MultiioAsyncBuilder` input args that contain a glob metacharacter
+//! (`*`, `?`, `[`, including `**` for arbitrary depth) or that name an
+//! existing directory expand into one `AsyncInputSpec` per matched file,
+//! filtered to extensions the registry can resolve to a `FormatKind`. This
+//! is why `MultiioAsyncBuilder::build` is `async`: expansion walks the
+//! filesystem via `tokio::fs` before the engine is built. Output args are
+//! never expanded this way, since a write target is inherently singular.
+//!
+//! ## External-command transform stages (async builder)
+//!
+//! An async input/output arg of the form `source | command args` (input) or
+//! `command args | destination` (output) routes the resolved source's/
+//! destination's bytes through a spawned `command` before they reach the
+//! format layer, via [`io::AsyncTransformInput`]/[`io::AsyncTransformOutput`].
+//! This is the general escape hatch for transforms with no dedicated codec
+//! (`gzip -d`, `exiftool -all= - -out -`, a sanitizing script, ...); a literal
+//! `|` that isn't surrounded by spaces (e.g. inside a path or `=inline`
+//! content) is left alone.
+//!
+//! ## Watch mode (async engine)
+//!
+//! `MultiioAsyncBuilder::with_watch`/`with_watch_debounce` configure an
+//! engine for `AsyncIoEngine::run_watched`, which re-runs the full
+//! resolve→decode→encode→write pipeline and yields a [`RunReport`] each time
+//! a watched input file is modified, coalescing rapid bursts of changes
+//! within the debounce window into a single rerun. Watching is approximated
+//! by polling file mtimes (there's no filesystem-notification crate in this
+//! dependency tree); inline and stdin inputs have nothing to watch and are
+//! simply re-read on every run.
+//!
+//! ## Content sniffing
+//!
+//! For extensionless inputs (e.g. stdin), `FormatRegistry::detect_format(bytes)`
+//! ranks the formats that successfully parse `bytes` by
+//! confidence, and `InputSpec`'s candidate resolution (`deserialize_value`) tries
+//! the most confident detected format first rather than walking
+//! `format_candidates` in its declared order.
 //!
 //! ## Streaming usage & semantics
 //!
@@ -57,27 +148,63 @@
 //!   - `deserialize_csv_stream` – row-by-row CSV records
 //!   - `deserialize_yaml_stream` – multi-document YAML
 //!   - `deserialize_plaintext_stream` – line-based plaintext
+//!   - `deserialize_ndjson_stream` – one JSON value per line
+//! - **Sync streaming write helper** (in `multiio::format`):
+//!   - `FormatRegistry::stream_serialize_from<T>(format, ext, writer, iter)`
+//!     consumes an `Iterator<Item = Result<T, FormatError>>` and writes each
+//!     record incrementally instead of collecting into a `Vec` first. NDJSON
+//!     and plaintext write one line per item; CSV writes a header row from
+//!     the first record, then one row per item. Formats without a genuine
+//!     incremental encoder fall back to collecting every record and making a
+//!     single whole-document `serialize` call.
 //! - **Sync engine streaming**:
 //!   - `IoEngine::read_records<T>()` uses `FormatRegistry::stream_deserialize_into` to
 //!     pick the right streaming implementation (including custom formats). Each record
 //!     is yielded as `Result<T, SingleIoError>`.
+//!   - `IoEngine::read_records_dynamic()` is `read_records::<serde_json::Value>()`, for
+//!     callers that want to inspect or reshape heterogeneous rows before picking a type.
+//!   - `IoEngine::read_csv_records<T>()`/`read_ndjson_records<T>()` restrict that same
+//!     streaming to inputs resolved to CSV/NDJSON specifically, and
+//!     `IoEngine::write_ndjson_records<T>(iter)` is the write-side counterpart: it
+//!     pulls one record at a time off `iter`, so a transform between a huge NDJSON
+//!     input and a huge NDJSON output never needs the whole sequence in memory.
 //! - **Memory model (sync)**:
 //!   - Streaming helpers work directly from a `Read` implementation and do not require
 //!     loading the entire input into memory at once, aside from what the underlying
 //!     decoder (e.g. `serde_json`, `csv`, `serde_yaml`) buffers internally.
 //!
 //! - **Async engine streaming**:
-//!   - `AsyncIoEngine::read_records_async<T>(concurrency)` reads each async input into
-//!     a `Vec<u8>` and then reuses the same sync streaming helpers via an in-memory
-//!     cursor. This gives record-level streaming semantics on top of an async source,
-//!     while keeping the implementation simple and predictable.
+//!   - `AsyncIoEngine::read_records_async<T>(concurrency)` dispatches to a format's
+//!     `AsyncStreamFormat` decoder when one is available (currently JSON, NDJSON,
+//!     CSV, and plaintext), which pulls one record's worth of bytes at a time
+//!     directly off the async reader.
+//!     Formats without an incremental decoder fall back to reading each input into
+//!     a `Vec<u8>` and reusing the same sync streaming helpers via an in-memory
+//!     cursor.
 //!   - `concurrency` controls how many inputs are processed in parallel; records from
 //!     different inputs may be interleaved in the resulting stream.
+//!   - `AsyncIoEngine::read_records_dynamic_async(concurrency)` is
+//!     `read_records_async::<serde_json::Value>(concurrency)`, for the same schema-less
+//!     use case as the sync engine's `read_records_dynamic`.
+//!   - `read_records_async_ordered(concurrency)` / `read_stream_async_ordered(concurrency)`
+//!     use `buffered` instead of `buffer_unordered`, so up to `concurrency` inputs still
+//!     run concurrently but results are yielded in the original input order.
 //! - **Memory model (async)**:
-//!   - Because each input is first read into a `Vec<u8>`, the peak memory usage per
-//!     input is still proportional to the full input size. Streaming at the record
-//!     level improves processing behavior, but does not yet provide true incremental
-//!     I/O at the byte level.
+//!   - For formats with an `AsyncStreamFormat` decoder, peak memory per input is
+//!     bounded by roughly one record plus the reader's internal buffer. For all
+//!     other formats, each input is first read into a `Vec<u8>`, so peak memory is
+//!     still proportional to the full input size.
+//!
+//! - **Length-delimited framing for binary formats**:
+//!   - [`format::FramedBinaryStreamFormat`]/[`format::serialize_framed_stream_to_async_writer`]
+//!     prefix each record with an unsigned LEB128 varint byte length, for a
+//!     byte-level codec (e.g. Bincode) with no self-delimiting document
+//!     boundary of its own. CBOR values are self-delimiting, so
+//!     `FormatKind::Cbor` streams without framing; no built-in `FormatKind`
+//!     uses framing, so it isn't part of `records_stream_for_spec_async`'s
+//!     automatic dispatch; a caller adding such a codec would wrap it in
+//!     `FramedBinaryStreamFormat` and use it the same way as any other
+//!     `AsyncStreamFormat` implementor.
 //!
 //! - **YAML async streaming note**:
 //!   - Synchronous YAML streaming (`deserialize_yaml_stream`) yields documents lazily
@@ -96,6 +223,10 @@ pub mod engine;
 pub mod error;
 pub mod format;
 pub mod io;
+pub mod router;
+#[cfg(feature = "tracing")]
+mod trace;
+pub mod testing;
 
 // Async modules (feature-gated)
 #[cfg(feature = "async")]
@@ -110,11 +241,22 @@ pub use engine::IoEngine;
 pub use error::{AggregateError, ErrorPolicy, SingleIoError, Stage};
 #[cfg(feature = "custom")]
 pub use format::CustomFormat;
-pub use format::{FormatError, FormatKind, FormatRegistry, default_registry};
+pub use format::{
+    Confidence, Format, FormatError, FormatKind, FormatRegistry, PayloadErrorKind, default_registry,
+};
+pub use format::{KeyOrder, OutputOptions, OutputStyle};
+#[cfg(feature = "csv")]
+pub use format::CsvOptions;
+#[cfg(feature = "preserve_order")]
+pub use format::OrderedValue;
 pub use io::{
-    FileInput, FileOutput, InMemorySink, InMemorySource, InputProvider, OutputTarget, StderrOutput,
-    StdinInput, StdoutOutput,
+    FileInput, FileOutput, Framing, InMemorySink, InMemorySource, InputProvider, NdjsonFraming,
+    OutputTarget, ProcessInput, ProcessOutput, SchemeRegistry, SocketInput, SocketOutput,
+    StderrOutput, StdinInput, StdoutOutput,
 };
+#[cfg(feature = "http")]
+pub use io::{HttpInput, HttpOutput, HttpWriteMethod};
+pub use router::{ByKeyRouter, PredicateRouter, RoundRobinRouter, Router};
 
 // Async re-exports
 #[cfg(feature = "async")]
@@ -122,14 +264,17 @@ pub use builder_async::MultiioAsyncBuilder;
 #[cfg(feature = "async")]
 pub use config::{AsyncInputSpec, AsyncOutputSpec};
 #[cfg(feature = "async")]
-pub use engine_async::AsyncIoEngine;
+pub use engine_async::{AsyncIoEngine, RunReport};
 #[cfg(feature = "async")]
-pub use format::{AsyncFormatRegistry, default_async_registry};
+pub use format::{AsyncCustomFormat, AsyncFormatRegistry, default_async_registry};
 #[cfg(feature = "async")]
 pub use io::{
-    AsyncFileInput, AsyncFileOutput, AsyncInputProvider, AsyncOutputTarget, AsyncStdinInput,
-    AsyncStdoutOutput,
+    AsyncFileInput, AsyncFileOutput, AsyncFraming, AsyncInputProvider, AsyncNdjsonFraming,
+    AsyncOutputTarget, AsyncProcessInput, AsyncProcessOutput, AsyncSchemeRegistry,
+    AsyncSocketInput, AsyncSocketOutput, AsyncStdinInput, AsyncStdoutOutput,
 };
+#[cfg(all(feature = "async", feature = "db"))]
+pub use io::{SqlInput, SqlOutput};
 
 /// Build a synchronous IoEngine from a PipelineConfig using the default
 /// FormatRegistry.
@@ -0,0 +1,107 @@
+//! Record-level fan-out routing for splitting a stream of records across
+//! multiple outputs instead of broadcasting the same values to every sink.
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Maps a record to zero or more output indices.
+///
+/// Implementations are consulted once per record by
+/// `IoEngine::write_records_routed`, which accumulates the records assigned
+/// to each output and writes each output's batch through the normal
+/// `write_one` path. Returning an index outside `0..output_count` is
+/// ignored rather than panicking, and returning no indices drops the record
+/// (it is written to no output).
+pub trait Router<T> {
+    /// Return the indices (into the engine's output list) that `record`
+    /// should be routed to, given that there are `output_count` outputs.
+    fn route(&self, record: &T, output_count: usize) -> Vec<usize>;
+}
+
+/// Balances records evenly across all outputs in round-robin order.
+pub struct RoundRobinRouter {
+    next: AtomicUsize,
+}
+
+impl RoundRobinRouter {
+    /// Create a new round-robin router starting at output index 0.
+    pub fn new() -> Self {
+        Self {
+            next: AtomicUsize::new(0),
+        }
+    }
+}
+
+impl Default for RoundRobinRouter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Router<T> for RoundRobinRouter {
+    fn route(&self, _record: &T, output_count: usize) -> Vec<usize> {
+        if output_count == 0 {
+            return Vec::new();
+        }
+        let idx = self.next.fetch_add(1, Ordering::Relaxed) % output_count;
+        vec![idx]
+    }
+}
+
+/// Shards records across outputs by hashing a key extracted from each
+/// record. Records with the same key always land on the same output index
+/// (for a fixed `output_count`).
+pub struct ByKeyRouter<F> {
+    key_fn: F,
+}
+
+impl<F> ByKeyRouter<F> {
+    /// Create a router that shards by hashing `key_fn(record)`.
+    pub fn new(key_fn: F) -> Self {
+        Self { key_fn }
+    }
+}
+
+impl<T, K, F> Router<T> for ByKeyRouter<F>
+where
+    F: Fn(&T) -> K,
+    K: Hash,
+{
+    fn route(&self, record: &T, output_count: usize) -> Vec<usize> {
+        if output_count == 0 {
+            return Vec::new();
+        }
+        let mut hasher = DefaultHasher::new();
+        (self.key_fn)(record).hash(&mut hasher);
+        let shard = (hasher.finish() % output_count as u64) as usize;
+        vec![shard]
+    }
+}
+
+/// Routes a record to an explicit set of output indices when a predicate
+/// matches, and drops it (routes to nothing) otherwise.
+pub struct PredicateRouter<F> {
+    predicate: F,
+    targets: Vec<usize>,
+}
+
+impl<F> PredicateRouter<F> {
+    /// Create a router that sends matching records to `targets`.
+    pub fn new(predicate: F, targets: Vec<usize>) -> Self {
+        Self { predicate, targets }
+    }
+}
+
+impl<T, F> Router<T> for PredicateRouter<F>
+where
+    F: Fn(&T) -> bool,
+{
+    fn route(&self, record: &T, _output_count: usize) -> Vec<usize> {
+        if (self.predicate)(record) {
+            self.targets.clone()
+        } else {
+            Vec::new()
+        }
+    }
+}
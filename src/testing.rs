@@ -0,0 +1,256 @@
+//! Declarative end-to-end pipeline test harness.
+//!
+//! Every engine test elsewhere in this crate hand-builds `InputSpec`s and
+//! `OutputSpec`s around `InMemorySource`/`InMemorySink`, then asserts on the
+//! sink's bytes directly (see `src/tests/engine/sync_tests.rs`). That's fine
+//! for one or two cases, but doesn't scale to table-driving format or
+//! error-policy coverage across many pipelines. [`PipelineTest`] does that:
+//! given a [`PipelineConfig`] and a set of per-output expectations, it builds
+//! the engine, transparently redirects every `stdout`/`stderr`/`file` output
+//! to an [`InMemorySink`], runs the pipeline, and reports every mismatch
+//! between what was expected and what actually happened.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use regex::Regex;
+
+use crate::builder::MultiioBuilder;
+use crate::config::{InputSpec, PipelineConfig};
+use crate::error::{AggregateError, Stage};
+use crate::format::FormatRegistry;
+use crate::io::InMemorySink;
+
+/// What a single output's contents are expected to look like.
+pub enum ExpectedOutput {
+    /// The raw bytes written must match exactly.
+    Bytes(Vec<u8>),
+    /// The contents, decoded via the registry (trial-deserializing across
+    /// every registered format, same as [`FormatRegistry::deserialize_value`]
+    /// does when called with no explicit format or candidates), must equal
+    /// this value.
+    Decoded(serde_json::Value),
+    /// The textual contents must match this regex somewhere (see
+    /// [`Regex::is_match`]), for golden-output-by-descriptor style
+    /// assertions that don't care about exact formatting.
+    Pattern(Regex),
+}
+
+/// One `(Stage, target)` pair an `AggregateError` produced by the run is
+/// expected to contain. Matched by key only: the underlying error's message
+/// isn't compared, since it's usually not worth pinning down exactly.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExpectedErrorKey {
+    pub stage: Stage,
+    pub target: String,
+}
+
+impl ExpectedErrorKey {
+    pub fn new(stage: Stage, target: impl Into<String>) -> Self {
+        Self {
+            stage,
+            target: target.into(),
+        }
+    }
+}
+
+/// A single mismatch between a [`PipelineTest`]'s expectations and what the
+/// run actually produced.
+#[derive(Debug)]
+pub enum Mismatch {
+    /// The engine failed to build at all (e.g. an unknown output `kind` in
+    /// the config), before any output could be checked.
+    BuildFailed(AggregateError),
+    /// An expected output `id` doesn't correspond to any configured output.
+    UnknownOutputId { id: String },
+    /// An output's contents didn't match its matcher.
+    OutputMismatch { id: String, detail: String },
+    /// An expected `(Stage, target)` error never showed up in the run.
+    MissingError(ExpectedErrorKey),
+    /// The run produced an error not covered by `expect_errors`.
+    UnexpectedError { stage: Stage, target: String },
+}
+
+impl std::fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Mismatch::BuildFailed(e) => write!(f, "engine failed to build: {e}"),
+            Mismatch::UnknownOutputId { id } => {
+                write!(f, "expected output {id:?} was never configured")
+            }
+            Mismatch::OutputMismatch { id, detail } => write!(f, "output {id:?}: {detail}"),
+            Mismatch::MissingError(key) => {
+                write!(f, "expected [{}] {} error, but none occurred", key.stage, key.target)
+            }
+            Mismatch::UnexpectedError { stage, target } => {
+                write!(f, "unexpected [{stage}] {target} error")
+            }
+        }
+    }
+}
+
+/// A declarative end-to-end pipeline test: a config to run, a matcher per
+/// output `id` of interest, and (optionally) the exact set of errors the run
+/// is expected to produce.
+pub struct PipelineTest {
+    config: PipelineConfig,
+    extra_inputs: Vec<InputSpec>,
+    expected_outputs: HashMap<String, ExpectedOutput>,
+    expected_errors: Option<Vec<ExpectedErrorKey>>,
+}
+
+impl PipelineTest {
+    /// Start a test from a `PipelineConfig`. Every `stdout`/`stderr`/`file`
+    /// output it declares is redirected to an `InMemorySink` by [`Self::run`]
+    /// regardless of whether it has a matcher; other output kinds (`tcp`,
+    /// `http`, `command`) are left to resolve for real.
+    pub fn new(config: PipelineConfig) -> Self {
+        Self {
+            config,
+            extra_inputs: Vec::new(),
+            expected_outputs: HashMap::new(),
+            expected_errors: None,
+        }
+    }
+
+    /// Add an already-built input spec (typically an [`InMemorySource`]-backed
+    /// one) alongside whatever the config's own `inputs` resolve to, for
+    /// tests that want literal in-memory input bytes without writing a
+    /// temp file.
+    ///
+    /// [`InMemorySource`]: crate::io::InMemorySource
+    pub fn with_input(mut self, spec: InputSpec) -> Self {
+        self.extra_inputs.push(spec);
+        self
+    }
+
+    /// Expect the output named `id` to match `expected` once the run
+    /// completes.
+    pub fn expect_output(mut self, id: impl Into<String>, expected: ExpectedOutput) -> Self {
+        self.expected_outputs.insert(id.into(), expected);
+        self
+    }
+
+    /// Expect the run to produce exactly this set of `(Stage, target)`
+    /// errors (order-independent). Omit this to skip error-set assertions
+    /// entirely, e.g. for a happy-path test that only checks outputs.
+    pub fn expect_errors(mut self, errors: Vec<ExpectedErrorKey>) -> Self {
+        self.expected_errors = Some(errors);
+        self
+    }
+
+    /// Build the engine against `registry`, run it end to end (read every
+    /// input as a dynamic `serde_json::Value`, then write every value to
+    /// every output), and report every mismatch between what was declared
+    /// and what happened. An empty `Vec` means the run matched exactly.
+    pub fn run(self, registry: FormatRegistry) -> Vec<Mismatch> {
+        let redirect_ids: Vec<String> = self
+            .config
+            .outputs
+            .iter()
+            .filter(|o| matches!(o.kind.as_str(), "stdout" | "stderr" | "file" | "-"))
+            .map(|o| o.id.clone())
+            .collect();
+
+        let builder = match MultiioBuilder::from_pipeline_config(self.config, registry) {
+            Ok(b) => b,
+            Err(e) => return vec![Mismatch::BuildFailed(e)],
+        };
+        let builder = self
+            .extra_inputs
+            .into_iter()
+            .fold(builder, MultiioBuilder::add_input_spec);
+
+        let mut engine = match builder.build() {
+            Ok(e) => e,
+            Err(e) => return vec![Mismatch::BuildFailed(e)],
+        };
+
+        let mut sinks: HashMap<String, InMemorySink> = HashMap::new();
+        for spec in engine.outputs_mut() {
+            if redirect_ids.iter().any(|id| id == &spec.raw) {
+                let sink = InMemorySink::new(spec.raw.clone());
+                sinks.insert(spec.raw.clone(), sink.clone());
+                spec.target = Arc::new(sink);
+            }
+        }
+
+        let run_errors = match engine.read_all::<serde_json::Value>() {
+            Ok(values) => engine.write_all(&values).err(),
+            Err(e) => Some(e),
+        };
+
+        let mut mismatches = Vec::new();
+        let registry = engine.registry();
+
+        for (id, expected) in &self.expected_outputs {
+            let Some(sink) = sinks.get(id) else {
+                mismatches.push(Mismatch::UnknownOutputId { id: id.clone() });
+                continue;
+            };
+
+            if let Some(detail) = check_output(registry, sink, expected) {
+                mismatches.push(Mismatch::OutputMismatch {
+                    id: id.clone(),
+                    detail,
+                });
+            }
+        }
+
+        if let Some(expected_errors) = &self.expected_errors {
+            let actual: Vec<(Stage, String)> = run_errors
+                .iter()
+                .flat_map(|agg| agg.errors.iter())
+                .map(|e| (e.stage, e.target.clone()))
+                .collect();
+
+            for expected in expected_errors {
+                if !actual
+                    .iter()
+                    .any(|(stage, target)| *stage == expected.stage && target == &expected.target)
+                {
+                    mismatches.push(Mismatch::MissingError(expected.clone()));
+                }
+            }
+
+            for (stage, target) in &actual {
+                if !expected_errors
+                    .iter()
+                    .any(|e| e.stage == *stage && &e.target == target)
+                {
+                    mismatches.push(Mismatch::UnexpectedError {
+                        stage: *stage,
+                        target: target.clone(),
+                    });
+                }
+            }
+        }
+
+        mismatches
+    }
+}
+
+fn check_output(registry: &FormatRegistry, sink: &InMemorySink, expected: &ExpectedOutput) -> Option<String> {
+    let contents = sink.contents();
+
+    match expected {
+        ExpectedOutput::Bytes(expected_bytes) => (contents != *expected_bytes).then(|| {
+            format!(
+                "expected {} byte(s), got {}",
+                expected_bytes.len(),
+                contents.len()
+            )
+        }),
+        ExpectedOutput::Decoded(expected_value) => {
+            match registry.deserialize_value::<serde_json::Value>(None, &[], &contents) {
+                Ok(actual) if &actual == expected_value => None,
+                Ok(actual) => Some(format!("decoded to {actual}, expected {expected_value}")),
+                Err(e) => Some(format!("failed to decode output: {e}")),
+            }
+        }
+        ExpectedOutput::Pattern(pattern) => {
+            let text = String::from_utf8_lossy(&contents);
+            (!pattern.is_match(&text)).then(|| format!("{text:?} doesn't match {pattern}"))
+        }
+    }
+}
@@ -0,0 +1,96 @@
+//! Tests for `MultiioAsyncBuilder`'s glob/directory input expansion.
+
+use crate::{ErrorPolicy, MultiioAsyncBuilder};
+
+async fn write(path: &std::path::Path, contents: &str) {
+    tokio::fs::write(path, contents)
+        .await
+        .expect("write test fixture");
+}
+
+#[tokio::test]
+async fn glob_pattern_expands_to_one_spec_per_match() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    write(&dir.path().join("a.json"), "{}").await;
+    write(&dir.path().join("b.json"), "{}").await;
+    write(&dir.path().join("c.txt"), "not json").await;
+
+    let pattern = format!("{}/*.json", dir.path().to_string_lossy());
+    let engine = MultiioAsyncBuilder::default()
+        .add_input(pattern)
+        .with_mode(ErrorPolicy::FastFail)
+        .build()
+        .await
+        .expect("build should succeed");
+
+    assert_eq!(engine.inputs().len(), 2);
+}
+
+#[tokio::test]
+async fn bare_directory_expands_recursively_to_supported_files() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    write(&dir.path().join("a.json"), "{}").await;
+    let sub = dir.path().join("sub");
+    tokio::fs::create_dir(&sub).await.expect("mkdir sub");
+    write(&sub.join("b.json"), "{}").await;
+    write(&sub.join("c.unknown_ext"), "ignored").await;
+
+    let engine = MultiioAsyncBuilder::default()
+        .add_input(dir.path().to_string_lossy().into_owned())
+        .with_mode(ErrorPolicy::FastFail)
+        .build()
+        .await
+        .expect("build should succeed");
+
+    assert_eq!(engine.inputs().len(), 2);
+}
+
+#[tokio::test]
+async fn double_star_glob_matches_nested_directories() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let sub = dir.path().join("a").join("b");
+    tokio::fs::create_dir_all(&sub).await.expect("mkdir -p");
+    write(&sub.join("deep.yaml"), "key: value").await;
+    write(&dir.path().join("shallow.json"), "{}").await;
+
+    let pattern = format!("{}/**/*.yaml", dir.path().to_string_lossy());
+    let engine = MultiioAsyncBuilder::default()
+        .add_input(pattern)
+        .with_mode(ErrorPolicy::FastFail)
+        .build()
+        .await
+        .expect("build should succeed");
+
+    assert_eq!(engine.inputs().len(), 1);
+}
+
+#[tokio::test]
+async fn glob_with_no_matches_yields_no_specs_without_erroring() {
+    let dir = tempfile::tempdir().expect("tempdir");
+
+    let pattern = format!("{}/*.json", dir.path().to_string_lossy());
+    let engine = MultiioAsyncBuilder::default()
+        .add_input(pattern)
+        .with_mode(ErrorPolicy::FastFail)
+        .build()
+        .await
+        .expect("build should succeed even with zero matches");
+
+    assert_eq!(engine.inputs().len(), 0);
+}
+
+#[tokio::test]
+async fn plain_single_file_path_is_unaffected_by_expansion() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let path = dir.path().join("only.json");
+    write(&path, "{}").await;
+
+    let engine = MultiioAsyncBuilder::default()
+        .add_input(path.to_string_lossy().into_owned())
+        .with_mode(ErrorPolicy::FastFail)
+        .build()
+        .await
+        .expect("build should succeed");
+
+    assert_eq!(engine.inputs().len(), 1);
+}
@@ -57,6 +57,7 @@ format_order: ["json", "yaml", "plaintext"]
     let engine = builder
         .with_mode(ErrorPolicy::FastFail)
         .build()
+        .await
         .expect("build async engine");
 
     let vals: Vec<ConfigData> = engine.read_all().await.expect("read_all");
@@ -126,6 +127,7 @@ format_order: ["json", "csv", "markdown", "yaml"]
     let engine = builder
         .with_mode(ErrorPolicy::FastFail)
         .build()
+        .await
         .expect("build async engine");
 
     let vals: Vec<ConfigData> = engine.read_all().await.expect("read_all");
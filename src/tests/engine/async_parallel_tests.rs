@@ -0,0 +1,277 @@
+//! Tests for `AsyncIoEngine::read_all_parallel`/`write_all_parallel`, mirroring
+//! the sync engine's parallel-fan-out tests in `sync_tests.rs`.
+
+#![cfg(feature = "async")]
+
+use std::sync::Arc;
+
+use crate::config::{AsyncInputSpec, AsyncOutputSpec, FileExistsPolicy};
+use crate::error::{AggregateError, ErrorPolicy};
+use crate::io::{AsyncFileInput, AsyncFileOutput};
+use crate::{default_async_registry, AsyncIoEngine, FormatKind};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+struct Config {
+    name: String,
+    value: i32,
+}
+
+fn make_engine(
+    error_policy: ErrorPolicy,
+    inputs: Vec<AsyncInputSpec>,
+    outputs: Vec<AsyncOutputSpec>,
+) -> AsyncIoEngine {
+    let registry = default_async_registry();
+    AsyncIoEngine::new(registry, error_policy, inputs, outputs)
+}
+
+#[tokio::test]
+async fn async_engine_read_all_parallel_preserves_order() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut specs = Vec::new();
+    for i in 0..8 {
+        let path = dir.path().join(format!("in{i}.json"));
+        tokio::fs::write(&path, format!(r#"{{"name": "n{i}", "value": {i}}}"#))
+            .await
+            .unwrap();
+        let id = path.to_string_lossy().into_owned();
+        let provider = Arc::new(AsyncFileInput::new(path));
+        specs.push(
+            AsyncInputSpec::new(id, provider)
+                .with_format(FormatKind::Json)
+                .with_candidates(vec![FormatKind::Json]),
+        );
+    }
+
+    let engine = make_engine(ErrorPolicy::Accumulate, specs, Vec::new()).with_concurrency(3);
+
+    let results: Vec<Config> = engine
+        .read_all_parallel()
+        .await
+        .expect("parallel read should succeed");
+    assert_eq!(results.len(), 8);
+    for (i, config) in results.iter().enumerate() {
+        assert_eq!(config.name, format!("n{i}"));
+        assert_eq!(config.value, i as i32);
+    }
+}
+
+#[tokio::test]
+async fn async_engine_read_all_parallel_without_concurrency_matches_read_all() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("in.json");
+    tokio::fs::write(&path, r#"{"name": "a", "value": 1}"#)
+        .await
+        .unwrap();
+    let id = path.to_string_lossy().into_owned();
+    let provider = Arc::new(AsyncFileInput::new(path));
+    let spec = AsyncInputSpec::new(id, provider)
+        .with_format(FormatKind::Json)
+        .with_candidates(vec![FormatKind::Json]);
+
+    let engine = make_engine(ErrorPolicy::FastFail, vec![spec], Vec::new());
+    let results: Vec<Config> = engine
+        .read_all_parallel()
+        .await
+        .expect("should fall back to read_all");
+    assert_eq!(
+        results,
+        vec![Config {
+            name: "a".into(),
+            value: 1
+        }]
+    );
+}
+
+#[tokio::test]
+async fn async_engine_read_all_parallel_fast_fail_returns_single_error() {
+    let dir = tempfile::tempdir().unwrap();
+
+    let ok_path = dir.path().join("ok.json");
+    tokio::fs::write(&ok_path, r#"{"name": "ok", "value": 1}"#)
+        .await
+        .unwrap();
+    let ok_id = ok_path.to_string_lossy().into_owned();
+    let spec_ok = AsyncInputSpec::new(ok_id, Arc::new(AsyncFileInput::new(ok_path)))
+        .with_format(FormatKind::Json)
+        .with_candidates(vec![FormatKind::Json]);
+
+    let bad_path = dir.path().join("bad.json");
+    tokio::fs::write(&bad_path, "{not-json").await.unwrap();
+    let bad_id = bad_path.to_string_lossy().into_owned();
+    let spec_bad = AsyncInputSpec::new(bad_id, Arc::new(AsyncFileInput::new(bad_path)))
+        .with_format(FormatKind::Json)
+        .with_candidates(vec![FormatKind::Json]);
+
+    let engine = make_engine(ErrorPolicy::FastFail, vec![spec_ok, spec_bad], Vec::new())
+        .with_concurrency(2);
+
+    let result: Result<Vec<Config>, AggregateError> = engine.read_all_parallel().await;
+    let agg = result.expect_err("expected a fast-fail error");
+    assert_eq!(agg.errors.len(), 1);
+}
+
+#[tokio::test]
+async fn async_engine_write_all_parallel_writes_every_output() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut out_specs = Vec::new();
+    let mut out_paths = Vec::new();
+    for i in 0..5 {
+        let path = dir.path().join(format!("out{i}.json"));
+        let id = path.to_string_lossy().into_owned();
+        let target = Arc::new(AsyncFileOutput::new(path.clone()));
+        out_specs.push(
+            AsyncOutputSpec::new(id, target)
+                .with_format(FormatKind::Json)
+                .with_file_exists_policy(FileExistsPolicy::Overwrite),
+        );
+        out_paths.push(path);
+    }
+
+    let engine = make_engine(ErrorPolicy::Accumulate, Vec::new(), out_specs).with_concurrency(3);
+    let values = vec![Config {
+        name: "a".into(),
+        value: 1,
+    }];
+
+    engine
+        .write_all_parallel(&values)
+        .await
+        .expect("parallel write should succeed");
+
+    for path in &out_paths {
+        let bytes = tokio::fs::read(path).await.unwrap();
+        assert!(!bytes.is_empty());
+    }
+}
+
+#[tokio::test]
+async fn async_engine_write_stream_async_writes_every_output_with_explicit_concurrency() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut out_specs = Vec::new();
+    let mut out_paths = Vec::new();
+    for i in 0..5 {
+        let path = dir.path().join(format!("out{i}.json"));
+        let id = path.to_string_lossy().into_owned();
+        let target = Arc::new(AsyncFileOutput::new(path.clone()));
+        out_specs.push(
+            AsyncOutputSpec::new(id, target)
+                .with_format(FormatKind::Json)
+                .with_file_exists_policy(FileExistsPolicy::Overwrite),
+        );
+        out_paths.push(path);
+    }
+
+    // No `with_concurrency` call: `write_stream_async` takes its own
+    // concurrency rather than relying on engine-level state.
+    let engine = make_engine(ErrorPolicy::Accumulate, Vec::new(), out_specs);
+    let values = vec![Config {
+        name: "a".into(),
+        value: 1,
+    }];
+
+    engine
+        .write_stream_async(&values, 3)
+        .await
+        .expect("stream write should succeed");
+
+    for path in &out_paths {
+        let bytes = tokio::fs::read(path).await.unwrap();
+        assert!(!bytes.is_empty());
+    }
+}
+
+#[tokio::test]
+async fn async_engine_write_stream_async_fast_fail_returns_single_error() {
+    let dir = tempfile::tempdir().unwrap();
+
+    let ok_path = dir.path().join("ok.json");
+    let spec_ok = AsyncOutputSpec::new(
+        ok_path.to_string_lossy().into_owned(),
+        Arc::new(AsyncFileOutput::new(ok_path)),
+    )
+    .with_format(FormatKind::Json);
+
+    // A directory can't be opened for writing as a regular file, so this
+    // output always fails.
+    let bad_path = dir.path().join("subdir");
+    std::fs::create_dir(&bad_path).unwrap();
+    let spec_bad = AsyncOutputSpec::new(
+        bad_path.to_string_lossy().into_owned(),
+        Arc::new(AsyncFileOutput::new(bad_path)),
+    )
+    .with_format(FormatKind::Json);
+
+    let engine = make_engine(ErrorPolicy::FastFail, Vec::new(), vec![spec_ok, spec_bad]);
+    let values = vec![Config {
+        name: "a".into(),
+        value: 1,
+    }];
+
+    let result = engine.write_stream_async(&values, 2).await;
+    let agg = result.expect_err("expected a fast-fail error");
+    assert_eq!(agg.errors.len(), 1);
+}
+
+#[tokio::test]
+async fn async_engine_write_stream_async_with_zero_concurrency_does_not_hang() {
+    // `buffer_unordered(0)` never polls any of its inner futures and hangs
+    // forever, so `concurrency: 0` must be clamped up to 1 rather than
+    // passed straight through. Wrapped in a timeout so a regression fails
+    // this test instead of hanging the whole suite.
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("out.json");
+    let id = path.to_string_lossy().into_owned();
+    let target = Arc::new(AsyncFileOutput::new(path.clone()));
+    let out_specs = vec![AsyncOutputSpec::new(id, target)
+        .with_format(FormatKind::Json)
+        .with_file_exists_policy(FileExistsPolicy::Overwrite)];
+
+    let engine = make_engine(ErrorPolicy::Accumulate, Vec::new(), out_specs);
+    let values = vec![Config {
+        name: "a".into(),
+        value: 1,
+    }];
+
+    tokio::time::timeout(
+        std::time::Duration::from_secs(5),
+        engine.write_stream_async(&values, 0),
+    )
+    .await
+    .expect("concurrency: 0 should not hang")
+    .expect("stream write should succeed");
+
+    let bytes = tokio::fs::read(&path).await.unwrap();
+    assert!(!bytes.is_empty());
+}
+
+#[tokio::test]
+async fn async_engine_write_stream_async_one_value_writes_every_output() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut out_paths = Vec::new();
+    let mut out_specs = Vec::new();
+    for i in 0..3 {
+        let path = dir.path().join(format!("out{i}.json"));
+        let id = path.to_string_lossy().into_owned();
+        let target = Arc::new(AsyncFileOutput::new(path.clone()));
+        out_specs.push(AsyncOutputSpec::new(id, target).with_format(FormatKind::Json));
+        out_paths.push(path);
+    }
+
+    let engine = make_engine(ErrorPolicy::Accumulate, Vec::new(), out_specs);
+    let value = Config {
+        name: "solo".into(),
+        value: 7,
+    };
+
+    engine
+        .write_stream_async_one_value(&value, 2)
+        .await
+        .expect("stream write should succeed");
+
+    for path in &out_paths {
+        let bytes = tokio::fs::read(path).await.unwrap();
+        assert!(!bytes.is_empty());
+    }
+}
@@ -63,6 +63,113 @@ async fn async_engine_read_write_file_ok() {
     assert_eq!(decoded, values);
 }
 
+#[tokio::test]
+async fn async_engine_atomic_overwrite_creates_missing_parent_dir_and_leaves_no_temp_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let out_path = dir.path().join("nested").join("deeper").join("output.json");
+
+    let out_target = Arc::new(AsyncFileOutput::new(out_path.clone()));
+    let output_spec = AsyncOutputSpec::new(out_path.to_string_lossy().into_owned(), out_target)
+        .with_format(FormatKind::Json)
+        .with_file_exists_policy(FileExistsPolicy::AtomicOverwrite);
+
+    let engine = make_engine(ErrorPolicy::FastFail, Vec::new(), vec![output_spec]);
+    let values = vec![Config {
+        name: "a".into(),
+        value: 1,
+    }];
+    engine
+        .write_all(&values)
+        .await
+        .expect("atomic write should create missing parent directories");
+
+    let out_bytes = tokio::fs::read(&out_path).await.unwrap();
+    let decoded: Vec<Config> = serde_json::from_slice(&out_bytes).unwrap();
+    assert_eq!(decoded, values);
+
+    let leftovers: Vec<_> = std::fs::read_dir(out_path.parent().unwrap())
+        .expect("read dir")
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_name().to_string_lossy().contains(".tmp-"))
+        .collect();
+    assert!(leftovers.is_empty(), "temp file was not cleaned up");
+}
+
+#[cfg(feature = "compression")]
+#[tokio::test]
+async fn async_engine_atomic_overwrite_still_compresses_the_written_file() {
+    use crate::io::Compression;
+
+    let dir = tempfile::tempdir().unwrap();
+    let out_path = dir.path().join("output.json.gz");
+
+    let file_target = Arc::new(AsyncFileOutput::new(out_path.clone()));
+    let compressed_target =
+        Arc::new(crate::io::AsyncCompressedOutput::new(file_target, Compression::gzip(6)));
+    let output_spec = AsyncOutputSpec::new(out_path.to_string_lossy().into_owned(), compressed_target)
+        .with_format(FormatKind::Json)
+        .with_file_exists_policy(FileExistsPolicy::AtomicOverwrite);
+
+    let engine = make_engine(ErrorPolicy::FastFail, Vec::new(), vec![output_spec]);
+    let values = vec![Config {
+        name: "a".into(),
+        value: 1,
+    }];
+    engine
+        .write_all(&values)
+        .await
+        .expect("atomic write should succeed");
+
+    // The on-disk bytes must be gzip-compressed, not the plaintext JSON the
+    // engine serialized - a plaintext leak here would mean AtomicOverwrite
+    // bypassed the compressing target entirely.
+    let on_disk = tokio::fs::read(&out_path).await.unwrap();
+    let plaintext_json = serde_json::to_vec(&values).unwrap();
+    assert_ne!(on_disk, plaintext_json);
+
+    let decoded_bytes = {
+        let mut decoder = flate2::read::GzDecoder::new(std::io::Cursor::new(on_disk));
+        let mut out = Vec::new();
+        std::io::Read::read_to_end(&mut decoder, &mut out).expect("decompress output");
+        out
+    };
+    let decoded: Vec<Config> = serde_json::from_slice(&decoded_bytes).unwrap();
+    assert_eq!(decoded, values);
+}
+
+#[tokio::test]
+async fn async_engine_file_exists_policy_error_fails_when_target_exists() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let out_path = dir.path().join("report.json");
+    tokio::fs::write(&out_path, "stale contents")
+        .await
+        .expect("seed existing file");
+
+    let target = Arc::new(AsyncFileOutput::new(out_path.clone()));
+    let out_spec = AsyncOutputSpec::new(out_path.to_string_lossy().into_owned(), target)
+        .with_format(FormatKind::Json)
+        .with_file_exists_policy(FileExistsPolicy::Error);
+
+    let engine = make_engine(ErrorPolicy::FastFail, Vec::new(), vec![out_spec]);
+    let values = vec![Config {
+        name: "a".into(),
+        value: 1,
+    }];
+
+    let err = engine
+        .write_all(&values)
+        .await
+        .expect_err("expected Error policy to reject an existing file");
+    assert_eq!(err.errors.len(), 1);
+    assert_eq!(err.errors[0].stage, Stage::Open);
+
+    // The original file must be untouched.
+    assert_eq!(
+        tokio::fs::read_to_string(&out_path).await.unwrap(),
+        "stale contents"
+    );
+}
+
 #[tokio::test]
 async fn async_engine_fast_fail_on_open_error() {
     #[derive(Debug)]
@@ -162,3 +269,261 @@ async fn async_engine_accumulate_parse_errors() {
     assert!(targets.iter().any(|t| t.contains("bad1")));
     assert!(targets.iter().any(|t| t.contains("bad2")));
 }
+
+#[tokio::test]
+async fn async_engine_retry_policy_recovers_from_transient_failures() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    // Fails with a transient error twice, then succeeds on the third open.
+    #[derive(Debug)]
+    struct FlakyAsyncInput {
+        id: String,
+        attempts: AtomicUsize,
+    }
+
+    #[async_trait::async_trait]
+    impl AsyncInputProvider for FlakyAsyncInput {
+        fn id(&self) -> &str {
+            &self.id
+        }
+
+        async fn open(&self) -> std::io::Result<Box<dyn AsyncRead + Unpin + Send>> {
+            if self.attempts.fetch_add(1, Ordering::SeqCst) < 2 {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::TimedOut,
+                    "simulated transient timeout",
+                ));
+            }
+            Ok(Box::new(std::io::Cursor::new(
+                br#"{"name": "a", "value": 1}"#.to_vec(),
+            )))
+        }
+    }
+
+    let src = Arc::new(FlakyAsyncInput {
+        id: "flaky".to_string(),
+        attempts: AtomicUsize::new(0),
+    });
+
+    let spec = AsyncInputSpec::new("flaky", src)
+        .with_format(FormatKind::Json)
+        .with_candidates(vec![FormatKind::Json]);
+
+    let engine = make_engine(
+        ErrorPolicy::Retry {
+            max_attempts: 5,
+            base_delay: std::time::Duration::from_millis(1),
+            max_delay: std::time::Duration::from_millis(5),
+        },
+        vec![spec],
+        Vec::new(),
+    );
+
+    let values: Vec<Config> = engine
+        .read_all()
+        .await
+        .expect("should recover after retries");
+    assert_eq!(
+        values,
+        vec![Config {
+            name: "a".into(),
+            value: 1
+        }]
+    );
+}
+
+#[tokio::test]
+async fn async_engine_retry_policy_gives_up_after_max_attempts() {
+    #[derive(Debug)]
+    struct AlwaysFailsAsyncInput {
+        id: String,
+    }
+
+    #[async_trait::async_trait]
+    impl AsyncInputProvider for AlwaysFailsAsyncInput {
+        fn id(&self) -> &str {
+            &self.id
+        }
+
+        async fn open(&self) -> std::io::Result<Box<dyn AsyncRead + Unpin + Send>> {
+            Err(std::io::Error::new(
+                std::io::ErrorKind::TimedOut,
+                "simulated persistent timeout",
+            ))
+        }
+    }
+
+    let src = Arc::new(AlwaysFailsAsyncInput {
+        id: "broken".to_string(),
+    });
+    let spec = AsyncInputSpec::new("broken", src)
+        .with_format(FormatKind::Json)
+        .with_candidates(vec![FormatKind::Json]);
+
+    let engine = make_engine(
+        ErrorPolicy::Retry {
+            max_attempts: 3,
+            base_delay: std::time::Duration::from_millis(1),
+            max_delay: std::time::Duration::from_millis(2),
+        },
+        vec![spec],
+        Vec::new(),
+    );
+
+    let result: Result<Vec<Config>, AggregateError> = engine.read_all().await;
+    let agg = result.expect_err("expected failure after exhausting retries");
+    assert_eq!(agg.errors.len(), 1);
+    assert_eq!(agg.errors[0].attempts, 3);
+}
+
+#[tokio::test]
+async fn async_engine_retry_policy_does_not_retry_permanent_errors() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[derive(Debug)]
+    struct NotFoundAsyncInput {
+        id: String,
+        attempts: AtomicUsize,
+    }
+
+    #[async_trait::async_trait]
+    impl AsyncInputProvider for NotFoundAsyncInput {
+        fn id(&self) -> &str {
+            &self.id
+        }
+
+        async fn open(&self) -> std::io::Result<Box<dyn AsyncRead + Unpin + Send>> {
+            self.attempts.fetch_add(1, Ordering::SeqCst);
+            Err(std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                "simulated missing file",
+            ))
+        }
+    }
+
+    let src = Arc::new(NotFoundAsyncInput {
+        id: "missing".to_string(),
+        attempts: AtomicUsize::new(0),
+    });
+    let spec = AsyncInputSpec::new("missing", src.clone())
+        .with_format(FormatKind::Json)
+        .with_candidates(vec![FormatKind::Json]);
+
+    let engine = make_engine(
+        ErrorPolicy::Retry {
+            max_attempts: 5,
+            base_delay: std::time::Duration::from_millis(1),
+            max_delay: std::time::Duration::from_millis(2),
+        },
+        vec![spec],
+        Vec::new(),
+    );
+
+    let result: Result<Vec<Config>, AggregateError> = engine.read_all().await;
+    result.expect_err("expected permanent failure");
+    assert_eq!(src.attempts.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn async_engine_retry_policy_recovers_from_write_side_transient_failure() {
+    use std::pin::Pin;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::task::{Context, Poll};
+    use tokio::io::AsyncWrite;
+
+    // Unlike the retry tests above, which all fail on `open()`, this one
+    // fails on the write call itself: `open_overwrite` succeeds every time,
+    // but the returned writer's first `poll_write` fails with a transient
+    // `ConnectionReset`. This only retries if a write failure is tagged with
+    // a stage that `is_transient` actually checks (`Stage::Write`, not
+    // `Stage::Serialize`).
+    #[derive(Debug)]
+    struct FlakyAsyncOutput {
+        id: String,
+        attempts: Arc<AtomicUsize>,
+    }
+
+    struct FlakyAsyncWriter {
+        attempts: Arc<AtomicUsize>,
+    }
+
+    impl AsyncWrite for FlakyAsyncWriter {
+        fn poll_write(
+            self: Pin<&mut Self>,
+            _cx: &mut Context<'_>,
+            buf: &[u8],
+        ) -> Poll<std::io::Result<usize>> {
+            if self.attempts.fetch_add(1, Ordering::SeqCst) < 1 {
+                return Poll::Ready(Err(std::io::Error::new(
+                    std::io::ErrorKind::ConnectionReset,
+                    "simulated connection reset on write",
+                )));
+            }
+            Poll::Ready(Ok(buf.len()))
+        }
+
+        fn poll_flush(
+            self: Pin<&mut Self>,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::io::Result<()>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn poll_shutdown(
+            self: Pin<&mut Self>,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::io::Result<()>> {
+            Poll::Ready(Ok(()))
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl crate::io::AsyncOutputTarget for FlakyAsyncOutput {
+        fn id(&self) -> &str {
+            &self.id
+        }
+
+        async fn open_overwrite(
+            &self,
+        ) -> std::io::Result<Box<dyn AsyncWrite + Unpin + Send>> {
+            Ok(Box::new(FlakyAsyncWriter {
+                attempts: self.attempts.clone(),
+            }))
+        }
+
+        async fn open_append(
+            &self,
+        ) -> std::io::Result<Box<dyn AsyncWrite + Unpin + Send>> {
+            self.open_overwrite().await
+        }
+    }
+
+    let target = Arc::new(FlakyAsyncOutput {
+        id: "flaky-write".to_string(),
+        attempts: Arc::new(AtomicUsize::new(0)),
+    });
+    let output_spec = AsyncOutputSpec::new("flaky-write", target.clone())
+        .with_format(FormatKind::Json)
+        .with_candidates(vec![FormatKind::Json])
+        .with_file_exists_policy(FileExistsPolicy::Overwrite);
+
+    let engine = make_engine(
+        ErrorPolicy::Retry {
+            max_attempts: 3,
+            base_delay: std::time::Duration::from_millis(1),
+            max_delay: std::time::Duration::from_millis(2),
+        },
+        Vec::new(),
+        vec![output_spec],
+    );
+
+    let values = vec![Config {
+        name: "a".into(),
+        value: 1,
+    }];
+
+    engine
+        .write_all(&values)
+        .await
+        .expect("write should recover after a transient write-side failure");
+}
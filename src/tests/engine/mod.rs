@@ -1,9 +1,16 @@
 //! Engine module tests.
 
+#[cfg(feature = "async")]
+mod async_parallel_tests;
 #[cfg(feature = "async")]
 mod async_tests;
 mod csv_row_stream_tests;
 mod json_row_stream_tests;
+#[cfg(feature = "ndjson")]
+mod ndjson_row_stream_tests;
+mod router_tests;
 #[cfg(any(feature = "json", feature = "async"))]
 mod stream_tests;
 mod sync_tests;
+#[cfg(feature = "tracing")]
+mod tracing_tests;
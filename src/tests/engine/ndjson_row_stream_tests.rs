@@ -0,0 +1,312 @@
+use std::sync::Arc;
+
+use crate::config::{InputSpec, OutputSpec};
+use crate::format::FormatKind;
+use crate::io::{InMemorySink, InMemorySource};
+use crate::{ErrorPolicy, IoEngine, default_registry};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+struct Row {
+    name: String,
+    value: i32,
+}
+
+fn make_ndjson_engine(ndjson: &str) -> IoEngine {
+    let registry = default_registry();
+
+    let src = Arc::new(InMemorySource::from_string("ndjson", ndjson));
+    let spec = InputSpec::new("ndjson", src)
+        .with_format(FormatKind::Ndjson)
+        .with_candidates(vec![FormatKind::Ndjson]);
+
+    IoEngine::new(registry, ErrorPolicy::Accumulate, vec![spec], Vec::new())
+}
+
+#[test]
+fn ndjson_row_stream_reads_all_records() {
+    let ndjson = "{\"name\":\"foo\",\"value\":1}\n{\"name\":\"bar\",\"value\":2}\n";
+    let engine = make_ndjson_engine(ndjson);
+
+    let rows: Vec<Row> = engine
+        .read_ndjson_records::<Row>()
+        .collect::<Result<_, _>>()
+        .expect("ndjson rows should parse");
+
+    assert_eq!(
+        rows,
+        vec![
+            Row {
+                name: "foo".into(),
+                value: 1,
+            },
+            Row {
+                name: "bar".into(),
+                value: 2,
+            },
+        ]
+    );
+}
+
+#[test]
+fn ndjson_row_stream_skips_blank_lines_and_reports_line_number_on_error() {
+    let ndjson = "{\"name\":\"foo\",\"value\":1}\n\n{\"name\":\"bar\",\"value\":\"oops\"}\n";
+    let engine = make_ndjson_engine(ndjson);
+
+    let results: Vec<_> = engine.read_ndjson_records::<Row>().collect();
+    assert_eq!(results.len(), 2);
+    assert_eq!(
+        results[0].as_ref().expect("first row parses"),
+        &Row {
+            name: "foo".into(),
+            value: 1,
+        }
+    );
+    let err = results[1].as_ref().expect_err("second row should fail");
+    assert_eq!(err.stage, crate::error::Stage::Parse);
+    assert!(err.error.to_string().contains("line 3"));
+}
+
+#[test]
+fn ndjson_row_stream_reports_errors_for_non_ndjson_format() {
+    let registry = default_registry();
+    let src = Arc::new(InMemorySource::from_string("json", "{}"));
+    let spec = InputSpec::new("json", src)
+        .with_format(FormatKind::Json)
+        .with_candidates(vec![FormatKind::Json]);
+
+    let engine = IoEngine::new(registry, ErrorPolicy::Accumulate, vec![spec], Vec::new());
+
+    let mut iter = engine.read_ndjson_records::<Row>();
+    let err = iter
+        .next()
+        .expect("one result")
+        .expect_err("expected error for non-ndjson input");
+
+    assert_eq!(err.stage, crate::error::Stage::ResolveInput);
+    assert_eq!(err.target, "json");
+}
+
+#[test]
+fn ndjson_write_stream_writes_one_line_per_record() {
+    let registry = default_registry();
+    let sink = InMemorySink::new("out");
+    let target = Arc::new(sink.clone());
+    let spec = OutputSpec::new("out", target)
+        .with_format(FormatKind::Ndjson)
+        .with_candidates(vec![FormatKind::Ndjson]);
+
+    let engine = IoEngine::new(registry, ErrorPolicy::Accumulate, Vec::new(), vec![spec]);
+
+    let rows = vec![
+        Row {
+            name: "foo".into(),
+            value: 1,
+        },
+        Row {
+            name: "bar".into(),
+            value: 2,
+        },
+    ];
+    engine
+        .write_ndjson_records(rows.into_iter())
+        .expect("write_ndjson_records should succeed");
+
+    let contents = sink.contents_string();
+    let lines: Vec<&str> = contents.lines().collect();
+    assert_eq!(lines.len(), 2);
+    assert_eq!(
+        serde_json::from_str::<Row>(lines[0]).unwrap(),
+        Row {
+            name: "foo".into(),
+            value: 1,
+        }
+    );
+    assert_eq!(
+        serde_json::from_str::<Row>(lines[1]).unwrap(),
+        Row {
+            name: "bar".into(),
+            value: 2,
+        }
+    );
+}
+
+#[derive(Debug)]
+struct FailAfterOneOutput {
+    id: String,
+    dest_path: std::path::PathBuf,
+}
+
+struct FailAfterOneWriter {
+    inner: std::fs::File,
+    writes: std::sync::Arc<std::sync::atomic::AtomicUsize>,
+}
+
+impl std::io::Write for FailAfterOneWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        if self.writes.fetch_add(1, std::sync::atomic::Ordering::SeqCst) >= 1 {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::BrokenPipe,
+                "simulated write failure",
+            ));
+        }
+        self.inner.write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+impl crate::io::OutputTarget for FailAfterOneOutput {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn open_overwrite(&self) -> std::io::Result<Box<dyn std::io::Write + Send>> {
+        unreachable!("test only exercises AtomicOverwrite")
+    }
+
+    fn open_append(&self) -> std::io::Result<Box<dyn std::io::Write + Send>> {
+        unreachable!("test only exercises AtomicOverwrite")
+    }
+
+    fn open_overwrite_at(&self, path: &std::path::Path) -> std::io::Result<Box<dyn std::io::Write + Send>> {
+        let inner = std::fs::File::create(path)?;
+        Ok(Box::new(FailAfterOneWriter {
+            inner,
+            writes: std::sync::Arc::new(std::sync::atomic::AtomicUsize::new(0)),
+        }))
+    }
+
+    fn file_path(&self) -> Option<&std::path::Path> {
+        Some(&self.dest_path)
+    }
+}
+
+#[test]
+fn ndjson_write_stream_atomic_overwrite_does_not_leak_temp_file_on_write_failure() {
+    // A write failure partway through the record loop must abandon (not
+    // leak) the `Atomic` sink's temp file.
+    let dir = tempfile::tempdir().expect("tempdir");
+    let dest_path = dir.path().join("out.ndjson");
+
+    let target = Arc::new(FailAfterOneOutput {
+        id: dest_path.to_string_lossy().into_owned(),
+        dest_path: dest_path.clone(),
+    });
+    let spec = OutputSpec::new(dest_path.to_string_lossy().into_owned(), target)
+        .with_format(FormatKind::Ndjson)
+        .with_candidates(vec![FormatKind::Ndjson])
+        .with_file_exists_policy(crate::config::FileExistsPolicy::AtomicOverwrite);
+
+    let registry = default_registry();
+    let engine = IoEngine::new(registry, ErrorPolicy::Accumulate, Vec::new(), vec![spec]);
+
+    let rows = vec![
+        Row {
+            name: "foo".into(),
+            value: 1,
+        },
+        Row {
+            name: "bar".into(),
+            value: 2,
+        },
+    ];
+
+    engine
+        .write_ndjson_records(rows.into_iter())
+        .expect_err("a mid-stream write failure should be reported");
+
+    assert!(
+        !dest_path.exists(),
+        "destination must never be created/modified when the write fails mid-stream"
+    );
+    let leftovers: Vec<_> = std::fs::read_dir(dir.path())
+        .expect("read dir")
+        .filter_map(|e| e.ok())
+        .collect();
+    assert!(leftovers.is_empty(), "temp file was not cleaned up");
+}
+
+#[test]
+fn ndjson_write_stream_fast_fail_abandons_other_open_sinks() {
+    // Under FastFail, one output's write failure stops the run immediately -
+    // but any other output already opened (and not itself failing) must
+    // still have its `Atomic` sink abandoned rather than leaked, since it
+    // never gets the rest of the records either.
+    let dir = tempfile::tempdir().expect("tempdir");
+    let failing_path = dir.path().join("failing.ndjson");
+    let healthy_path = dir.path().join("healthy.ndjson");
+
+    let failing_target = Arc::new(FailAfterOneOutput {
+        id: failing_path.to_string_lossy().into_owned(),
+        dest_path: failing_path.clone(),
+    });
+    let failing_spec = OutputSpec::new(failing_path.to_string_lossy().into_owned(), failing_target)
+        .with_format(FormatKind::Ndjson)
+        .with_candidates(vec![FormatKind::Ndjson])
+        .with_file_exists_policy(crate::config::FileExistsPolicy::AtomicOverwrite);
+
+    let healthy_target = Arc::new(crate::io::FileOutput::new(healthy_path.clone()));
+    let healthy_spec = OutputSpec::new(healthy_path.to_string_lossy().into_owned(), healthy_target)
+        .with_format(FormatKind::Ndjson)
+        .with_candidates(vec![FormatKind::Ndjson])
+        .with_file_exists_policy(crate::config::FileExistsPolicy::AtomicOverwrite);
+
+    let registry = default_registry();
+    let engine = IoEngine::new(
+        registry,
+        ErrorPolicy::FastFail,
+        Vec::new(),
+        vec![failing_spec, healthy_spec],
+    );
+
+    let rows = vec![
+        Row {
+            name: "foo".into(),
+            value: 1,
+        },
+        Row {
+            name: "bar".into(),
+            value: 2,
+        },
+    ];
+
+    engine
+        .write_ndjson_records(rows.into_iter())
+        .expect_err("fast-fail run should report an error");
+
+    assert!(!failing_path.exists(), "failing output must never be created");
+    assert!(!healthy_path.exists(), "abandoned output must never be created");
+
+    let leftovers: Vec<_> = std::fs::read_dir(dir.path())
+        .expect("read dir")
+        .filter_map(|e| e.ok())
+        .collect();
+    assert!(leftovers.is_empty(), "no temp file should survive an aborted fast-fail run");
+}
+
+#[test]
+fn ndjson_write_stream_reports_errors_for_non_ndjson_format() {
+    let registry = default_registry();
+    let sink = InMemorySink::new("out");
+    let target = Arc::new(sink);
+    let spec = OutputSpec::new("out", target)
+        .with_format(FormatKind::Json)
+        .with_candidates(vec![FormatKind::Json]);
+
+    let engine = IoEngine::new(registry, ErrorPolicy::Accumulate, Vec::new(), vec![spec]);
+
+    let rows = vec![Row {
+        name: "foo".into(),
+        value: 1,
+    }];
+    let err = engine
+        .write_ndjson_records(rows.into_iter())
+        .expect_err("expected error for non-ndjson output");
+
+    assert_eq!(err.errors.len(), 1);
+    assert_eq!(err.errors[0].stage, crate::error::Stage::ResolveOutput);
+    assert_eq!(err.errors[0].target, "out");
+}
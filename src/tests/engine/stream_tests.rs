@@ -1,10 +1,11 @@
-//! Tests for IoEngine::read_stream and AsyncIoEngine::read_stream_async.
+//! Tests for IoEngine::read_stream/write_stream and
+//! AsyncIoEngine::read_stream_async/write_stream_records_async.
 
 use std::sync::Arc;
 
-use crate::config::{InputSpec, OutputSpec};
+use crate::config::{FileExistsPolicy, InputSpec, OutputSpec};
 use crate::error::{ErrorPolicy, Stage};
-use crate::io::InMemorySource;
+use crate::io::{InMemorySink, InMemorySource};
 use crate::{FormatKind, IoEngine, default_registry};
 use serde::{Deserialize, Serialize};
 
@@ -60,6 +61,224 @@ fn sync_read_stream_returns_per_input_results() {
     assert!(iter.next().is_none());
 }
 
+#[test]
+fn sync_read_records_dynamic_yields_json_values() {
+    let src = Arc::new(InMemorySource::from_string(
+        "rows",
+        "{\"name\":\"foo\",\"value\":1}\n{\"name\":\"bar\",\"value\":2}\n",
+    ));
+
+    let spec = InputSpec::new("rows", src)
+        .with_format(FormatKind::Json)
+        .with_candidates(vec![FormatKind::Json]);
+
+    let engine = make_sync_engine(vec![spec]);
+
+    let rows: Vec<serde_json::Value> = engine
+        .read_records_dynamic()
+        .map(|r| r.expect("expected Ok rows"))
+        .collect();
+
+    assert_eq!(rows.len(), 2);
+    assert_eq!(rows[0]["name"], "foo");
+    assert_eq!(rows[0]["value"], 1);
+    assert_eq!(rows[1]["name"], "bar");
+    assert_eq!(rows[1]["value"], 2);
+}
+
+#[cfg(feature = "ndjson")]
+#[test]
+fn sync_write_stream_streams_ndjson_records_one_at_a_time() {
+    let sink = InMemorySink::new("out");
+    let target = Arc::new(sink.clone());
+    let spec = OutputSpec::new("out", target)
+        .with_format(FormatKind::Ndjson)
+        .with_candidates(vec![FormatKind::Ndjson])
+        .with_file_exists_policy(FileExistsPolicy::Overwrite);
+
+    let registry = default_registry();
+    let engine = IoEngine::new(registry, ErrorPolicy::Accumulate, Vec::new(), vec![spec]);
+
+    let rows = vec![
+        StreamConfig {
+            name: "foo".into(),
+            value: 1,
+        },
+        StreamConfig {
+            name: "bar".into(),
+            value: 2,
+        },
+    ];
+
+    engine
+        .write_stream(rows.into_iter())
+        .expect("write_stream should succeed");
+
+    let contents = sink.contents_string();
+    let lines: Vec<&str> = contents.lines().collect();
+    assert_eq!(lines.len(), 2);
+    assert_eq!(
+        serde_json::from_str::<StreamConfig>(lines[0]).unwrap(),
+        StreamConfig {
+            name: "foo".into(),
+            value: 1,
+        }
+    );
+    assert_eq!(
+        serde_json::from_str::<StreamConfig>(lines[1]).unwrap(),
+        StreamConfig {
+            name: "bar".into(),
+            value: 2,
+        }
+    );
+}
+
+#[cfg(feature = "ndjson")]
+#[test]
+fn sync_write_stream_atomic_overwrite_does_not_leak_temp_file_on_write_failure() {
+    // A mid-stream write failure through `write_stream_one`'s streaming
+    // encoder must abandon (not finalize) the `Atomic` sink, or the temp
+    // file it opened is leaked on disk forever.
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[derive(Debug)]
+    struct FailAfterOneOutput {
+        id: String,
+        dest_path: std::path::PathBuf,
+    }
+
+    struct FailAfterOneWriter {
+        inner: std::fs::File,
+        writes: Arc<AtomicUsize>,
+    }
+
+    impl std::io::Write for FailAfterOneWriter {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            if self.writes.fetch_add(1, Ordering::SeqCst) >= 1 {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::BrokenPipe,
+                    "simulated write failure",
+                ));
+            }
+            self.inner.write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            self.inner.flush()
+        }
+    }
+
+    impl crate::io::OutputTarget for FailAfterOneOutput {
+        fn id(&self) -> &str {
+            &self.id
+        }
+
+        fn open_overwrite(&self) -> std::io::Result<Box<dyn std::io::Write + Send>> {
+            unreachable!("test only exercises AtomicOverwrite")
+        }
+
+        fn open_append(&self) -> std::io::Result<Box<dyn std::io::Write + Send>> {
+            unreachable!("test only exercises AtomicOverwrite")
+        }
+
+        fn open_overwrite_at(&self, path: &std::path::Path) -> std::io::Result<Box<dyn std::io::Write + Send>> {
+            let inner = std::fs::File::create(path)?;
+            Ok(Box::new(FailAfterOneWriter {
+                inner,
+                writes: Arc::new(AtomicUsize::new(0)),
+            }))
+        }
+
+        fn file_path(&self) -> Option<&std::path::Path> {
+            Some(&self.dest_path)
+        }
+    }
+
+    let dir = tempfile::tempdir().expect("tempdir");
+    let dest_path = dir.path().join("out.ndjson");
+
+    let target = Arc::new(FailAfterOneOutput {
+        id: dest_path.to_string_lossy().into_owned(),
+        dest_path: dest_path.clone(),
+    });
+    let spec = OutputSpec::new(dest_path.to_string_lossy().into_owned(), target)
+        .with_format(FormatKind::Ndjson)
+        .with_candidates(vec![FormatKind::Ndjson])
+        .with_file_exists_policy(FileExistsPolicy::AtomicOverwrite);
+
+    let registry = default_registry();
+    let engine = IoEngine::new(registry, ErrorPolicy::Accumulate, Vec::new(), vec![spec]);
+
+    let rows = vec![
+        StreamConfig {
+            name: "foo".into(),
+            value: 1,
+        },
+        StreamConfig {
+            name: "bar".into(),
+            value: 2,
+        },
+    ];
+
+    engine
+        .write_stream(rows.into_iter())
+        .expect_err("a mid-stream write failure should be reported");
+
+    assert!(
+        !dest_path.exists(),
+        "destination must never be created/modified when the write fails mid-stream"
+    );
+    let leftovers: Vec<_> = std::fs::read_dir(dir.path())
+        .expect("read dir")
+        .filter_map(|e| e.ok())
+        .collect();
+    assert!(leftovers.is_empty(), "temp file was not cleaned up");
+}
+
+#[test]
+fn sync_write_stream_falls_back_to_write_all_with_multiple_outputs() {
+    let sink_a = InMemorySink::new("a");
+    let sink_b = InMemorySink::new("b");
+
+    let spec_a = OutputSpec::new("a", Arc::new(sink_a.clone()))
+        .with_format(FormatKind::Json)
+        .with_candidates(vec![FormatKind::Json])
+        .with_file_exists_policy(FileExistsPolicy::Overwrite);
+    let spec_b = OutputSpec::new("b", Arc::new(sink_b.clone()))
+        .with_format(FormatKind::Json)
+        .with_candidates(vec![FormatKind::Json])
+        .with_file_exists_policy(FileExistsPolicy::Overwrite);
+
+    let registry = default_registry();
+    let engine = IoEngine::new(
+        registry,
+        ErrorPolicy::Accumulate,
+        Vec::new(),
+        vec![spec_a, spec_b],
+    );
+
+    let rows = vec![StreamConfig {
+        name: "foo".into(),
+        value: 1,
+    }];
+
+    engine
+        .write_stream(rows.into_iter())
+        .expect("write_stream should succeed");
+
+    for sink in [&sink_a, &sink_b] {
+        let decoded: Vec<StreamConfig> =
+            serde_json::from_str(&sink.contents_string()).expect("output must be valid json");
+        assert_eq!(
+            decoded,
+            vec![StreamConfig {
+                name: "foo".into(),
+                value: 1,
+            }]
+        );
+    }
+}
+
 #[cfg(feature = "async")]
 mod async_stream {
     use super::*;
@@ -67,9 +286,9 @@ mod async_stream {
 
     use futures::StreamExt;
 
-    use crate::config::AsyncInputSpec;
+    use crate::config::{AsyncInputSpec, AsyncOutputSpec, FileExistsPolicy};
     use crate::format::{CustomFormat, FormatError, FormatRegistry};
-    use crate::io::AsyncFileInput;
+    use crate::io::{AsyncFileInput, AsyncFileOutput};
     use crate::{AsyncIoEngine, default_async_registry};
 
     #[tokio::test]
@@ -155,6 +374,36 @@ mod async_stream {
         assert_eq!(rows[1].value, 2);
     }
 
+    #[tokio::test]
+    async fn async_read_records_dynamic_async_yields_json_values() {
+        let dir = tempfile::tempdir().unwrap();
+
+        let path = dir.path().join("rows.jsonl");
+        let jsonl = "{\"name\":\"foo\",\"value\":1}\n{\"name\":\"bar\",\"value\":2}\n";
+        tokio::fs::write(&path, jsonl).await.unwrap();
+
+        let id = path.to_string_lossy().to_string();
+        let spec = AsyncInputSpec::new(id, Arc::new(AsyncFileInput::new(path.clone())))
+            .with_format(FormatKind::Json)
+            .with_candidates(vec![FormatKind::Json]);
+
+        let registry = default_async_registry();
+        let outputs: Vec<crate::config::AsyncOutputSpec> = Vec::new();
+        let engine = AsyncIoEngine::new(registry, ErrorPolicy::Accumulate, vec![spec], outputs);
+
+        let rows: Vec<serde_json::Value> = engine
+            .read_records_dynamic_async(1)
+            .map(|r| r.expect("expected Ok rows"))
+            .collect()
+            .await;
+
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0]["name"], "foo");
+        assert_eq!(rows[0]["value"], 1);
+        assert_eq!(rows[1]["name"], "bar");
+        assert_eq!(rows[1]["value"], 2);
+    }
+
     #[tokio::test]
     #[cfg(feature = "csv")]
     async fn async_read_records_async_streams_csv_rows() {
@@ -296,6 +545,83 @@ mod async_stream {
         );
     }
 
+    #[tokio::test]
+    async fn async_read_records_async_with_zero_concurrency_does_not_hang() {
+        // `buffer_unordered(0)` never polls any of its inner futures and
+        // hangs forever, so `concurrency: 0` must be clamped up to 1 rather
+        // than passed straight through. Wrapped in a timeout so a regression
+        // fails this test instead of hanging the whole suite.
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("a.jsonl");
+        tokio::fs::write(&path, "{\"name\":\"a\",\"value\":1}\n")
+            .await
+            .unwrap();
+
+        let id = path.to_string_lossy().to_string();
+        let spec = AsyncInputSpec::new(id, Arc::new(AsyncFileInput::new(path)))
+            .with_format(FormatKind::Json)
+            .with_candidates(vec![FormatKind::Json]);
+
+        let registry = default_async_registry();
+        let outputs: Vec<crate::config::AsyncOutputSpec> = Vec::new();
+        let engine = AsyncIoEngine::new(registry, ErrorPolicy::Accumulate, vec![spec], outputs);
+
+        let results: Vec<Result<StreamConfig, crate::error::SingleIoError>> = tokio::time::timeout(
+            std::time::Duration::from_secs(5),
+            engine.read_records_async::<StreamConfig>(0).collect(),
+        )
+        .await
+        .expect("concurrency: 0 should not hang");
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].as_ref().expect("expected Ok row").name, "a");
+    }
+
+    #[tokio::test]
+    async fn async_read_records_async_ordered_preserves_input_order() {
+        let dir = tempfile::tempdir().unwrap();
+
+        let mk_spec = |name: &str| async {
+            let path = dir.path().join(format!("{name}.jsonl"));
+            let jsonl = format!("{{\"name\":\"{name}\",\"value\":1}}\n");
+            tokio::fs::write(&path, jsonl).await.unwrap();
+
+            let id = path.to_string_lossy().to_string();
+            AsyncInputSpec::new(id, Arc::new(AsyncFileInput::new(path)))
+                .with_format(FormatKind::Json)
+                .with_candidates(vec![FormatKind::Json])
+        };
+
+        let a = mk_spec("a").await;
+        let b = mk_spec("b").await;
+        let c = mk_spec("c").await;
+
+        let inputs = vec![a, b, c];
+
+        let registry = default_async_registry();
+        let outputs: Vec<crate::config::AsyncOutputSpec> = Vec::new();
+        let engine = AsyncIoEngine::new(registry, ErrorPolicy::Accumulate, inputs, outputs);
+
+        let results: Vec<Result<StreamConfig, crate::error::SingleIoError>> = engine
+            .read_records_async_ordered::<StreamConfig>(4)
+            .collect()
+            .await;
+
+        assert_eq!(results.len(), 3);
+
+        let names: Vec<String> = results
+            .into_iter()
+            .map(|r| r.expect("expected Ok rows").name)
+            .collect();
+
+        // Unlike `read_records_async`, order must match the inputs exactly,
+        // even though concurrency > 1.
+        assert_eq!(
+            names,
+            vec!["a".to_string(), "b".to_string(), "c".to_string()]
+        );
+    }
+
     #[tokio::test]
     async fn async_read_records_async_streams_custom_ndjson_via_bridge() {
         let dir = tempfile::tempdir().unwrap();
@@ -364,4 +690,184 @@ mod async_stream {
         assert_eq!(rows[1].name, "bar");
         assert_eq!(rows[1].value, 2);
     }
+
+    #[tokio::test]
+    #[cfg(feature = "ndjson")]
+    async fn async_write_stream_records_async_streams_ndjson_items() {
+        use crate::AsyncIoEngine;
+
+        let dir = tempfile::tempdir().unwrap();
+        let out_path = dir.path().join("out.ndjson");
+
+        let out_target = Arc::new(AsyncFileOutput::new(out_path.clone()));
+        let output_spec = AsyncOutputSpec::new(out_path.to_string_lossy().into_owned(), out_target)
+            .with_format(FormatKind::Ndjson)
+            .with_candidates(vec![FormatKind::Ndjson])
+            .with_file_exists_policy(FileExistsPolicy::Overwrite);
+
+        let registry = crate::default_async_registry();
+        let engine = AsyncIoEngine::new(
+            registry,
+            ErrorPolicy::Accumulate,
+            Vec::new(),
+            vec![output_spec],
+        );
+
+        let rows = vec![
+            StreamConfig {
+                name: "foo".into(),
+                value: 1,
+            },
+            StreamConfig {
+                name: "bar".into(),
+                value: 2,
+            },
+        ];
+
+        engine
+            .write_stream_records_async(futures::stream::iter(rows))
+            .await
+            .expect("write_stream_records_async should succeed");
+
+        let contents = tokio::fs::read_to_string(&out_path).await.unwrap();
+        let lines: Vec<&str> = contents.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(
+            serde_json::from_str::<StreamConfig>(lines[0]).unwrap(),
+            StreamConfig {
+                name: "foo".into(),
+                value: 1,
+            }
+        );
+        assert_eq!(
+            serde_json::from_str::<StreamConfig>(lines[1]).unwrap(),
+            StreamConfig {
+                name: "bar".into(),
+                value: 2,
+            }
+        );
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "ndjson")]
+    async fn async_write_stream_records_async_atomic_overwrite_leaves_dest_untouched_on_write_failure() {
+        // A mid-stream write failure on an `AtomicOverwrite` sink must never
+        // finalize (shutdown/fsync/rename) the partial temp file over the
+        // real destination - that would silently violate the "only the old
+        // file or the complete new one, never a truncated one" guarantee.
+        use std::path::Path;
+        use std::pin::Pin;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::task::{Context, Poll};
+        use tokio::io::AsyncWrite;
+
+        #[derive(Debug)]
+        struct FlakyAtomicOutput {
+            id: String,
+            dest_path: std::path::PathBuf,
+        }
+
+        struct FailAfterOneWriter {
+            inner: tokio::fs::File,
+            writes: Arc<AtomicUsize>,
+        }
+
+        impl AsyncWrite for FailAfterOneWriter {
+            fn poll_write(
+                mut self: Pin<&mut Self>,
+                cx: &mut Context<'_>,
+                buf: &[u8],
+            ) -> Poll<std::io::Result<usize>> {
+                if self.writes.fetch_add(1, Ordering::SeqCst) >= 1 {
+                    return Poll::Ready(Err(std::io::Error::new(
+                        std::io::ErrorKind::BrokenPipe,
+                        "simulated write failure",
+                    )));
+                }
+                Pin::new(&mut self.inner).poll_write(cx, buf)
+            }
+
+            fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+                Pin::new(&mut self.inner).poll_flush(cx)
+            }
+
+            fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+                Pin::new(&mut self.inner).poll_shutdown(cx)
+            }
+        }
+
+        #[async_trait::async_trait]
+        impl crate::io::AsyncOutputTarget for FlakyAtomicOutput {
+            fn id(&self) -> &str {
+                &self.id
+            }
+
+            async fn open_overwrite(&self) -> std::io::Result<Box<dyn AsyncWrite + Unpin + Send>> {
+                unreachable!("AtomicOverwrite opens via open_overwrite_at, not open_overwrite")
+            }
+
+            async fn open_append(&self) -> std::io::Result<Box<dyn AsyncWrite + Unpin + Send>> {
+                unreachable!("test only exercises AtomicOverwrite")
+            }
+
+            async fn open_overwrite_at(&self, path: &Path) -> std::io::Result<Box<dyn AsyncWrite + Unpin + Send>> {
+                let file = tokio::fs::File::create(path).await?;
+                Ok(Box::new(FailAfterOneWriter {
+                    inner: file,
+                    writes: Arc::new(AtomicUsize::new(0)),
+                }))
+            }
+
+            fn file_path(&self) -> Option<&Path> {
+                Some(&self.dest_path)
+            }
+        }
+
+        let dir = tempfile::tempdir().unwrap();
+        let dest_path = dir.path().join("out.ndjson");
+
+        let target = Arc::new(FlakyAtomicOutput {
+            id: dest_path.to_string_lossy().into_owned(),
+            dest_path: dest_path.clone(),
+        });
+        let output_spec = AsyncOutputSpec::new(dest_path.to_string_lossy().into_owned(), target)
+            .with_format(FormatKind::Ndjson)
+            .with_candidates(vec![FormatKind::Ndjson])
+            .with_file_exists_policy(FileExistsPolicy::AtomicOverwrite);
+
+        let registry = crate::default_async_registry();
+        let engine = AsyncIoEngine::new(
+            registry,
+            ErrorPolicy::Accumulate,
+            Vec::new(),
+            vec![output_spec],
+        );
+
+        let rows = vec![
+            StreamConfig {
+                name: "foo".into(),
+                value: 1,
+            },
+            StreamConfig {
+                name: "bar".into(),
+                value: 2,
+            },
+        ];
+
+        let result = engine
+            .write_stream_records_async(futures::stream::iter(rows))
+            .await;
+        assert!(result.is_err(), "a mid-stream write failure should be reported");
+
+        assert!(
+            tokio::fs::metadata(&dest_path).await.is_err(),
+            "destination must never be created/modified when the write fails mid-stream"
+        );
+
+        let mut entries = tokio::fs::read_dir(dir.path()).await.unwrap();
+        assert!(
+            entries.next_entry().await.unwrap().is_none(),
+            "no temp file should survive a failed atomic write"
+        );
+    }
 }
@@ -4,8 +4,8 @@ use std::sync::Arc;
 
 use crate::config::{FileExistsPolicy, InputSpec, OutputSpec};
 use crate::error::{AggregateError, ErrorPolicy, Stage};
-use crate::io::{InMemorySink, InMemorySource, InputProvider};
-use crate::{FormatKind, IoEngine, default_registry};
+use crate::io::{FileOutput, InMemorySink, InMemorySource, InputProvider};
+use crate::{default_registry, FormatKind, IoEngine};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
@@ -146,4 +146,606 @@ fn sync_engine_accumulate_parse_errors() {
     assert!(targets.contains(&"bad2"));
 }
 
+#[test]
+fn sync_engine_atomic_overwrite_replaces_file_contents() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let out_path = dir.path().join("report.json");
+    std::fs::write(&out_path, "stale contents").expect("seed existing file");
+
+    let target = Arc::new(FileOutput::new(out_path.clone()));
+    let out_spec = OutputSpec::new(out_path.to_string_lossy().into_owned(), target)
+        .with_format(FormatKind::Json)
+        .with_file_exists_policy(FileExistsPolicy::AtomicOverwrite);
+
+    let engine = make_engine(ErrorPolicy::FastFail, Vec::new(), vec![out_spec]);
+    let values = vec![Config {
+        name: "a".into(),
+        value: 1,
+    }];
+    engine
+        .write_all(&values)
+        .expect("atomic write should succeed");
+
+    let decoded: Vec<Config> =
+        serde_json::from_str(&std::fs::read_to_string(&out_path).expect("read output"))
+            .expect("output must be valid json");
+    assert_eq!(decoded, values);
+
+    // No leftover temp file should remain in the directory.
+    let leftovers: Vec<_> = std::fs::read_dir(dir.path())
+        .expect("read dir")
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_name().to_string_lossy().contains(".tmp-"))
+        .collect();
+    assert!(leftovers.is_empty(), "temp file was not cleaned up");
+}
+
+#[cfg(feature = "encryption")]
+#[test]
+fn sync_engine_atomic_overwrite_still_encrypts_the_written_file() {
+    use crate::io::{EncryptedInput, EncryptedOutput, InputProvider, SecretKey};
+    use std::io::Read;
+
+    let dir = tempfile::tempdir().expect("tempdir");
+    let out_path = dir.path().join("report.json");
+
+    let key = SecretKey::generate();
+    let file_target = Arc::new(FileOutput::new(out_path.clone()));
+    let encrypted_target = Arc::new(EncryptedOutput::new(file_target, key.clone()));
+    let out_spec = OutputSpec::new(out_path.to_string_lossy().into_owned(), encrypted_target)
+        .with_format(FormatKind::Json)
+        .with_file_exists_policy(FileExistsPolicy::AtomicOverwrite);
+
+    let engine = make_engine(ErrorPolicy::FastFail, Vec::new(), vec![out_spec]);
+    let values = vec![Config {
+        name: "a".into(),
+        value: 1,
+    }];
+    engine
+        .write_all(&values)
+        .expect("atomic write should succeed");
+
+    // The on-disk bytes must be ciphertext, not the plaintext JSON the
+    // engine serialized - a plaintext leak here would mean AtomicOverwrite
+    // bypassed the encrypting target entirely.
+    let on_disk = std::fs::read(&out_path).expect("read output");
+    let plaintext_json = serde_json::to_vec(&values).expect("serialize expected plaintext");
+    assert_ne!(on_disk, plaintext_json);
+
+    let file_input = Arc::new(crate::io::FileInput::new(out_path.clone()));
+    let decrypting_input = EncryptedInput::new(file_input, key);
+    let mut decrypted = Vec::new();
+    decrypting_input
+        .open()
+        .expect("open encrypted file")
+        .read_to_end(&mut decrypted)
+        .expect("decrypt output");
+    let decoded: Vec<Config> = serde_json::from_slice(&decrypted).expect("decrypted output must be valid json");
+    assert_eq!(decoded, values);
+}
+
+#[test]
+fn sync_engine_file_exists_policy_error_fails_when_target_exists() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let out_path = dir.path().join("report.json");
+    std::fs::write(&out_path, "stale contents").expect("seed existing file");
+
+    let target = Arc::new(FileOutput::new(out_path.clone()));
+    let out_spec = OutputSpec::new(out_path.to_string_lossy().into_owned(), target)
+        .with_format(FormatKind::Json)
+        .with_file_exists_policy(FileExistsPolicy::Error);
+
+    let engine = make_engine(ErrorPolicy::FastFail, Vec::new(), vec![out_spec]);
+    let values = vec![Config {
+        name: "a".into(),
+        value: 1,
+    }];
+
+    let err = engine
+        .write_all(&values)
+        .expect_err("expected Error policy to reject an existing file");
+    assert_eq!(err.errors.len(), 1);
+    assert_eq!(err.errors[0].stage, Stage::Open);
+
+    // The original file must be untouched.
+    assert_eq!(
+        std::fs::read_to_string(&out_path).unwrap(),
+        "stale contents"
+    );
+}
+
+#[test]
+fn sync_engine_read_all_parallel_preserves_order() {
+    let specs: Vec<InputSpec> = (0..8)
+        .map(|i| {
+            let json = format!(r#"{{"name": "n{i}", "value": {i}}}"#);
+            let src = Arc::new(InMemorySource::from_string(format!("in{i}"), json));
+            InputSpec::new(format!("in{i}"), src)
+                .with_format(FormatKind::Json)
+                .with_candidates(vec![FormatKind::Json])
+        })
+        .collect();
+
+    let engine = make_engine(ErrorPolicy::Accumulate, specs, Vec::new()).with_concurrency(3);
+
+    let results: Vec<Config> = engine
+        .read_all_parallel()
+        .expect("parallel read should succeed");
+    assert_eq!(results.len(), 8);
+    for (i, config) in results.iter().enumerate() {
+        assert_eq!(config.name, format!("n{i}"));
+        assert_eq!(config.value, i as i32);
+    }
+}
+
+#[test]
+fn sync_engine_read_all_parallel_without_concurrency_matches_read_all() {
+    let src = Arc::new(InMemorySource::from_string(
+        "in",
+        r#"{"name": "a", "value": 1}"#,
+    ));
+    let spec = InputSpec::new("in", src)
+        .with_format(FormatKind::Json)
+        .with_candidates(vec![FormatKind::Json]);
+
+    let engine = make_engine(ErrorPolicy::FastFail, vec![spec], Vec::new());
+    let results: Vec<Config> = engine
+        .read_all_parallel()
+        .expect("should fall back to read_all");
+    assert_eq!(
+        results,
+        vec![Config {
+            name: "a".into(),
+            value: 1
+        }]
+    );
+}
+
+#[test]
+fn sync_engine_read_all_parallel_fast_fail_returns_single_error() {
+    let good = Arc::new(InMemorySource::from_string(
+        "ok",
+        r#"{"name": "ok", "value": 1}"#,
+    ));
+    let spec_ok = InputSpec::new("ok", good)
+        .with_format(FormatKind::Json)
+        .with_candidates(vec![FormatKind::Json]);
+
+    let bad = Arc::new(InMemorySource::from_string("bad", "{not-json"));
+    let spec_bad = InputSpec::new("bad", bad)
+        .with_format(FormatKind::Json)
+        .with_candidates(vec![FormatKind::Json]);
+
+    let engine =
+        make_engine(ErrorPolicy::FastFail, vec![spec_ok, spec_bad], Vec::new()).with_concurrency(2);
+
+    let result: Result<Vec<Config>, AggregateError> = engine.read_all_parallel();
+    let agg = result.expect_err("expected a fast-fail error");
+    assert_eq!(agg.errors.len(), 1);
+}
+
+#[test]
+fn sync_engine_write_all_parallel_writes_every_output() {
+    let sinks: Vec<_> = (0..5)
+        .map(|i| Arc::new(InMemorySink::new(format!("out{i}"))))
+        .collect();
+    let out_specs: Vec<OutputSpec> = sinks
+        .iter()
+        .enumerate()
+        .map(|(i, sink)| {
+            OutputSpec::new(format!("out{i}"), sink.clone())
+                .with_format(FormatKind::Json)
+                .with_file_exists_policy(FileExistsPolicy::Overwrite)
+        })
+        .collect();
+
+    let engine = make_engine(ErrorPolicy::Accumulate, Vec::new(), out_specs).with_concurrency(3);
+    let values = vec![Config {
+        name: "a".into(),
+        value: 1,
+    }];
+
+    engine
+        .write_all_parallel(&values)
+        .expect("parallel write should succeed");
+
+    for sink in &sinks {
+        let bytes = sink.contents();
+        assert!(!bytes.is_empty());
+    }
+}
+
+#[test]
+fn sync_engine_retry_policy_recovers_from_transient_failures() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    // Fails with a transient error twice, then succeeds on the third open.
+    #[derive(Debug)]
+    struct FlakyInput {
+        id: String,
+        attempts: AtomicUsize,
+    }
+
+    impl InputProvider for FlakyInput {
+        fn id(&self) -> &str {
+            &self.id
+        }
+
+        fn open(&self) -> std::io::Result<Box<dyn std::io::Read + Send>> {
+            if self.attempts.fetch_add(1, Ordering::SeqCst) < 2 {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::TimedOut,
+                    "simulated transient timeout",
+                ));
+            }
+            Ok(Box::new(std::io::Cursor::new(
+                br#"{"name": "a", "value": 1}"#.to_vec(),
+            )))
+        }
+    }
+
+    let src = Arc::new(FlakyInput {
+        id: "flaky".to_string(),
+        attempts: AtomicUsize::new(0),
+    });
+
+    let spec = InputSpec::new("flaky", src)
+        .with_format(FormatKind::Json)
+        .with_candidates(vec![FormatKind::Json]);
+
+    let engine = make_engine(
+        ErrorPolicy::Retry {
+            max_attempts: 5,
+            base_delay: std::time::Duration::from_millis(1),
+            max_delay: std::time::Duration::from_millis(5),
+        },
+        vec![spec],
+        Vec::new(),
+    );
+
+    let values: Vec<Config> = engine.read_all().expect("should recover after retries");
+    assert_eq!(
+        values,
+        vec![Config {
+            name: "a".into(),
+            value: 1
+        }]
+    );
+}
+
+#[test]
+fn sync_engine_retry_policy_recovers_from_connection_reset() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    // Same shape as `sync_engine_retry_policy_recovers_from_transient_failures`,
+    // but with ConnectionReset instead of TimedOut, since both are classified
+    // transient and should retry identically.
+    #[derive(Debug)]
+    struct FlakyInput {
+        id: String,
+        attempts: AtomicUsize,
+    }
+
+    impl InputProvider for FlakyInput {
+        fn id(&self) -> &str {
+            &self.id
+        }
+
+        fn open(&self) -> std::io::Result<Box<dyn std::io::Read + Send>> {
+            if self.attempts.fetch_add(1, Ordering::SeqCst) < 1 {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::ConnectionReset,
+                    "simulated connection reset",
+                ));
+            }
+            Ok(Box::new(std::io::Cursor::new(
+                br#"{"name": "a", "value": 1}"#.to_vec(),
+            )))
+        }
+    }
+
+    let src = Arc::new(FlakyInput {
+        id: "flaky-reset".to_string(),
+        attempts: AtomicUsize::new(0),
+    });
+
+    let spec = InputSpec::new("flaky-reset", src)
+        .with_format(FormatKind::Json)
+        .with_candidates(vec![FormatKind::Json]);
+
+    let engine = make_engine(
+        ErrorPolicy::Retry {
+            max_attempts: 3,
+            base_delay: std::time::Duration::from_millis(1),
+            max_delay: std::time::Duration::from_millis(2),
+        },
+        vec![spec],
+        Vec::new(),
+    );
+
+    let values: Vec<Config> = engine.read_all().expect("should recover after retries");
+    assert_eq!(
+        values,
+        vec![Config {
+            name: "a".into(),
+            value: 1
+        }]
+    );
+}
+
+#[test]
+fn sync_engine_retry_policy_gives_up_after_max_attempts() {
+    #[derive(Debug)]
+    struct AlwaysFailsInput {
+        id: String,
+    }
+
+    impl InputProvider for AlwaysFailsInput {
+        fn id(&self) -> &str {
+            &self.id
+        }
+
+        fn open(&self) -> std::io::Result<Box<dyn std::io::Read + Send>> {
+            Err(std::io::Error::new(
+                std::io::ErrorKind::TimedOut,
+                "simulated persistent timeout",
+            ))
+        }
+    }
+
+    let src = Arc::new(AlwaysFailsInput {
+        id: "broken".to_string(),
+    });
+    let spec = InputSpec::new("broken", src)
+        .with_format(FormatKind::Json)
+        .with_candidates(vec![FormatKind::Json]);
+
+    let engine = make_engine(
+        ErrorPolicy::Retry {
+            max_attempts: 3,
+            base_delay: std::time::Duration::from_millis(1),
+            max_delay: std::time::Duration::from_millis(2),
+        },
+        vec![spec],
+        Vec::new(),
+    );
+
+    let result: Result<Vec<Config>, AggregateError> = engine.read_all();
+    let agg = result.expect_err("expected failure after exhausting retries");
+    assert_eq!(agg.errors.len(), 1);
+    assert_eq!(agg.errors[0].attempts, 3);
+}
+
+#[test]
+fn sync_engine_retry_policy_does_not_retry_permanent_errors() {
+    #[derive(Debug)]
+    struct NotFoundInput {
+        id: String,
+        attempts: std::sync::atomic::AtomicUsize,
+    }
+
+    impl InputProvider for NotFoundInput {
+        fn id(&self) -> &str {
+            &self.id
+        }
+
+        fn open(&self) -> std::io::Result<Box<dyn std::io::Read + Send>> {
+            self.attempts
+                .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            Err(std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                "simulated missing file",
+            ))
+        }
+    }
+
+    let src = Arc::new(NotFoundInput {
+        id: "missing".to_string(),
+        attempts: std::sync::atomic::AtomicUsize::new(0),
+    });
+    let spec = InputSpec::new("missing", src.clone())
+        .with_format(FormatKind::Json)
+        .with_candidates(vec![FormatKind::Json]);
+
+    let engine = make_engine(
+        ErrorPolicy::Retry {
+            max_attempts: 5,
+            base_delay: std::time::Duration::from_millis(1),
+            max_delay: std::time::Duration::from_millis(2),
+        },
+        vec![spec],
+        Vec::new(),
+    );
+
+    let result: Result<Vec<Config>, AggregateError> = engine.read_all();
+    result.expect_err("expected permanent failure");
+    assert_eq!(src.attempts.load(std::sync::atomic::Ordering::SeqCst), 1);
+}
+
+#[test]
+fn sync_engine_retry_policy_recovers_from_write_side_transient_failure() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    // Unlike the other retry tests above, which all fail on `open()`, this
+    // one fails on the write call itself: `open_overwrite` succeeds every
+    // time, but the returned writer's `write_all` fails with a transient
+    // `ConnectionReset` on its first call. This only retries if a write
+    // failure is tagged with a stage that `is_transient` actually checks
+    // (`Stage::Write`, not `Stage::Serialize`).
+    #[derive(Debug)]
+    struct FlakyOutput {
+        id: String,
+        attempts: Arc<AtomicUsize>,
+    }
+
+    struct FlakyWriter {
+        attempts: Arc<AtomicUsize>,
+    }
+
+    impl std::io::Write for FlakyWriter {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            if self.attempts.fetch_add(1, Ordering::SeqCst) < 1 {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::ConnectionReset,
+                    "simulated connection reset on write",
+                ));
+            }
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl crate::io::OutputTarget for FlakyOutput {
+        fn id(&self) -> &str {
+            &self.id
+        }
+
+        fn open_overwrite(&self) -> std::io::Result<Box<dyn std::io::Write + Send>> {
+            Ok(Box::new(FlakyWriter {
+                attempts: self.attempts.clone(),
+            }))
+        }
+
+        fn open_append(&self) -> std::io::Result<Box<dyn std::io::Write + Send>> {
+            self.open_overwrite()
+        }
+    }
+
+    let target = Arc::new(FlakyOutput {
+        id: "flaky-write".to_string(),
+        attempts: Arc::new(AtomicUsize::new(0)),
+    });
+    let output_spec = OutputSpec::new("flaky-write", target.clone())
+        .with_format(FormatKind::Json)
+        .with_candidates(vec![FormatKind::Json])
+        .with_file_exists_policy(FileExistsPolicy::Overwrite);
+
+    let engine = make_engine(
+        ErrorPolicy::Retry {
+            max_attempts: 3,
+            base_delay: std::time::Duration::from_millis(1),
+            max_delay: std::time::Duration::from_millis(2),
+        },
+        Vec::new(),
+        vec![output_spec],
+    );
+
+    let values = vec![Config {
+        name: "a".into(),
+        value: 1,
+    }];
+
+    engine
+        .write_all(&values)
+        .expect("write should recover after a transient write-side failure");
+}
+
+#[test]
+fn sync_engine_retry_policy_does_not_retry_append_write_after_partial_write() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Mutex;
+
+    // Unlike the write-side retry test above, this writer's `write` call
+    // partially succeeds (accepting a prefix of the buffer) before the next
+    // call fails with an otherwise-transient `ConnectionReset`. Retrying an
+    // `Append` write means reopening the target and rewriting the *entire*
+    // buffer from scratch, which would duplicate that already-written
+    // prefix - so this must be treated as permanent and never retried, and
+    // `open_append` must only be called once.
+    #[derive(Debug)]
+    struct PartialWriteOutput {
+        id: String,
+        disk: Arc<Mutex<Vec<u8>>>,
+        open_attempts: Arc<AtomicUsize>,
+    }
+
+    struct PartialWriteWriter {
+        disk: Arc<Mutex<Vec<u8>>>,
+        calls: usize,
+    }
+
+    impl std::io::Write for PartialWriteWriter {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.calls += 1;
+            if self.calls == 1 {
+                let prefix = &buf[..buf.len().min(5)];
+                self.disk.lock().unwrap().extend_from_slice(prefix);
+                return Ok(prefix.len());
+            }
+            Err(std::io::Error::new(
+                std::io::ErrorKind::ConnectionReset,
+                "simulated connection reset mid-write",
+            ))
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl crate::io::OutputTarget for PartialWriteOutput {
+        fn id(&self) -> &str {
+            &self.id
+        }
+
+        fn open_overwrite(&self) -> std::io::Result<Box<dyn std::io::Write + Send>> {
+            self.open_append()
+        }
+
+        fn open_append(&self) -> std::io::Result<Box<dyn std::io::Write + Send>> {
+            self.open_attempts.fetch_add(1, Ordering::SeqCst);
+            Ok(Box::new(PartialWriteWriter {
+                disk: self.disk.clone(),
+                calls: 0,
+            }))
+        }
+    }
+
+    let disk = Arc::new(Mutex::new(Vec::new()));
+    let open_attempts = Arc::new(AtomicUsize::new(0));
+    let target = Arc::new(PartialWriteOutput {
+        id: "partial-append".to_string(),
+        disk: disk.clone(),
+        open_attempts: open_attempts.clone(),
+    });
+    let output_spec = OutputSpec::new("partial-append", target.clone())
+        .with_format(FormatKind::Json)
+        .with_candidates(vec![FormatKind::Json])
+        .with_file_exists_policy(FileExistsPolicy::Append);
+
+    let engine = make_engine(
+        ErrorPolicy::Retry {
+            max_attempts: 3,
+            base_delay: std::time::Duration::from_millis(1),
+            max_delay: std::time::Duration::from_millis(2),
+        },
+        Vec::new(),
+        vec![output_spec],
+    );
+
+    let values = vec![Config {
+        name: "a".into(),
+        value: 1,
+    }];
+
+    let agg = engine
+        .write_all(&values)
+        .expect_err("a partial write must not be silently retried");
+    assert_eq!(agg.errors[0].attempts, 1, "should fail on the first attempt, not retry");
+
+    assert_eq!(
+        open_attempts.load(Ordering::SeqCst),
+        1,
+        "open_append must only be called once - a retry would duplicate the partial write"
+    );
+    assert_eq!(
+        disk.lock().unwrap().len(),
+        5,
+        "disk should hold exactly the partial write, not a duplicated/extended one"
+    );
+}
+
 // Async engine tests could be added here with cfg(feature = "async") if desired.
@@ -0,0 +1,123 @@
+//! Verifies that the `tracing` instrumentation fires span/event pairs around
+//! successful and failing pipeline operations, for both engines.
+
+#![cfg(feature = "tracing")]
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use crate::config::{FileExistsPolicy, InputSpec, OutputSpec};
+use crate::error::ErrorPolicy;
+use crate::io::{InMemorySink, InMemorySource};
+use crate::{default_registry, FormatKind, IoEngine};
+use serde::{Deserialize, Serialize};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Metadata, Subscriber};
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+struct Config {
+    name: String,
+    value: i32,
+}
+
+/// A minimal subscriber that just counts spans entered and info/error events
+/// emitted, without inspecting field values. Good enough to confirm that
+/// `crate::trace` is actually wired into the engines rather than dead code.
+#[derive(Default)]
+struct CountingSubscriber {
+    spans_entered: AtomicUsize,
+    info_events: AtomicUsize,
+    error_events: AtomicUsize,
+}
+
+struct NoopVisit;
+impl Visit for NoopVisit {
+    fn record_debug(&mut self, _field: &Field, _value: &dyn std::fmt::Debug) {}
+}
+
+impl Subscriber for CountingSubscriber {
+    fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, _span: &Attributes<'_>) -> Id {
+        Id::from_u64(1)
+    }
+
+    fn record(&self, _span: &Id, _values: &Record<'_>) {}
+
+    fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        match *event.metadata().level() {
+            tracing::Level::ERROR => {
+                self.error_events.fetch_add(1, Ordering::SeqCst);
+            }
+            tracing::Level::INFO => {
+                self.info_events.fetch_add(1, Ordering::SeqCst);
+            }
+            _ => {}
+        }
+        event.record(&mut NoopVisit);
+    }
+
+    fn enter(&self, _span: &Id) {
+        self.spans_entered.fetch_add(1, Ordering::SeqCst);
+    }
+
+    fn exit(&self, _span: &Id) {}
+}
+
+#[test]
+fn tracing_emits_span_and_info_event_on_success() {
+    let subscriber = Arc::new(CountingSubscriber::default());
+    let _guard = tracing::subscriber::set_default(subscriber.clone());
+
+    let json = r#"{"name": "a", "value": 1}"#;
+    let src = Arc::new(InMemorySource::from_string("in", json));
+    let input_spec = InputSpec::new("in", src)
+        .with_format(FormatKind::Json)
+        .with_candidates(vec![FormatKind::Json]);
+
+    let sink = Arc::new(InMemorySink::new("out"));
+    let output_spec = OutputSpec::new("out", sink)
+        .with_format(FormatKind::Json)
+        .with_candidates(vec![FormatKind::Json])
+        .with_file_exists_policy(FileExistsPolicy::Overwrite);
+
+    let registry = default_registry();
+    let engine = IoEngine::new(
+        registry,
+        ErrorPolicy::FastFail,
+        vec![input_spec],
+        vec![output_spec],
+    );
+
+    let values: Vec<Config> = engine.read_all().expect("read_all should succeed");
+    engine.write_all(&values).expect("write_all should succeed");
+
+    assert!(subscriber.spans_entered.load(Ordering::SeqCst) >= 2);
+    assert!(subscriber.info_events.load(Ordering::SeqCst) >= 2);
+    assert_eq!(subscriber.error_events.load(Ordering::SeqCst), 0);
+}
+
+#[test]
+fn tracing_emits_error_event_on_parse_failure() {
+    let subscriber = Arc::new(CountingSubscriber::default());
+    let _guard = tracing::subscriber::set_default(subscriber.clone());
+
+    let src = Arc::new(InMemorySource::from_string("in", "{not-json"));
+    let input_spec = InputSpec::new("in", src)
+        .with_format(FormatKind::Json)
+        .with_candidates(vec![FormatKind::Json]);
+
+    let registry = default_registry();
+    let engine = IoEngine::new(registry, ErrorPolicy::FastFail, vec![input_spec], vec![]);
+
+    let result: Result<Vec<Config>, _> = engine.read_all();
+    result.expect_err("expected parse failure");
+
+    assert_eq!(subscriber.error_events.load(Ordering::SeqCst), 1);
+    assert_eq!(subscriber.info_events.load(Ordering::SeqCst), 0);
+}
@@ -5,11 +5,13 @@ use multiio::error::{AggregateError, SingleIoError, Stage};
 #[test]
 fn aggregate_error_display_includes_count() {
     let e1 = SingleIoError {
+        attempts: 1,
         stage: Stage::Open,
         target: "a".to_string(),
         error: Box::new(std::io::Error::new(std::io::ErrorKind::Other, "e1")),
     };
     let e2 = SingleIoError {
+        attempts: 1,
         stage: Stage::Parse,
         target: "b".to_string(),
         error: Box::new(std::io::Error::new(std::io::ErrorKind::Other, "e2")),
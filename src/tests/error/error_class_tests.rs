@@ -0,0 +1,98 @@
+//! Tests for `ErrorClass` classification on `SingleIoError`/`AggregateError`.
+
+use crate::error::{AggregateError, ErrorClass, SingleIoError, Stage};
+
+fn io_error(kind: std::io::ErrorKind, stage: Stage) -> SingleIoError {
+    SingleIoError {
+        attempts: 1,
+        stage,
+        target: "t".to_string(),
+        error: Box::new(std::io::Error::new(kind, "boom")),
+    }
+}
+
+#[test]
+fn classifies_io_errors_by_kind() {
+    assert_eq!(
+        io_error(std::io::ErrorKind::NotFound, Stage::Open).class(),
+        ErrorClass::NotFound
+    );
+    assert_eq!(
+        io_error(std::io::ErrorKind::PermissionDenied, Stage::Open).class(),
+        ErrorClass::PermissionDenied
+    );
+    assert_eq!(
+        io_error(std::io::ErrorKind::TimedOut, Stage::Open).class(),
+        ErrorClass::TimedOut
+    );
+    assert_eq!(
+        io_error(std::io::ErrorKind::Interrupted, Stage::Open).class(),
+        ErrorClass::Interrupted
+    );
+    assert_eq!(
+        io_error(std::io::ErrorKind::ConnectionReset, Stage::Open).class(),
+        ErrorClass::ConnectionReset
+    );
+    assert_eq!(
+        io_error(std::io::ErrorKind::Other, Stage::Open).class(),
+        ErrorClass::Other
+    );
+}
+
+#[test]
+fn stage_overrides_io_error_kind_for_parse_and_serialize() {
+    // Even though this carries an InvalidData io::Error, the Parse/Serialize
+    // stage wins since retrying the same bytes can never help.
+    assert_eq!(
+        io_error(std::io::ErrorKind::InvalidData, Stage::Parse).class(),
+        ErrorClass::Parse
+    );
+    assert_eq!(
+        io_error(std::io::ErrorKind::InvalidData, Stage::Serialize).class(),
+        ErrorClass::Serialize
+    );
+}
+
+#[test]
+fn non_io_error_classifies_as_other() {
+    let err = SingleIoError {
+        attempts: 1,
+        stage: Stage::Open,
+        target: "t".to_string(),
+        error: "not an io error".into(),
+    };
+
+    assert_eq!(err.class(), ErrorClass::Other);
+}
+
+#[test]
+fn aggregate_counts_by_class() {
+    let agg = AggregateError {
+        errors: vec![
+            io_error(std::io::ErrorKind::TimedOut, Stage::Open),
+            io_error(std::io::ErrorKind::TimedOut, Stage::Open),
+            io_error(std::io::ErrorKind::InvalidData, Stage::Parse),
+        ],
+    };
+
+    assert_eq!(
+        agg.count_by_class(),
+        vec![(ErrorClass::TimedOut, 2), (ErrorClass::Parse, 1)]
+    );
+    assert!(agg.has_class(ErrorClass::TimedOut));
+    assert!(!agg.has_class(ErrorClass::NotFound));
+}
+
+#[test]
+fn aggregate_display_includes_class_summary() {
+    let agg = AggregateError {
+        errors: vec![
+            io_error(std::io::ErrorKind::TimedOut, Stage::Open),
+            io_error(std::io::ErrorKind::InvalidData, Stage::Parse),
+        ],
+    };
+
+    let s = format!("{agg}");
+    assert!(s.contains("1 timed out"));
+    assert!(s.contains("1 parse error"));
+}
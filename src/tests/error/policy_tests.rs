@@ -11,6 +11,7 @@ fn error_policy_default_is_accumulate() {
 #[test]
 fn aggregate_error_single_and_len() {
     let err = SingleIoError {
+        attempts: 1,
         stage: Stage::Open,
         target: "test".to_string(),
         error: Box::new(std::io::Error::new(std::io::ErrorKind::Other, "oops")),
@@ -24,6 +25,7 @@ fn aggregate_error_single_and_len() {
 #[test]
 fn aggregate_error_from_single() {
     let err = SingleIoError {
+        attempts: 1,
         stage: Stage::Parse,
         target: "input".to_string(),
         error: Box::new(std::io::Error::new(std::io::ErrorKind::InvalidData, "bad")),
@@ -32,3 +34,65 @@ fn aggregate_error_from_single() {
     let agg: AggregateError = err.into();
     assert_eq!(agg.len(), 1);
 }
+
+#[test]
+fn transient_io_errors_are_retryable() {
+    for kind in [
+        std::io::ErrorKind::Interrupted,
+        std::io::ErrorKind::WouldBlock,
+        std::io::ErrorKind::TimedOut,
+        std::io::ErrorKind::UnexpectedEof,
+        std::io::ErrorKind::ConnectionReset,
+        std::io::ErrorKind::ConnectionAborted,
+        std::io::ErrorKind::BrokenPipe,
+    ] {
+        let err = SingleIoError {
+            attempts: 1,
+            stage: Stage::Open,
+            target: "net://example".to_string(),
+            error: Box::new(std::io::Error::new(kind, "transient")),
+        };
+        assert!(err.is_transient(), "{kind:?} should be transient");
+    }
+}
+
+#[test]
+fn permanent_errors_are_not_retryable() {
+    let not_found = SingleIoError {
+        attempts: 1,
+        stage: Stage::Open,
+        target: "missing".to_string(),
+        error: Box::new(std::io::Error::new(std::io::ErrorKind::NotFound, "gone")),
+    };
+    assert!(!not_found.is_transient());
+
+    let denied = SingleIoError {
+        attempts: 1,
+        stage: Stage::Open,
+        target: "locked".to_string(),
+        error: Box::new(std::io::Error::new(
+            std::io::ErrorKind::PermissionDenied,
+            "denied",
+        )),
+    };
+    assert!(!denied.is_transient());
+}
+
+#[test]
+fn parse_and_serialize_errors_are_never_retryable_even_with_transient_io_kind() {
+    let parse_err = SingleIoError {
+        attempts: 1,
+        stage: Stage::Parse,
+        target: "input".to_string(),
+        error: Box::new(std::io::Error::new(std::io::ErrorKind::TimedOut, "oops")),
+    };
+    assert!(!parse_err.is_transient());
+
+    let serialize_err = SingleIoError {
+        attempts: 1,
+        stage: Stage::Serialize,
+        target: "output".to_string(),
+        error: Box::new(std::io::Error::new(std::io::ErrorKind::TimedOut, "oops")),
+    };
+    assert!(!serialize_err.is_transient());
+}
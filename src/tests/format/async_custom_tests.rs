@@ -0,0 +1,119 @@
+//! Async custom format tests.
+
+#![cfg(feature = "async")]
+
+use crate::format::{AsyncCustomFormat, AsyncFormatRegistry, FormatError, FormatKind};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+struct TestData {
+    name: String,
+    value: i32,
+}
+
+fn bracket_format() -> AsyncCustomFormat {
+    AsyncCustomFormat::new("async-bracket", &["abrk"])
+        .with_deserialize(|bytes| {
+            let bytes = bytes.to_vec();
+            Box::pin(async move {
+                let s = String::from_utf8_lossy(&bytes);
+                let inner = s.trim_start_matches('[').trim_end_matches(']');
+                serde_json::from_str(inner).map_err(|e| FormatError::Serde(Box::new(e)))
+            })
+        })
+        .with_serialize(|value| {
+            let value = value.clone();
+            Box::pin(async move {
+                let json =
+                    serde_json::to_string(&value).map_err(|e| FormatError::Serde(Box::new(e)))?;
+                Ok(format!("[{}]", json).into_bytes())
+            })
+        })
+}
+
+#[tokio::test]
+async fn test_async_custom_format_registration() {
+    let mut registry = AsyncFormatRegistry::new();
+    registry.register_custom(bracket_format());
+
+    assert!(registry.has_format(&FormatKind::Custom("async-bracket")));
+    assert!(registry.get_custom("async-bracket").is_some());
+}
+
+#[tokio::test]
+async fn test_async_custom_format_extension_lookup() {
+    let mut registry = AsyncFormatRegistry::new();
+    registry.register_custom(bracket_format());
+
+    let kind = registry.kind_for_extension("abrk");
+    assert_eq!(kind, Some(FormatKind::Custom("async-bracket")));
+}
+
+#[tokio::test]
+async fn test_async_custom_format_serialize_deserialize() {
+    let registry = AsyncFormatRegistry::new().with_custom_format(bracket_format());
+
+    let data = TestData {
+        name: "test".to_string(),
+        value: 42,
+    };
+
+    let bytes = registry
+        .serialize_value_async(Some(&FormatKind::Custom("async-bracket")), &[], &data)
+        .await
+        .unwrap();
+
+    let output = String::from_utf8(bytes).unwrap();
+    assert!(output.starts_with('['));
+    assert!(output.ends_with(']'));
+
+    let result: TestData = registry
+        .deserialize_value_async(
+            Some(&FormatKind::Custom("async-bracket")),
+            &[],
+            output.as_bytes(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(result, data);
+}
+
+#[tokio::test]
+async fn async_custom_format_without_deserialize_errors() {
+    let fmt = AsyncCustomFormat::new("no-deser", &["and"]).with_serialize(|value| {
+        let value = value.clone();
+        Box::pin(
+            async move { serde_json::to_vec(&value).map_err(|e| FormatError::Serde(Box::new(e))) },
+        )
+    });
+
+    let err = fmt
+        .deserialize::<serde_json::Value>(b"{}")
+        .await
+        .expect_err("expected error when deserializing without handler");
+
+    match err {
+        FormatError::Other(inner) => {
+            let msg = inner.to_string();
+            assert!(
+                msg.contains("does not support deserialization"),
+                "unexpected message: {}",
+                msg
+            );
+        }
+        other => panic!("expected FormatError::Other, got: {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn unregistered_async_custom_format_errors() {
+    let registry = AsyncFormatRegistry::new();
+
+    let err = registry
+        .deserialize_value_async::<TestData>(Some(&FormatKind::Custom("missing")), &[], b"{}")
+        .await
+        .expect_err("unregistered custom format should fail to resolve");
+
+    assert!(matches!(err, FormatError::UnknownFormat(_)));
+}
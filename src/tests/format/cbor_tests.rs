@@ -0,0 +1,88 @@
+#![cfg(feature = "cbor")]
+
+//! CBOR format roundtrip and streaming tests.
+
+use std::io::Cursor;
+
+use crate::format::{FormatKind, default_registry, deserialize, deserialize_cbor_stream, serialize};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+struct Row {
+    name: String,
+    value: i32,
+}
+
+#[test]
+fn cbor_roundtrip_struct() {
+    let row = Row {
+        name: "a".into(),
+        value: 1,
+    };
+
+    let bytes = serialize(FormatKind::Cbor, &row).expect("serialize cbor");
+    let decoded: Row = deserialize(FormatKind::Cbor, &bytes).expect("deserialize cbor");
+    assert_eq!(decoded, row);
+}
+
+#[test]
+fn cbor_roundtrip_array_of_objects() {
+    let rows = vec![
+        Row {
+            name: "a".into(),
+            value: 1,
+        },
+        Row {
+            name: "b".into(),
+            value: 2,
+        },
+    ];
+
+    let bytes = serialize(FormatKind::Cbor, &rows).expect("serialize cbor");
+    let decoded: Vec<Row> = deserialize(FormatKind::Cbor, &bytes).expect("deserialize cbor");
+    assert_eq!(decoded, rows);
+}
+
+#[test]
+fn cbor_stream_reads_concatenated_values() {
+    let rows = vec![
+        Row {
+            name: "one".into(),
+            value: 1,
+        },
+        Row {
+            name: "two".into(),
+            value: 2,
+        },
+    ];
+
+    let mut bytes = Vec::new();
+    for row in &rows {
+        ciborium::ser::into_writer(row, &mut bytes).expect("encode cbor value");
+    }
+
+    let iter = deserialize_cbor_stream::<Row, _>(Cursor::new(bytes));
+    let decoded: Vec<Row> = iter.collect::<Result<_, _>>().expect("rows should parse");
+    assert_eq!(decoded, rows);
+}
+
+#[test]
+fn cbor_stream_via_registry_yields_rows() {
+    let rows = vec![Row {
+        name: "x".into(),
+        value: 42,
+    }];
+
+    let mut bytes = Vec::new();
+    for row in &rows {
+        ciborium::ser::into_writer(row, &mut bytes).expect("encode cbor value");
+    }
+
+    let registry = default_registry();
+    let iter = registry
+        .stream_deserialize_into::<Row>(Some(&FormatKind::Cbor), &[], Box::new(Cursor::new(bytes)))
+        .expect("cbor streaming should be supported");
+
+    let decoded: Vec<Row> = iter.collect::<Result<_, _>>().expect("rows should parse");
+    assert_eq!(decoded, rows);
+}
@@ -2,7 +2,9 @@
 
 //! CSV format roundtrip tests.
 
-use crate::format::{FormatKind, deserialize, serialize};
+use std::io::Cursor;
+
+use crate::format::{CsvOptions, FormatKind, FormatRegistry, deserialize, deserialize_csv_stream, serialize};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
@@ -55,3 +57,179 @@ fn csv_errors_on_non_object() {
     let res = serialize(FormatKind::Csv, &data);
     assert!(res.is_err());
 }
+
+#[test]
+fn csv_stream_yields_rows_one_at_a_time() {
+    let input = "name,value\na,1\nb,2\n";
+    let iter = deserialize_csv_stream::<CsvRow, _>(Cursor::new(input.as_bytes()));
+
+    let rows: Vec<CsvRow> = iter.collect::<Result<_, _>>().expect("rows should parse");
+
+    assert_eq!(
+        rows,
+        vec![
+            CsvRow { name: "a".into(), value: 1 },
+            CsvRow { name: "b".into(), value: 2 },
+        ]
+    );
+}
+
+#[test]
+fn csv_stream_via_registry_yields_rows() {
+    use crate::format::default_registry;
+
+    let input = "name,value\na,1\nb,2\n";
+    let registry = default_registry();
+
+    let iter = registry
+        .stream_deserialize_into::<CsvRow>(
+            Some(&FormatKind::Csv),
+            &[],
+            Box::new(Cursor::new(input.as_bytes())),
+        )
+        .expect("csv streaming should be supported");
+
+    let rows: Vec<CsvRow> = iter.collect::<Result<_, _>>().expect("rows should parse");
+    assert_eq!(rows.len(), 2);
+}
+
+#[test]
+fn csv_options_tsv_dialect_round_trips() {
+    let mut registry = FormatRegistry::new();
+    registry.register_custom(
+        CsvOptions::new()
+            .with_delimiter(b'\t')
+            .into_custom_format("tsv", &["tsv"]),
+    );
+
+    let rows = vec![
+        CsvRow { name: "a".into(), value: 1 },
+        CsvRow { name: "b".into(), value: 2 },
+    ];
+
+    let kind = FormatKind::Custom("tsv");
+    let bytes = registry
+        .serialize_value(Some(&kind), &[], &rows)
+        .expect("serialize tsv");
+    assert!(bytes.iter().any(|&b| b == b'\t'));
+    assert!(!bytes.contains(&b','));
+
+    let decoded: Vec<CsvRow> = registry
+        .deserialize_value(Some(&kind), &[], &bytes)
+        .expect("deserialize tsv");
+    assert_eq!(decoded, rows);
+}
+
+#[test]
+fn csv_options_headerless_synthesizes_column_names() {
+    let mut registry = FormatRegistry::new();
+    registry.register_custom(
+        CsvOptions::new()
+            .with_headers(false)
+            .into_custom_format("headerless-csv", &[]),
+    );
+
+    let kind = FormatKind::Custom("headerless-csv");
+    let value: serde_json::Value = registry
+        .deserialize_value(Some(&kind), &[], b"a,1\nb,2\n")
+        .expect("deserialize headerless csv");
+
+    let expected = serde_json::json!([
+        {"col0": "a", "col1": 1},
+        {"col0": "b", "col1": 2},
+    ]);
+    assert_eq!(value, expected);
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+struct TypedRow {
+    name: String,
+    value: i32,
+    flag: bool,
+}
+
+#[test]
+fn csv_roundtrip_infers_numeric_and_bool_scalars() {
+    let rows = vec![
+        TypedRow { name: "a".into(), value: 1, flag: true },
+        TypedRow { name: "b".into(), value: 2, flag: false },
+    ];
+
+    let bytes = serialize(FormatKind::Csv, &rows).expect("serialize csv");
+    let decoded: Vec<TypedRow> = deserialize(FormatKind::Csv, &bytes).expect("deserialize csv");
+    assert_eq!(decoded, rows);
+
+    let value: serde_json::Value = deserialize(FormatKind::Csv, &bytes).expect("deserialize csv as value");
+    assert_eq!(
+        value,
+        serde_json::json!([
+            {"name": "a", "value": 1, "flag": true},
+            {"name": "b", "value": 2, "flag": false},
+        ])
+    );
+}
+
+#[test]
+fn csv_deserialize_keeps_quoted_numeric_field_as_string() {
+    let input = "name,value\n\"42\",7\nplain,9\n";
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct QuotedRow {
+        name: serde_json::Value,
+        value: i32,
+    }
+
+    let decoded: Vec<QuotedRow> = deserialize(FormatKind::Csv, input.as_bytes()).expect("deserialize csv");
+    assert_eq!(decoded[0].name, serde_json::Value::String("42".into()));
+    assert_eq!(decoded[1].name, serde_json::Value::String("plain".into()));
+}
+
+#[test]
+fn csv_quoted_field_with_embedded_newline_does_not_corrupt_later_rows() {
+    // The first record's quoted `name` field embeds a literal newline, so it
+    // spans two physical lines but is still one `csv::Reader` record. That
+    // used to desync the physical-line-indexed quote scan from the real
+    // record index, misattributing quote info to every row after it. The
+    // override now disables itself for the whole document on a length
+    // mismatch instead, so `value` (never quoted here) still infers as a
+    // plain number rather than picking up some other row's quote status.
+    let input = "name,value\n\"multi\nline\",1\nplain,9\n";
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct QuotedRow {
+        name: String,
+        value: i32,
+    }
+
+    let decoded: Vec<QuotedRow> = deserialize(FormatKind::Csv, input.as_bytes()).expect("deserialize csv");
+    assert_eq!(
+        decoded,
+        vec![
+            QuotedRow { name: "multi\nline".into(), value: 1 },
+            QuotedRow { name: "plain".into(), value: 9 },
+        ]
+    );
+}
+
+#[test]
+fn csv_options_headerless_uses_explicit_header_names() {
+    let mut registry = FormatRegistry::new();
+    registry.register_custom(
+        CsvOptions::new()
+            .with_header_names(vec!["name".into(), "value".into()])
+            .into_custom_format("named-headerless-csv", &[]),
+    );
+
+    let kind = FormatKind::Custom("named-headerless-csv");
+    let rows: Vec<CsvRow> = registry
+        .deserialize_value(Some(&kind), &[], b"a,1\nb,2\n")
+        .expect("deserialize named headerless csv");
+
+    assert_eq!(
+        rows,
+        vec![
+            CsvRow { name: "a".into(), value: 1 },
+            CsvRow { name: "b".into(), value: 2 },
+        ]
+    );
+}
@@ -142,3 +142,23 @@ fn custom_format_without_serialize_errors() {
         other => panic!("expected FormatError::Other, got: {other:?}"),
     }
 }
+
+#[test]
+fn custom_format_with_sniff_participates_in_detection() {
+    let mut registry = FormatRegistry::new();
+
+    // Wraps JSON in brackets, same shape as the bracket_format above; the
+    // sniff signal is "starts with '['" so it outranks a bare parse.
+    let bracket_format = CustomFormat::new("bracket", &["brk"])
+        .with_deserialize(|bytes| {
+            let s = String::from_utf8_lossy(bytes);
+            let inner = s.trim_start_matches('[').trim_end_matches(']');
+            serde_json::from_str(inner).map_err(|e| FormatError::Serde(Box::new(e)))
+        })
+        .with_sniff(|bytes| bytes.starts_with(b"["));
+
+    registry.register_custom(bracket_format);
+
+    let detected = registry.detect(b"[{\"name\":\"test\",\"value\":42}]");
+    assert_eq!(detected, Some(FormatKind::Custom("bracket")));
+}
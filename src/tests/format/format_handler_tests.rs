@@ -0,0 +1,38 @@
+//! Tests for overriding a format's behavior via `Format` trait objects.
+
+use std::io::{Cursor, Read};
+use std::sync::Arc;
+
+use crate::format::{Format, FormatError, FormatKind, FormatRegistry};
+
+/// A handler that always reports the same fixed document, regardless of
+/// what bytes it is asked to decode.
+struct FixedHandler;
+
+impl Format for FixedHandler {
+    fn serialize(&self, _value: &dyn erased_serde::Serialize) -> Result<Vec<u8>, FormatError> {
+        Ok(b"fixed".to_vec())
+    }
+
+    fn deserialize(&self, _bytes: &[u8]) -> Result<serde_json::Value, FormatError> {
+        Ok(serde_json::json!({"fixed": true}))
+    }
+}
+
+#[test]
+fn register_handler_overrides_builtin_format() {
+    let mut registry = FormatRegistry::new();
+    registry.register(FormatKind::Json);
+    registry.register_handler(FormatKind::Json, Arc::new(FixedHandler));
+
+    let reader: Box<dyn Read> = Box::new(Cursor::new(b"{\"real\": 1}".to_vec()));
+    let mut values = registry
+        .stream_deserialize_into::<serde_json::Value>(Some(&FormatKind::Json), &[], reader)
+        .expect("stream_deserialize_into should succeed");
+
+    let value = values
+        .next()
+        .expect("one value")
+        .expect("value should deserialize");
+    assert_eq!(value, serde_json::json!({"fixed": true}));
+}
@@ -0,0 +1,38 @@
+use crate::format::{FormatKind, deserialize, serialize};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+struct Json5Data {
+    name: String,
+    value: i32,
+    flag: bool,
+}
+
+#[test]
+fn json5_roundtrip_simple_struct() {
+    let data = Json5Data {
+        name: "json5".into(),
+        value: 7,
+        flag: true,
+    };
+
+    let bytes = serialize(FormatKind::Json5, &data).expect("serialize json5");
+    assert!(!bytes.is_empty());
+
+    let decoded: Json5Data = deserialize(FormatKind::Json5, &bytes).expect("deserialize json5");
+    assert_eq!(decoded, data);
+}
+
+#[test]
+fn json5_deserializes_relaxed_syntax() {
+    let relaxed = b"{ name: 'json5', value: 7, flag: true, }";
+    let decoded: Json5Data = deserialize(FormatKind::Json5, relaxed).expect("deserialize json5");
+    assert_eq!(
+        decoded,
+        Json5Data {
+            name: "json5".into(),
+            value: 7,
+            flag: true,
+        }
+    );
+}
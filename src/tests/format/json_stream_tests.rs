@@ -0,0 +1,63 @@
+//! Tests for `deserialize_json_stream`'s top-level-array fast path.
+
+use std::io::Cursor;
+
+use crate::format::deserialize_json_stream;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+struct Row {
+    name: String,
+    value: i32,
+}
+
+#[test]
+fn array_of_objects_streams_each_element() {
+    let input = br#"[{"name":"a","value":1},{"name":"b","value":2}]"#;
+    let rows: Vec<Row> = deserialize_json_stream(Cursor::new(input.as_slice()))
+        .collect::<Result<_, _>>()
+        .expect("rows should parse");
+
+    assert_eq!(
+        rows,
+        vec![
+            Row { name: "a".into(), value: 1 },
+            Row { name: "b".into(), value: 2 },
+        ]
+    );
+}
+
+#[test]
+fn lone_object_is_treated_as_one_element_stream() {
+    let input = br#"{"name":"solo","value":7}"#;
+    let rows: Vec<Row> = deserialize_json_stream(Cursor::new(input.as_slice()))
+        .collect::<Result<_, _>>()
+        .expect("row should parse");
+
+    assert_eq!(rows, vec![Row { name: "solo".into(), value: 7 }]);
+}
+
+#[test]
+fn whitespace_separated_documents_still_stream() {
+    let input = b"{\"name\":\"a\",\"value\":1}\n{\"name\":\"b\",\"value\":2}\n";
+    let rows: Vec<Row> = deserialize_json_stream(Cursor::new(input.as_slice()))
+        .collect::<Result<_, _>>()
+        .expect("rows should parse");
+
+    assert_eq!(
+        rows,
+        vec![
+            Row { name: "a".into(), value: 1 },
+            Row { name: "b".into(), value: 2 },
+        ]
+    );
+}
+
+#[test]
+fn malformed_element_inside_array_surfaces_as_error() {
+    let input = br#"[{"name":"a","value":1},{"name":"b","value":"not a number"}]"#;
+    let result: Result<Vec<Row>, _> =
+        deserialize_json_stream(Cursor::new(input.as_slice())).collect();
+
+    assert!(result.is_err());
+}
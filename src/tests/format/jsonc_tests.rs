@@ -0,0 +1,69 @@
+use crate::format::{FormatKind, deserialize_jsonc, serialize, strip_jsonc_comments};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+struct JsoncData {
+    name: String,
+    value: i32,
+    tags: Vec<String>,
+}
+
+#[test]
+fn strip_jsonc_comments_removes_line_and_block_comments() {
+    let input = "{\n  // a line comment\n  \"a\": 1, /* inline block */\n  \"b\": 2\n}";
+    let cleaned = strip_jsonc_comments(input);
+    let value: serde_json::Value = serde_json::from_str(&cleaned).unwrap();
+    assert_eq!(value["a"], 1);
+    assert_eq!(value["b"], 2);
+}
+
+#[test]
+fn strip_jsonc_comments_removes_trailing_commas() {
+    let input = r#"{"a": [1, 2, 3,], "b": 4,}"#;
+    let cleaned = strip_jsonc_comments(input);
+    let value: serde_json::Value = serde_json::from_str(&cleaned).unwrap();
+    assert_eq!(value["a"], serde_json::json!([1, 2, 3]));
+    assert_eq!(value["b"], 4);
+}
+
+#[test]
+fn strip_jsonc_comments_preserves_slashes_and_commas_in_strings() {
+    let input = r#"{"url": "http://example.com", "note": "a, b"}"#;
+    let cleaned = strip_jsonc_comments(input);
+    let value: serde_json::Value = serde_json::from_str(&cleaned).unwrap();
+    assert_eq!(value["url"], "http://example.com");
+    assert_eq!(value["note"], "a, b");
+}
+
+#[test]
+fn deserialize_jsonc_roundtrips_a_struct() {
+    let input = br#"{
+        // name of the thing
+        "name": "widget",
+        "value": 7, // trailing comment
+        "tags": ["a", "b",], /* block comment */
+    }"#;
+
+    let decoded: JsoncData = deserialize_jsonc(input).expect("deserialize jsonc");
+    assert_eq!(
+        decoded,
+        JsoncData {
+            name: "widget".into(),
+            value: 7,
+            tags: vec!["a".into(), "b".into()],
+        }
+    );
+}
+
+#[test]
+fn strict_json_deserialize_is_unaffected_by_jsonc_helper() {
+    let data = JsoncData {
+        name: "plain".into(),
+        value: 1,
+        tags: vec![],
+    };
+    let bytes = serialize(FormatKind::Json, &data).expect("serialize json");
+    let decoded: JsoncData =
+        crate::format::deserialize(FormatKind::Json, &bytes).expect("deserialize json");
+    assert_eq!(decoded, data);
+}
@@ -0,0 +1,64 @@
+//! Tests for `FormatError::MalformedPayload`: format + classification +
+//! bounded context snippet on payloads that don't parse or don't match the
+//! shape an operation expected.
+
+use crate::format::{FormatError, FormatKind, PayloadErrorKind};
+
+#[cfg(feature = "csv")]
+#[test]
+fn csv_non_object_serialize_is_data_shape_error() {
+    let data = vec![1, 2, 3];
+    let err = crate::format::serialize(FormatKind::Csv, &data).unwrap_err();
+
+    let FormatError::MalformedPayload { kind, classification, .. } = err else {
+        panic!("expected a malformed payload error, got {err:?}");
+    };
+    assert_eq!(kind, FormatKind::Csv);
+    assert_eq!(classification, PayloadErrorKind::DataShape);
+}
+
+#[cfg(feature = "csv")]
+#[test]
+fn csv_bad_syntax_is_syntax_error_with_context() {
+    let bad = b"name,value\n\"unterminated,1\n";
+    let err = crate::format::deserialize::<serde_json::Value>(FormatKind::Csv, bad).unwrap_err();
+
+    let FormatError::MalformedPayload { kind, classification, context, .. } = err else {
+        panic!("expected a malformed payload error, got {err:?}");
+    };
+    assert_eq!(kind, FormatKind::Csv);
+    assert_eq!(classification, PayloadErrorKind::Syntax);
+    assert!(!context.is_empty());
+}
+
+#[cfg(feature = "yaml")]
+#[test]
+fn yaml_shape_mismatch_without_location_is_data_shape_error() {
+    #[derive(Debug, serde::Deserialize)]
+    #[allow(dead_code)]
+    struct Data {
+        name: String,
+    }
+
+    // A scalar document has no location for serde_yaml to point at, but it
+    // still fails to deserialize into `Data`.
+    let bad = b"just a scalar";
+    let err = crate::format::deserialize::<Data>(FormatKind::Yaml, bad).unwrap_err();
+
+    let FormatError::MalformedPayload { kind, classification, .. } = err else {
+        panic!("expected a malformed payload error, got {err:?}");
+    };
+    assert_eq!(kind, FormatKind::Yaml);
+    assert_eq!(classification, PayloadErrorKind::DataShape);
+}
+
+#[test]
+fn truncate_context_elides_long_payloads_but_keeps_short_ones_whole() {
+    let short = b"{\"a\":1}";
+    assert_eq!(crate::format::truncate_context(short), "{\"a\":1}");
+
+    let long = vec![b'x'; 1000];
+    let truncated = crate::format::truncate_context(&long);
+    assert!(truncated.contains("..."));
+    assert!(truncated.len() < long.len());
+}
@@ -1,4 +1,4 @@
-use crate::format::{FormatKind, deserialize, serialize};
+use crate::format::{FormatKind, OutputOptions, deserialize, serialize, serialize_with_options};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
@@ -7,6 +7,13 @@ struct MdData {
     value: i32,
 }
 
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+struct MdNested {
+    title: String,
+    count: i32,
+    tags: Vec<String>,
+}
+
 #[test]
 fn markdown_roundtrip_json_code_block() {
     let data = MdData {
@@ -41,3 +48,62 @@ Some text.
     assert_eq!(decoded.name, "x");
     assert_eq!(decoded.value, 42);
 }
+
+#[test]
+fn markdown_merges_frontmatter_and_json_block() {
+    let md = r#"---
+title: hello
+---
+
+```json
+{"count": 3, "tags": ["a", "b"]}
+```
+"#;
+
+    let decoded: MdNested =
+        deserialize(FormatKind::Markdown, md.as_bytes()).expect("deserialize markdown");
+    assert_eq!(decoded.title, "hello");
+    assert_eq!(decoded.count, 3);
+    assert_eq!(decoded.tags, vec!["a".to_string(), "b".to_string()]);
+}
+
+#[test]
+fn markdown_merges_multiple_json_blocks_later_overrides_earlier() {
+    let md = r#"
+```json
+{"title": "first", "count": 1, "tags": []}
+```
+
+```json
+{"count": 2, "tags": ["x"]}
+```
+"#;
+
+    let decoded: MdNested =
+        deserialize(FormatKind::Markdown, md.as_bytes()).expect("deserialize markdown");
+    assert_eq!(decoded.title, "first");
+    assert_eq!(decoded.count, 2);
+    assert_eq!(decoded.tags, vec!["x".to_string()]);
+}
+
+#[test]
+fn markdown_structured_serialize_splits_frontmatter_and_payload() {
+    let data = MdNested {
+        title: "hello".into(),
+        count: 3,
+        tags: vec!["a".into(), "b".into()],
+    };
+
+    let options = OutputOptions::default().with_markdown_frontmatter(true);
+    let bytes = serialize_with_options(FormatKind::Markdown, &data, &options)
+        .expect("serialize markdown with frontmatter");
+    let s = String::from_utf8(bytes.clone()).expect("utf8 markdown");
+
+    assert!(s.starts_with("---\n"));
+    assert!(s.contains("title: hello"));
+    assert!(s.contains("```json"));
+
+    let decoded: MdNested =
+        deserialize(FormatKind::Markdown, &bytes).expect("deserialize markdown");
+    assert_eq!(decoded, data);
+}
@@ -1,21 +1,56 @@
 //! Format module tests.
 
+#[cfg(feature = "async")]
+mod async_custom_tests;
+#[cfg(feature = "async")]
+mod async_stream_tests;
+
 #[cfg(feature = "json")]
 mod custom_stream_tests;
 #[cfg(feature = "json")]
 mod custom_tests;
 #[cfg(feature = "json")]
+mod format_handler_tests;
+#[cfg(feature = "json")]
+mod json_stream_tests;
+#[cfg(feature = "json")]
+mod jsonc_tests;
+#[cfg(feature = "json")]
+mod options_tests;
+#[cfg(feature = "json")]
 mod registry_tests;
 
+#[cfg(all(feature = "preserve_order", feature = "json"))]
+mod ordered_value_tests;
+
+#[cfg(feature = "cbor")]
+mod cbor_tests;
 #[cfg(feature = "csv")]
 mod csv_tests;
 #[cfg(feature = "ini")]
 mod ini_tests;
+#[cfg(feature = "json5")]
+mod json5_tests;
+#[cfg(feature = "ndjson")]
+mod ndjson_tests;
 #[cfg(feature = "plaintext")]
 mod plaintext_stream_tests;
+#[cfg(feature = "preserves")]
+mod preserves_tests;
+#[cfg(feature = "ron")]
+mod ron_tests;
 #[cfg(feature = "toml")]
 mod toml_tests;
 #[cfg(feature = "xml")]
 mod xml_tests;
 #[cfg(feature = "yaml")]
 mod yaml_tests;
+
+#[cfg(any(feature = "json", feature = "yaml", feature = "toml"))]
+mod span_tests;
+
+#[cfg(any(feature = "csv", feature = "yaml"))]
+mod malformed_payload_tests;
+
+#[cfg(any(feature = "json", feature = "yaml", feature = "csv"))]
+mod serde_at_tests;
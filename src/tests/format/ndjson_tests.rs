@@ -0,0 +1,117 @@
+#![cfg(feature = "ndjson")]
+
+//! NDJSON format roundtrip and streaming tests.
+
+use std::io::Cursor;
+
+use crate::format::{FormatKind, default_registry, deserialize, deserialize_ndjson_stream, serialize};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+struct Row {
+    name: String,
+    value: i32,
+}
+
+#[test]
+fn ndjson_roundtrip_array_of_objects() {
+    let rows = vec![
+        Row {
+            name: "a".into(),
+            value: 1,
+        },
+        Row {
+            name: "b".into(),
+            value: 2,
+        },
+    ];
+
+    let bytes = serialize(FormatKind::Ndjson, &rows).expect("serialize ndjson");
+    assert_eq!(bytes.iter().filter(|&&b| b == b'\n').count(), 2);
+
+    let decoded: Vec<Row> = deserialize(FormatKind::Ndjson, &bytes).expect("deserialize ndjson");
+    assert_eq!(decoded, rows);
+}
+
+#[test]
+fn ndjson_stream_skips_blank_lines() {
+    let input = "{\"name\":\"a\",\"value\":1}\n\n{\"name\":\"b\",\"value\":2}\n";
+    let iter = deserialize_ndjson_stream::<Row, _>(Cursor::new(input.as_bytes()));
+
+    let rows: Vec<Row> = iter.collect::<Result<_, _>>().expect("rows should parse");
+
+    assert_eq!(
+        rows,
+        vec![
+            Row {
+                name: "a".into(),
+                value: 1
+            },
+            Row {
+                name: "b".into(),
+                value: 2
+            },
+        ]
+    );
+}
+
+#[test]
+fn ndjson_stream_reports_line_number_on_bad_row() {
+    let input = "{\"name\":\"a\",\"value\":1}\nnot json\n";
+    let iter = deserialize_ndjson_stream::<Row, _>(Cursor::new(input.as_bytes()));
+
+    let results: Vec<_> = iter.collect();
+    assert!(results[0].is_ok());
+    let err = results[1].as_ref().expect_err("second line should fail");
+    assert!(err.to_string().contains("line 2"));
+}
+
+#[test]
+fn ndjson_stream_continues_past_a_bad_line() {
+    // A malformed line should surface as an `Err` for that record without
+    // aborting the rest of the stream.
+    let input = "{\"name\":\"a\",\"value\":1}\nnot json\n{\"name\":\"b\",\"value\":2}\n";
+    let iter = deserialize_ndjson_stream::<Row, _>(Cursor::new(input.as_bytes()));
+
+    let results: Vec<_> = iter.collect();
+    assert_eq!(results.len(), 3);
+    assert!(results[0].is_ok());
+    assert!(results[1].is_err());
+    assert_eq!(
+        results[2].as_ref().expect("third line should still parse"),
+        &Row {
+            name: "b".into(),
+            value: 2
+        }
+    );
+}
+
+#[test]
+fn ndjson_stream_via_registry_yields_rows() {
+    let input = "{\"name\":\"one\",\"value\":1}\n{\"name\":\"two\",\"value\":2}\n";
+    let registry = default_registry();
+
+    let iter = registry
+        .stream_deserialize_into::<Row>(
+            Some(&FormatKind::Ndjson),
+            &[],
+            Box::new(Cursor::new(input.as_bytes())),
+        )
+        .expect("ndjson streaming should be supported");
+
+    let rows: Vec<Row> = iter.collect::<Result<_, _>>().expect("rows should parse");
+
+    assert_eq!(
+        rows,
+        vec![
+            Row {
+                name: "one".into(),
+                value: 1
+            },
+            Row {
+                name: "two".into(),
+                value: 2
+            },
+        ]
+    );
+}
@@ -0,0 +1,122 @@
+//! Tests for `OutputOptions`-aware serialization (`serialize_with_options`).
+
+use crate::format::{FormatKind, KeyOrder, OutputOptions, OutputStyle, serialize_with_options};
+use serde_json::json;
+
+#[test]
+fn json_pretty_uses_requested_indent() {
+    let value = json!({"b": 1, "a": 2});
+    let options = OutputOptions::pretty().with_indent("    ");
+
+    let bytes = serialize_with_options(FormatKind::Json, &value, &options).unwrap();
+    let text = String::from_utf8(bytes).unwrap();
+
+    assert!(text.contains('\n'));
+    assert!(text.contains("    \""));
+}
+
+#[test]
+fn json_compact_has_no_newlines() {
+    let value = json!({"a": 1, "b": 2});
+    let options = OutputOptions::compact();
+
+    let bytes = serialize_with_options(FormatKind::Json, &value, &options).unwrap();
+    let text = String::from_utf8(bytes).unwrap();
+
+    assert!(!text.contains('\n'));
+}
+
+#[test]
+fn json_sorted_key_order_is_alphabetical() {
+    let value = json!({"zebra": 1, "apple": 2, "mango": 3});
+    let options = OutputOptions::compact().with_key_order(KeyOrder::Sorted);
+
+    let bytes = serialize_with_options(FormatKind::Json, &value, &options).unwrap();
+    let text = String::from_utf8(bytes).unwrap();
+
+    let apple_pos = text.find("apple").unwrap();
+    let mango_pos = text.find("mango").unwrap();
+    let zebra_pos = text.find("zebra").unwrap();
+    assert!(apple_pos < mango_pos && mango_pos < zebra_pos);
+}
+
+#[cfg(feature = "yaml")]
+#[test]
+fn yaml_sorted_key_order_is_alphabetical() {
+    let value = json!({"zebra": 1, "apple": 2});
+    let options = OutputOptions::compact().with_key_order(KeyOrder::Sorted);
+
+    let bytes = serialize_with_options(FormatKind::Yaml, &value, &options).unwrap();
+    let text = String::from_utf8(bytes).unwrap();
+
+    assert!(text.find("apple").unwrap() < text.find("zebra").unwrap());
+}
+
+#[cfg(feature = "toml")]
+#[test]
+fn toml_pretty_style_produces_different_output_than_compact() {
+    let value = json!({"a": 1, "b": {"c": 2}});
+    let compact = serialize_with_options(FormatKind::Toml, &value, &OutputOptions::compact()).unwrap();
+    let pretty = serialize_with_options(FormatKind::Toml, &value, &OutputOptions::pretty()).unwrap();
+
+    assert_ne!(compact, pretty);
+}
+
+#[cfg(feature = "csv")]
+#[test]
+fn csv_serialize_with_options_ignores_style() {
+    #[derive(serde::Serialize)]
+    struct Row {
+        a: i32,
+        b: i32,
+    }
+    let rows = vec![Row { a: 1, b: 2 }];
+
+    let plain = crate::format::serialize(FormatKind::Csv, &rows).unwrap();
+    let with_options =
+        serialize_with_options(FormatKind::Csv, &rows, &OutputOptions::pretty()).unwrap();
+
+    assert_eq!(plain, with_options);
+}
+
+#[cfg(feature = "csv")]
+#[test]
+fn csv_serialize_with_options_honors_delimiter_quote_and_header() {
+    #[derive(serde::Serialize)]
+    struct Row {
+        a: i32,
+        b: i32,
+    }
+    let rows = vec![Row { a: 1, b: 2 }];
+
+    let tab_separated = serialize_with_options(
+        FormatKind::Csv,
+        &rows,
+        &OutputOptions::compact().with_csv_delimiter(b'\t'),
+    )
+    .unwrap();
+    let text = String::from_utf8(tab_separated).unwrap();
+    assert!(text.contains('\t'));
+    assert!(!text.contains(','));
+
+    let no_header = serialize_with_options(
+        FormatKind::Csv,
+        &rows,
+        &OutputOptions::compact().with_csv_header(false),
+    )
+    .unwrap();
+    let text = String::from_utf8(no_header).unwrap();
+    assert!(!text.contains('a'));
+    assert!(text.contains("1,2"));
+}
+
+#[test]
+fn output_style_and_key_order_from_str() {
+    assert_eq!(OutputStyle::from_str("pretty"), Some(OutputStyle::Pretty));
+    assert_eq!(OutputStyle::from_str("compact"), Some(OutputStyle::Compact));
+    assert_eq!(OutputStyle::from_str("bogus"), None);
+
+    assert_eq!(KeyOrder::from_str("sorted"), Some(KeyOrder::Sorted));
+    assert_eq!(KeyOrder::from_str("insertion"), Some(KeyOrder::Insertion));
+    assert_eq!(KeyOrder::from_str("bogus"), None);
+}
@@ -0,0 +1,43 @@
+//! Tests for order-preserving document round-trips.
+
+use crate::format::{FormatKind, FormatRegistry};
+use serde_json::json;
+
+#[test]
+fn preserve_order_keeps_field_order_through_round_trip() {
+    let mut registry = FormatRegistry::new().with_preserve_order(true);
+    registry.register(FormatKind::Json);
+
+    let bytes = br#"{"zebra": 1, "apple": 2, "mango": 3}"#;
+    let doc = registry
+        .deserialize_document(Some(&FormatKind::Json), &[], bytes)
+        .expect("deserialize document");
+
+    let out = registry
+        .serialize_document(Some(&FormatKind::Json), &[], &doc)
+        .expect("serialize document");
+    let text = String::from_utf8(out).unwrap();
+
+    let zebra_pos = text.find("zebra").unwrap();
+    let apple_pos = text.find("apple").unwrap();
+    let mango_pos = text.find("mango").unwrap();
+    assert!(zebra_pos < apple_pos && apple_pos < mango_pos);
+}
+
+#[test]
+fn without_preserve_order_still_round_trips_values() {
+    let mut registry = FormatRegistry::new();
+    registry.register(FormatKind::Json);
+    assert!(!registry.preserve_order());
+
+    let bytes = br#"{"a": 1, "b": [true, null, "x"]}"#;
+    let doc = registry
+        .deserialize_document(Some(&FormatKind::Json), &[], bytes)
+        .expect("deserialize document");
+
+    let out = registry
+        .serialize_document(Some(&FormatKind::Json), &[], &doc)
+        .expect("serialize document");
+    let roundtripped: serde_json::Value = serde_json::from_slice(&out).unwrap();
+    assert_eq!(roundtripped, json!({"a": 1, "b": [true, null, "x"]}));
+}
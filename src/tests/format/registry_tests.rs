@@ -73,6 +73,193 @@ fn resolve_uses_first_registered_candidate() {
     assert_eq!(kind, FormatKind::Json);
 }
 
+#[cfg(all(feature = "json", feature = "yaml"))]
+#[test]
+fn detect_format_ranks_signal_match_above_bare_parse() {
+    let mut registry = FormatRegistry::new();
+    registry.register(FormatKind::Json);
+    registry.register(FormatKind::Yaml);
+
+    // A bare JSON object: carries JSON's leading `{` tell, and also happens
+    // to parse as a single-scalar-less YAML mapping.
+    let ranked = registry.detect_format(br#"{"a": 1}"#);
+    assert_eq!(ranked.first().map(|(k, _)| *k), Some(FormatKind::Json));
+}
+
+#[cfg(feature = "yaml")]
+#[test]
+fn detect_format_favors_yaml_key_colon_signal() {
+    let mut registry = FormatRegistry::new();
+    registry.register(FormatKind::Yaml);
+
+    let ranked = registry.detect_format(b"name: alice\nage: 30\n");
+    assert_eq!(ranked.first().map(|(k, _)| *k), Some(FormatKind::Yaml));
+}
+
+#[cfg(all(feature = "json", feature = "yaml"))]
+#[test]
+fn resolve_with_sniffing_prefers_detected_format_over_candidate_order() {
+    let mut registry = FormatRegistry::new();
+    registry.register(FormatKind::Yaml);
+    registry.register(FormatKind::Json);
+
+    // Candidate order puts Yaml first, but the bytes are unambiguously JSON.
+    let kind = registry
+        .resolve_with_sniffing(
+            None,
+            &[FormatKind::Yaml, FormatKind::Json],
+            br#"{"a": 1}"#,
+        )
+        .expect("should resolve");
+
+    assert_eq!(kind, FormatKind::Json);
+}
+
+#[cfg(feature = "csv")]
+#[test]
+fn resolve_with_sniffing_falls_back_for_undetected_formats() {
+    let mut registry = FormatRegistry::new();
+    registry.register(FormatKind::Csv);
+
+    let kind = registry
+        .resolve_with_sniffing(None, &[FormatKind::Csv], b"name,value\na,1\n")
+        .expect("should fall back to ordinary resolve");
+
+    assert_eq!(kind, FormatKind::Csv);
+}
+
+#[cfg(all(feature = "json", feature = "yaml"))]
+#[test]
+fn detect_returns_the_single_most_confident_format() {
+    let mut registry = FormatRegistry::new();
+    registry.register(FormatKind::Json);
+    registry.register(FormatKind::Yaml);
+
+    assert_eq!(
+        registry.detect(br#"{"a": 1}"#),
+        Some(FormatKind::Json)
+    );
+    assert_eq!(
+        registry.detect(b"name: alice\nage: 30\n"),
+        Some(FormatKind::Yaml)
+    );
+}
+
+#[test]
+fn detect_returns_none_when_nothing_parses() {
+    let registry = FormatRegistry::new();
+    assert_eq!(registry.detect(b"not structured data at all"), None);
+}
+
+#[cfg(all(feature = "json", feature = "csv"))]
+#[test]
+fn deserialize_value_falls_back_to_trial_parsing_with_no_candidates() {
+    let mut registry = FormatRegistry::new();
+    registry.register(FormatKind::Json);
+    registry.register(FormatKind::Csv);
+
+    // CSV has no generic value type for `detect_format` to probe, so
+    // `resolve_with_sniffing` finds nothing; `deserialize_value` should still
+    // land on Csv by actually trying to parse the requested type.
+    #[derive(Debug, serde::Deserialize, PartialEq)]
+    struct Row {
+        name: String,
+        value: i32,
+    }
+
+    let rows: Vec<Row> = registry
+        .deserialize_value(None, &[], b"name,value\na,1\nb,2\n")
+        .expect("should fall back to trial parsing");
+
+    assert_eq!(
+        rows,
+        vec![
+            Row { name: "a".into(), value: 1 },
+            Row { name: "b".into(), value: 2 },
+        ]
+    );
+}
+
+#[test]
+fn deserialize_value_trial_fallback_returns_no_format_matched() {
+    let registry = FormatRegistry::new();
+
+    let err = registry
+        .deserialize_value::<serde_json::Value>(None, &[], b"not structured data at all")
+        .expect_err("expected NoFormatMatched when nothing registered parses");
+
+    assert!(matches!(err, FormatError::NoFormatMatched));
+}
+
+#[cfg(feature = "xml")]
+#[test]
+fn detect_format_recognizes_well_formed_xml() {
+    let mut registry = FormatRegistry::new();
+    registry.register(FormatKind::Xml);
+
+    let ranked = registry.detect_format(b"<?xml version=\"1.0\"?><root><a>1</a></root>");
+    assert_eq!(ranked.first().map(|(k, _)| *k), Some(FormatKind::Xml));
+}
+
+#[cfg(feature = "xml")]
+#[test]
+fn detect_format_rejects_malformed_xml() {
+    let mut registry = FormatRegistry::new();
+    registry.register(FormatKind::Xml);
+
+    let ranked = registry.detect_format(b"<root><a>1</a>");
+    assert!(ranked.is_empty());
+}
+
+#[cfg(feature = "cbor")]
+#[test]
+fn detect_format_recognizes_cbor_bytes() {
+    let mut registry = FormatRegistry::new();
+    registry.register(FormatKind::Cbor);
+
+    #[derive(serde::Serialize)]
+    struct Row {
+        name: String,
+        value: i32,
+    }
+
+    let mut bytes = Vec::new();
+    ciborium::ser::into_writer(
+        &Row {
+            name: "a".into(),
+            value: 1,
+        },
+        &mut bytes,
+    )
+    .expect("encode cbor value");
+
+    let ranked = registry.detect_format(&bytes);
+    assert_eq!(ranked.first().map(|(k, _)| *k), Some(FormatKind::Cbor));
+}
+
+#[cfg(all(feature = "xml", feature = "cbor"))]
+#[test]
+fn resolve_with_sniffing_prefers_cbor_over_declared_xml_candidate_order() {
+    let mut registry = FormatRegistry::new();
+    registry.register(FormatKind::Xml);
+    registry.register(FormatKind::Cbor);
+
+    #[derive(serde::Serialize)]
+    struct Row {
+        value: i32,
+    }
+
+    let mut bytes = Vec::new();
+    ciborium::ser::into_writer(&Row { value: 1 }, &mut bytes).expect("encode cbor value");
+
+    // Candidate order puts Xml first, but the bytes are unambiguously CBOR.
+    let kind = registry
+        .resolve_with_sniffing(None, &[FormatKind::Xml, FormatKind::Cbor], &bytes)
+        .expect("should resolve");
+
+    assert_eq!(kind, FormatKind::Cbor);
+}
+
 #[test]
 fn deserialize_value_with_missing_custom_format_returns_unknown() {
     let registry = FormatRegistry::new();
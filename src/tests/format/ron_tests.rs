@@ -0,0 +1,24 @@
+use crate::format::{FormatKind, deserialize, serialize};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+struct RonData {
+    name: String,
+    value: i32,
+    flag: bool,
+}
+
+#[test]
+fn ron_roundtrip_simple_struct() {
+    let data = RonData {
+        name: "ron".into(),
+        value: 7,
+        flag: true,
+    };
+
+    let bytes = serialize(FormatKind::Ron, &data).expect("serialize ron");
+    assert!(!bytes.is_empty());
+
+    let decoded: RonData = deserialize(FormatKind::Ron, &bytes).expect("deserialize ron");
+    assert_eq!(decoded, data);
+}
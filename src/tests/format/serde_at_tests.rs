@@ -0,0 +1,61 @@
+//! Tests that a type mismatch nested inside a struct/array field produces a
+//! `FormatError::SerdeAt` carrying the precise dotted/bracketed path to the
+//! offending spot, rather than just the underlying library's flat message.
+
+use crate::format::{FormatError, FormatKind};
+
+#[derive(Debug, serde::Deserialize)]
+#[allow(dead_code)]
+struct Record {
+    name: String,
+    value: i32,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[allow(dead_code)]
+struct Document {
+    records: Vec<Record>,
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn json_nested_type_mismatch_carries_field_path() {
+    let bad = br#"{"records": [{"name": "a", "value": 1}, {"name": "x", "value": "not a number"}]}"#;
+    let err = crate::format::deserialize::<Document>(FormatKind::Json, bad).unwrap_err();
+
+    let FormatError::SerdeAt { path, .. } = err else {
+        panic!("expected a path-tracked serde error, got {err:?}");
+    };
+    assert_eq!(path, "records[1].value");
+}
+
+#[cfg(feature = "yaml")]
+#[test]
+fn yaml_nested_type_mismatch_carries_field_path() {
+    let bad = "records:\n  - name: a\n    value: 1\n  - name: x\n    value: not a number\n";
+    let err = crate::format::deserialize::<Document>(FormatKind::Yaml, bad.as_bytes()).unwrap_err();
+
+    let FormatError::SerdeAt { path, .. } = err else {
+        panic!("expected a path-tracked serde error, got {err:?}");
+    };
+    assert_eq!(path, "records[1].value");
+}
+
+#[cfg(feature = "csv")]
+#[test]
+fn csv_row_type_mismatch_carries_index_path() {
+    #[derive(Debug, serde::Deserialize)]
+    #[allow(dead_code)]
+    struct Row {
+        name: String,
+        value: i32,
+    }
+
+    let bad = b"name,value\na,1\nb,not a number\n";
+    let err = crate::format::deserialize::<Vec<Row>>(FormatKind::Csv, bad).unwrap_err();
+
+    let FormatError::SerdeAt { path, .. } = err else {
+        panic!("expected a path-tracked serde error, got {err:?}");
+    };
+    assert_eq!(path, "[1].value");
+}
@@ -0,0 +1,50 @@
+//! Tests that malformed JSON/YAML/TOML input produces a located
+//! `FormatError::SerdeSpanned` pointing at (roughly) the offending byte.
+
+use crate::format::{deserialize, FormatError, FormatKind};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+struct Data {
+    #[allow(dead_code)]
+    name: String,
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn json_parse_error_carries_span() {
+    let bad = br#"{"name": "#;
+    let err = deserialize::<Data>(FormatKind::Json, bad).unwrap_err();
+
+    let FormatError::SerdeSpanned { span, input, .. } = err else {
+        panic!("expected a spanned serde error, got {err:?}");
+    };
+    assert_eq!(&*input, bad.as_slice());
+    assert!(span.0 <= bad.len());
+}
+
+#[cfg(feature = "yaml")]
+#[test]
+fn yaml_parse_error_carries_span() {
+    let bad = b"name: [unterminated";
+    let err = deserialize::<Data>(FormatKind::Yaml, bad).unwrap_err();
+
+    let FormatError::SerdeSpanned { span, input, .. } = err else {
+        panic!("expected a spanned serde error, got {err:?}");
+    };
+    assert_eq!(&*input, bad.as_slice());
+    assert!(span.0 <= bad.len());
+}
+
+#[cfg(feature = "toml")]
+#[test]
+fn toml_parse_error_carries_span() {
+    let bad = b"name = \"unterminated";
+    let err = deserialize::<Data>(FormatKind::Toml, bad).unwrap_err();
+
+    let FormatError::SerdeSpanned { span, input, .. } = err else {
+        panic!("expected a spanned serde error, got {err:?}");
+    };
+    assert_eq!(&*input, bad.as_slice());
+    assert!(span.0 <= bad.len());
+}
@@ -0,0 +1,36 @@
+//! Tests for `ProcessOutput`/`ProcessWriter`.
+
+use crate::{OutputTarget, ProcessOutput};
+use std::io::Write;
+
+#[test]
+fn process_output_does_not_hang_when_filter_reads_to_completion_first() {
+    // `cat` only starts writing its output once it has read all of its
+    // input, so this reproduces the scenario the original bug report named:
+    // a filter that reads stdin to completion before producing any output.
+    // If `ProcessWriter::Drop` doesn't close stdin before `wait()`, this
+    // test hangs forever instead of completing.
+    let target = ProcessOutput::new("cat");
+    let mut writer = target.open_overwrite().expect("spawn cat");
+    writer.write_all(b"hello world").expect("write to cat's stdin");
+    writer.flush().expect("cat should exit cleanly");
+}
+
+#[test]
+fn process_output_surfaces_nonzero_exit_status() {
+    let target = ProcessOutput::new("false");
+    let mut writer = target.open_overwrite().expect("spawn false");
+    // `false` may exit before or after this write lands, depending on
+    // scheduling; either way the nonzero status must surface on flush.
+    let _ = writer.write_all(b"anything");
+    let result = writer.flush();
+    assert!(result.is_err(), "nonzero exit status should surface as an error");
+}
+
+#[test]
+fn process_output_succeeds_for_a_zero_exit_filter() {
+    let target = ProcessOutput::new("true");
+    let mut writer = target.open_overwrite().expect("spawn true");
+    let _ = writer.write_all(b"anything");
+    writer.flush().expect("zero exit status should not error");
+}
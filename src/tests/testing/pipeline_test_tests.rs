@@ -0,0 +1,110 @@
+//! Tests for `crate::testing::PipelineTest`.
+
+use crate::config::{InputConfig, OutputConfig, PipelineConfig};
+use crate::error::Stage;
+use crate::format::default_registry;
+use crate::io::InMemorySource;
+use crate::testing::{ExpectedErrorKey, ExpectedOutput, Mismatch, PipelineTest};
+use crate::InputSpec;
+use std::sync::Arc;
+
+fn output_config(id: &str, kind: &str) -> OutputConfig {
+    OutputConfig {
+        id: id.to_string(),
+        kind: kind.to_string(),
+        path: None,
+        url: None,
+        headers: Default::default(),
+        format: Some("json".to_string()),
+        file_exists_policy: None,
+        style: None,
+        indent: None,
+        key_order: None,
+        file_mode: None,
+        encryption_key: None,
+    }
+}
+
+fn input_config(id: &str, path: &str) -> InputConfig {
+    InputConfig {
+        id: id.to_string(),
+        kind: "file".to_string(),
+        path: Some(path.to_string()),
+        url: None,
+        headers: Default::default(),
+        format: Some("json".to_string()),
+        encryption_key: None,
+    }
+}
+
+#[test]
+fn matches_decoded_output_from_memory_input() {
+    let json_src = InMemorySource::from_string("in", r#"{"name": "a", "value": 1}"#);
+    let input_spec = InputSpec::new("in", Arc::new(json_src)).with_format(crate::FormatKind::Json);
+
+    let config = PipelineConfig::new().add_output(output_config("out", "stdout"));
+
+    let mismatches = PipelineTest::new(config)
+        .with_input(input_spec)
+        .expect_output(
+            "out",
+            ExpectedOutput::Decoded(serde_json::json!([{"name": "a", "value": 1}])),
+        )
+        .run(default_registry());
+
+    assert!(mismatches.is_empty(), "unexpected mismatches: {mismatches:?}");
+}
+
+#[test]
+fn reports_output_mismatch_when_contents_differ() {
+    let json_src = InMemorySource::from_string("in", r#"{"name": "a", "value": 1}"#);
+    let input_spec = InputSpec::new("in", Arc::new(json_src)).with_format(crate::FormatKind::Json);
+
+    let config = PipelineConfig::new().add_output(output_config("out", "stdout"));
+
+    let mismatches = PipelineTest::new(config)
+        .with_input(input_spec)
+        .expect_output(
+            "out",
+            ExpectedOutput::Decoded(serde_json::json!([{"name": "wrong", "value": 99}])),
+        )
+        .run(default_registry());
+
+    assert_eq!(mismatches.len(), 1);
+    assert!(matches!(mismatches[0], Mismatch::OutputMismatch { .. }));
+}
+
+#[test]
+fn reports_missing_expected_error() {
+    let config = PipelineConfig::new()
+        .add_input(input_config("missing", "/no/such/path.json"))
+        .add_output(output_config("out", "stdout"));
+
+    let mismatches = PipelineTest::new(config)
+        .expect_errors(vec![ExpectedErrorKey::new(Stage::Parse, "missing")])
+        .run(default_registry());
+
+    // The actual failure is an Open error (file doesn't exist), not the
+    // Parse error we declared, so both a MissingError and an
+    // UnexpectedError should be reported.
+    assert_eq!(mismatches.len(), 2);
+    assert!(mismatches
+        .iter()
+        .any(|m| matches!(m, Mismatch::MissingError(_))));
+    assert!(mismatches
+        .iter()
+        .any(|m| matches!(m, Mismatch::UnexpectedError { .. })));
+}
+
+#[test]
+fn matches_expected_open_error() {
+    let config = PipelineConfig::new()
+        .add_input(input_config("missing", "/no/such/path.json"))
+        .add_output(output_config("out", "stdout"));
+
+    let mismatches = PipelineTest::new(config)
+        .expect_errors(vec![ExpectedErrorKey::new(Stage::Open, "missing")])
+        .run(default_registry());
+
+    assert!(mismatches.is_empty(), "unexpected mismatches: {mismatches:?}");
+}
@@ -0,0 +1,51 @@
+//! Optional `tracing` instrumentation for the I/O pipeline.
+//!
+//! Gated behind the `tracing` feature: each pipeline operation (one input
+//! read, one output write) runs inside a span tagged with the operation name
+//! and target id, and emits a structured event on completion — an info event
+//! with the target id, resolved `FormatKind`, byte count, and elapsed time on
+//! success, or an error event mirroring `SingleIoError`'s fields on failure.
+//! This is a no-op unless the feature is enabled, so it adds no cost to the
+//! default build.
+
+use std::time::Instant;
+
+use crate::error::{SingleIoError, Stage};
+use crate::format::FormatKind;
+
+/// Open a span for one pipeline operation, to be entered for its duration.
+pub(crate) fn operation_span(operation: &'static str, target: &str) -> tracing::Span {
+    tracing::debug_span!("io_operation", operation, target = %target)
+}
+
+/// Emit a structured completion event for a pipeline operation: an info event
+/// with stage/target/format/byte-count/elapsed on success, or an error event
+/// with `SingleIoError`'s own stage/target/attempts/error on failure.
+pub(crate) fn record_outcome<T>(
+    result: &Result<T, SingleIoError>,
+    success_stage: Stage,
+    target: &str,
+    format: Option<&FormatKind>,
+    bytes: usize,
+    start: Instant,
+) {
+    let elapsed_ms = start.elapsed().as_secs_f64() * 1000.0;
+    match result {
+        Ok(_) => tracing::info!(
+            stage = %success_stage,
+            target = %target,
+            format = ?format,
+            bytes,
+            elapsed_ms,
+            "pipeline stage succeeded"
+        ),
+        Err(e) => tracing::error!(
+            stage = %e.stage,
+            target = %e.target,
+            attempts = e.attempts,
+            error = %e.error,
+            elapsed_ms,
+            "pipeline stage failed"
+        ),
+    }
+}